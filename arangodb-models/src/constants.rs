@@ -3,5 +3,8 @@ pub static DB_COLLECTION_SUFFIX: &str = "Collection";
 pub static FIELDS_SUFFIX: &str = "Field";
 pub static MUTEX_FIELD_NAME: &str = "db_mutex";
 pub static MUTEX_FIELD_DB_NAME: &str = "_l";
+pub static SCHEMA_VERSION_FIELD_NAME: &str = "db_schema_version";
+pub static SCHEMA_VERSION_FIELD_DB_NAME: &str = "_v";
 pub static DB_MODEL_NAME: &str = "DB";
 pub static DB_MODEL_TAG: &str = "db";
+pub static KEY_FROM_SEPARATOR: char = '-';
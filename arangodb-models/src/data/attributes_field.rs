@@ -1,15 +1,20 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use std::collections::{HashMap, HashSet};
-use syn::{Attribute, Type};
+use syn::{Attribute, Lit, Meta, NestedMeta, Path, Type};
 
+use crate::errors::Error;
 use crate::utils::{
-    get_simple_name_from_meta, process_enum_literal, process_only_attribute, process_string_literal,
+    get_simple_name_from_meta, process_bool_literal, process_enum_literal, process_only_attribute,
+    process_string_literal,
 };
 
 pub const ATTR_ATTRIBUTE_SUFFIX: &str = "_attr";
+pub const API_READONLY_ATTRIBUTE: &str = "api_readonly";
 pub const SKIP_IN_ATTRIBUTE_PREFIX: &str = "skip_in_";
 pub const DB_NAME_ATTRIBUTE: &str = "db_name";
+pub const ENCRYPTED_ATTRIBUTE: &str = "encrypted";
+pub const FLATTEN_ATTRIBUTE: &str = "flatten";
 pub const INNER_MODEL_ATTRIBUTE: &str = "inner_model";
 pub static INNER_MODEL_ATTRIBUTE_NAMES: &[&str] = &["data", "struct", "enum"];
 pub static INNER_MODEL_ATTRIBUTE_VALUES: &[InnerModelKind] = &[
@@ -18,15 +23,25 @@ pub static INNER_MODEL_ATTRIBUTE_VALUES: &[InnerModelKind] = &[
     InnerModelKind::Enum,
 ];
 pub const INNER_TYPE_ATTRIBUTE_PREFIX: &str = "inner_type_";
+pub const MAX_LEN_ATTRIBUTE: &str = "max_len";
+pub const MAX_LEN_BYTES_ARGUMENT: &str = "bytes";
+pub const SERDE_WITH_ATTRIBUTE: &str = "serde_with";
+pub const TTL_ATTRIBUTE: &str = "ttl";
 
 #[derive(Default)]
 pub struct FieldAttributes {
     pub attributes: Vec<TokenStream>,
     pub attributes_by_model: HashMap<String, Vec<TokenStream>>,
     pub skip_in_model: HashSet<String>,
+    pub api_readonly: bool,
     pub db_name: Option<String>,
+    pub encrypted_with: Option<Path>,
+    pub flatten: bool,
     pub inner_model: InnerModelKind,
     pub inner_type_by_model: HashMap<String, Type>,
+    pub max_len: Option<MaxLength>,
+    pub serde_with: Option<Path>,
+    pub ttl: bool,
 }
 
 impl FieldAttributes {
@@ -58,9 +73,23 @@ impl FieldAttributes {
             let name = name.as_str();
 
             match name {
+                API_READONLY_ATTRIBUTE => {
+                    result.api_readonly = process_bool_literal(&meta, name, Some(true))?;
+                }
                 DB_NAME_ATTRIBUTE => {
                     result.db_name = Some(process_string_literal(&meta, name, None)?);
                 }
+                ENCRYPTED_ATTRIBUTE => {
+                    let value = process_string_literal(&meta, name, None)?;
+                    let path = syn::parse_str::<Path>(value.as_str()).map_err(|_| {
+                        Error::Message(format!("\"{}\" is not a valid cipher path", value))
+                            .with_tokens(attribute)
+                    })?;
+                    result.encrypted_with = Some(path);
+                }
+                FLATTEN_ATTRIBUTE => {
+                    result.flatten = process_bool_literal(&meta, name, Some(true))?;
+                }
                 INNER_MODEL_ATTRIBUTE => {
                     result.inner_model = process_enum_literal(
                         &meta,
@@ -70,6 +99,20 @@ impl FieldAttributes {
                         None,
                     )?;
                 }
+                MAX_LEN_ATTRIBUTE => {
+                    result.max_len = Some(MaxLength::from_meta(&meta, name)?);
+                }
+                SERDE_WITH_ATTRIBUTE => {
+                    let value = process_string_literal(&meta, name, None)?;
+                    let path = syn::parse_str::<Path>(value.as_str()).map_err(|_| {
+                        Error::Message(format!("\"{}\" is not a valid serde module path", value))
+                            .with_tokens(attribute)
+                    })?;
+                    result.serde_with = Some(path);
+                }
+                TTL_ATTRIBUTE => {
+                    result.ttl = process_bool_literal(&meta, name, Some(true))?;
+                }
                 _ => {
                     if name.ends_with(ATTR_ATTRIBUTE_SUFFIX) {
                         let final_name = name.trim_end_matches(ATTR_ATTRIBUTE_SUFFIX);
@@ -131,3 +174,58 @@ impl Default for InnerModelKind {
         Self::Data
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The maximum length of a string field, i.e. `#[max_len(<value>)]` counting characters
+/// or `#[max_len(<value>, bytes)]` counting bytes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MaxLength {
+    pub value: usize,
+    pub in_bytes: bool,
+}
+
+impl MaxLength {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn from_meta(meta: &Meta, attribute_name: &str) -> Result<MaxLength, syn::Error> {
+        let error = || {
+            Error::CompulsoryAttributeArguments(format!(
+                "The \"{}\" attribute require an integer argument optionally followed by \"{}\", e.g: {}(255) or {}(255, {})",
+                attribute_name,
+                MAX_LEN_BYTES_ARGUMENT,
+                attribute_name,
+                attribute_name,
+                MAX_LEN_BYTES_ARGUMENT,
+            ))
+            .with_tokens(meta)
+        };
+
+        let meta_list = match meta {
+            Meta::List(v) => v,
+            _ => return Err(error()),
+        };
+
+        let mut arguments = meta_list.nested.iter();
+        let value = match arguments.next() {
+            Some(NestedMeta::Lit(Lit::Int(lit))) => lit.base10_parse::<usize>()?,
+            _ => return Err(error()),
+        };
+
+        let in_bytes = match arguments.next() {
+            Some(NestedMeta::Meta(Meta::Path(path))) if path.is_ident(MAX_LEN_BYTES_ARGUMENT) => {
+                true
+            }
+            Some(_) => return Err(error()),
+            None => false,
+        };
+
+        if arguments.next().is_some() {
+            return Err(error());
+        }
+
+        Ok(MaxLength { value, in_bytes })
+    }
+}
@@ -1,7 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use std::collections::HashMap;
-use syn::Attribute;
+use syn::parse::Parser;
+use syn::{Attribute, Meta, NestedMeta};
 
 use crate::utils::{get_simple_name_from_meta, process_bool_literal, process_only_attribute};
 
@@ -65,4 +66,37 @@ impl StructAttributes {
 
         Ok(result)
     }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// Checks whether the attributes of `model` derive the trait named `name`.
+    pub fn derives(&self, model: &str, name: &str) -> bool {
+        let model_attributes = self.attributes_by_model.get(model).into_iter().flatten();
+
+        self.attributes
+            .iter()
+            .chain(model_attributes)
+            .any(|tokens| {
+                let attributes = match Attribute::parse_outer.parse2(tokens.clone()) {
+                    Ok(v) => v,
+                    Err(_) => return false,
+                };
+
+                attributes.iter().any(|attribute| {
+                    if !attribute.path.is_ident("derive") {
+                        return false;
+                    }
+
+                    let meta_list = match attribute.parse_meta() {
+                        Ok(Meta::List(v)) => v,
+                        _ => return false,
+                    };
+
+                    meta_list.nested.iter().any(|nested| match nested {
+                        NestedMeta::Meta(Meta::Path(path)) => path.is_ident(name),
+                        _ => false,
+                    })
+                })
+            })
+    }
 }
@@ -5,8 +5,8 @@ use syn::{Field, Fields, Path, Type, Variant};
 use crate::data::{FieldAttributes, InnerModelKind};
 use crate::errors::Error;
 use crate::utils::{
-    from_snake_case_to_camel_case, get_inner_map_types_from_path, get_inner_type_from_path,
-    get_name_from_path,
+    contains_float_type, from_snake_case_to_camel_case, get_inner_map_types_from_path,
+    get_inner_type_from_path, get_name_from_path,
 };
 
 pub struct FieldInfo<'a> {
@@ -53,6 +53,73 @@ impl<'a> FieldInfo<'a> {
             );
         }
 
+        // Check flatten fields.
+        if result.attributes.flatten
+            && (result.attributes.inner_model != InnerModelKind::Struct
+                || result.field_type_kind.is_some()
+                || !matches!(
+                    result.base_type_kind,
+                    BaseTypeKind::Other | BaseTypeKind::Box
+                ))
+        {
+            return Err(Error::Message(format!(
+                "Only non-optional struct fields can be flattened in field '{}'",
+                field.ident.as_ref().unwrap()
+            ))
+            .with_tokens(field));
+        }
+
+        // Check read-only fields.
+        if result.attributes.api_readonly && result.field_type_kind.is_none() {
+            return Err(Error::Message(format!(
+                "Only optional fields can be read-only in the API models in field '{}'",
+                field.ident.as_ref().unwrap()
+            ))
+            .with_tokens(field));
+        }
+
+        // Check encrypted fields.
+        if result.attributes.encrypted_with.is_some()
+            && (result.base_type_kind != BaseTypeKind::Other
+                || !result.attributes.inner_type_by_model.is_empty()
+                || !result
+                    .inner_type
+                    .as_ref()
+                    .unwrap()
+                    .to_string()
+                    .ends_with("String"))
+        {
+            return Err(Error::Message(format!(
+                "Only plain String fields can be encrypted in field '{}'",
+                field.ident.as_ref().unwrap()
+            ))
+            .with_tokens(field));
+        }
+
+        // Check fields with a custom serde module.
+        if result.attributes.serde_with.is_some()
+            && (result.attributes.flatten || result.attributes.inner_model != InnerModelKind::Data)
+        {
+            return Err(Error::Message(format!(
+                "Only data fields can use a custom serde module in field '{}'",
+                field.ident.as_ref().unwrap()
+            ))
+            .with_tokens(field));
+        }
+
+        // Check reference fields.
+        if matches!(
+            result.base_type_kind,
+            BaseTypeKind::DBReference | BaseTypeKind::VecDBReference
+        ) && result.attributes.inner_model != InnerModelKind::Data
+        {
+            return Err(Error::Message(format!(
+                "References cannot be declared as struct or enum models, remove the inner_model attribute in field '{}'",
+                field.ident.as_ref().unwrap()
+            ))
+            .with_tokens(field));
+        }
+
         let attributes = &mut result.attributes;
         match result.field_type_kind {
             Some(FieldTypeKind::NullableOption) => {
@@ -67,7 +134,7 @@ impl<'a> FieldInfo<'a> {
             }
             None => match result.base_type_kind {
                 BaseTypeKind::Other | BaseTypeKind::Box => {}
-                BaseTypeKind::Vec | BaseTypeKind::VecDBReference => {
+                BaseTypeKind::Vec | BaseTypeKind::VecBox | BaseTypeKind::VecDBReference => {
                     attributes.attributes.push(quote! {
                         #[serde(skip_serializing_if = "Vec::is_empty")]
                     });
@@ -77,6 +144,11 @@ impl<'a> FieldInfo<'a> {
                         #[serde(skip_serializing_if = "HashMap::is_empty")]
                     });
                 }
+                BaseTypeKind::BTreeMap => {
+                    attributes.attributes.push(quote! {
+                        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+                    });
+                }
                 BaseTypeKind::DBReference => {}
             },
         }
@@ -110,6 +182,38 @@ impl<'a> FieldInfo<'a> {
             }
         };
 
+        if attributes.flatten {
+            return Err(Error::Message(format!(
+                "Enum variants cannot be flattened in variant '{}'",
+                variant.ident
+            ))
+            .with_tokens(variant));
+        }
+
+        if attributes.api_readonly {
+            return Err(Error::Message(format!(
+                "Enum variants cannot be read-only in variant '{}'",
+                variant.ident
+            ))
+            .with_tokens(variant));
+        }
+
+        if attributes.encrypted_with.is_some() {
+            return Err(Error::Message(format!(
+                "Enum variants cannot be encrypted in variant '{}'",
+                variant.ident
+            ))
+            .with_tokens(variant));
+        }
+
+        if attributes.serde_with.is_some() {
+            return Err(Error::Message(format!(
+                "Enum variants cannot use a custom serde module in variant '{}'",
+                variant.ident
+            ))
+            .with_tokens(variant));
+        }
+
         // Create result.
         let mut result = FieldInfo {
             node: FieldNode::Variant(variant),
@@ -141,6 +245,14 @@ impl<'a> FieldInfo<'a> {
         self.node.ident()
     }
 
+    /// Whether the field type contains a floating point type, i.e. f32 or f64.
+    pub fn contains_float_type(&self) -> bool {
+        match self.node.as_field() {
+            Some(field) => contains_float_type(field.ty.to_token_stream()),
+            None => false,
+        }
+    }
+
     // METHODS ----------------------------------------------------------------
 
     fn read_base_type(&mut self, field_name: &str, path: &Path) -> Result<(), syn::Error> {
@@ -184,6 +296,18 @@ impl<'a> FieldInfo<'a> {
                         }
                     };
                     self.base_type_kind = BaseTypeKind::VecDBReference;
+                } else if let Some("Box") = name.as_deref() {
+                    self.inner_type = match get_inner_type_from_path(&type_path.path) {
+                        Some(v) => Some(v.to_token_stream()),
+                        None => {
+                            return Err(Error::Message(format!(
+                                "Missing T in Vec<Box<T>> in field '{}'",
+                                field_name
+                            ))
+                            .with_tokens(path));
+                        }
+                    };
+                    self.base_type_kind = BaseTypeKind::VecBox;
                 } else {
                     self.inner_type = Some(type_path.to_token_stream());
                     self.base_type_kind = BaseTypeKind::Vec;
@@ -212,7 +336,7 @@ impl<'a> FieldInfo<'a> {
                     }
                 };
                 self.base_type_kind = BaseTypeKind::DBReference;
-            } else if name == "HashMap" {
+            } else if name == "HashMap" || name == "BTreeMap" {
                 let (key_type, value_type) = match get_inner_map_types_from_path(path) {
                     Some(v) => v,
                     None => {
@@ -226,14 +350,18 @@ impl<'a> FieldInfo<'a> {
 
                 self.inner_type = Some(value_type.to_token_stream());
                 self.key_type = Some(key_type.to_token_stream());
-                self.base_type_kind = BaseTypeKind::HashMap;
+                self.base_type_kind = if name == "HashMap" {
+                    BaseTypeKind::HashMap
+                } else {
+                    BaseTypeKind::BTreeMap
+                };
             } else {
                 self.inner_type = Some(path.to_token_stream());
                 self.base_type_kind = BaseTypeKind::Other;
             }
         } else {
             return Err(Error::Message(
-                format!("Cannot get a base type from the tokens. They must be a simple type (T), a Vec<T> or a HashMap<K, T> in field '{}'", field_name),
+                format!("Cannot get a base type from the tokens. They must be a simple type (T), a Vec<T>, a HashMap<K, T> or a BTreeMap<K, T> in field '{}'", field_name),
             )
                 .with_tokens(path));
         }
@@ -329,11 +457,16 @@ impl<'a> FieldInfo<'a> {
             BaseTypeKind::Other => quote! { #inner_type },
             BaseTypeKind::Box => quote! { Box<#inner_type> },
             BaseTypeKind::Vec => quote! { Vec<#inner_type> },
+            BaseTypeKind::VecBox => quote! { Vec<Box<#inner_type>> },
             BaseTypeKind::VecDBReference => quote! { Vec<DBReference<#inner_type>> },
             BaseTypeKind::HashMap => {
                 let key_type = self.key_type.as_ref().unwrap();
                 quote! { HashMap<#key_type, #inner_type> }
             }
+            BaseTypeKind::BTreeMap => {
+                let key_type = self.key_type.as_ref().unwrap();
+                quote! { BTreeMap<#key_type, #inner_type> }
+            }
             BaseTypeKind::DBReference => quote! { DBReference<#inner_type> },
         };
 
@@ -363,11 +496,16 @@ impl<'a> FieldInfo<'a> {
             BaseTypeKind::Other => quote! { #inner_type },
             BaseTypeKind::Box => quote! { Box<#inner_type> },
             BaseTypeKind::Vec => quote! { Vec<#inner_type> },
+            BaseTypeKind::VecBox => quote! { Vec<Box<#inner_type>> },
             BaseTypeKind::VecDBReference => quote! { Vec<APIReference<#inner_type>> },
             BaseTypeKind::HashMap => {
                 let key_type = self.key_type.as_ref().unwrap();
                 quote! { HashMap<#key_type, #inner_type> }
             }
+            BaseTypeKind::BTreeMap => {
+                let key_type = self.key_type.as_ref().unwrap();
+                quote! { BTreeMap<#key_type, #inner_type> }
+            }
             BaseTypeKind::DBReference => quote! { APIReference<#inner_type> },
         };
 
@@ -380,7 +518,30 @@ impl<'a> FieldInfo<'a> {
         }
     }
 
+    pub fn build_db_field_name_attribute(&self) -> TokenStream {
+        if self.attributes.flatten {
+            quote! {
+                #[serde(flatten)]
+            }
+        } else {
+            let db_name = &self.db_name;
+
+            quote! {
+                #[serde(rename = #db_name)]
+            }
+        }
+    }
+
     pub fn build_field_deserialize_with(&self) -> TokenStream {
+        // A custom serde module replaces the default deserializers.
+        if let Some(serde_with) = &self.attributes.serde_with {
+            let serde_with = serde_with.to_token_stream().to_string().replace(' ', "");
+
+            return quote! {
+                #[serde(with = #serde_with)]
+            };
+        }
+
         if self.base_type_kind != BaseTypeKind::Other {
             return quote! {};
         }
@@ -451,8 +612,10 @@ pub enum BaseTypeKind {
     Other,
     Box,
     Vec,
+    VecBox,
     VecDBReference,
     HashMap,
+    BTreeMap,
     DBReference,
 }
 
@@ -8,7 +8,7 @@ use syn::{Attribute, File, Generics, Item, ItemEnum, ItemStruct, Visibility};
 
 use crate::constants::{
     DB_COLLECTION_SUFFIX, DB_DOCUMENT_SUFFIX, DB_MODEL_NAME, DB_MODEL_TAG, FIELDS_SUFFIX,
-    MUTEX_FIELD_DB_NAME,
+    MUTEX_FIELD_DB_NAME, SCHEMA_VERSION_FIELD_DB_NAME,
 };
 use crate::data::{FieldInfo, ModelOptions, StructAttributes};
 use crate::errors::Error;
@@ -100,6 +100,8 @@ impl<'a> ModelInfo<'a> {
 
         // Final checks.
         result.check_names(options)?;
+        result.check_api_document_names()?;
+        result.check_api_id_field(options)?;
 
         Ok(result)
     }
@@ -178,6 +180,7 @@ impl<'a> ModelInfo<'a> {
 
         // Final checks.
         result.check_names(options)?;
+        result.check_api_document_names()?;
 
         Ok(result)
     }
@@ -222,6 +225,23 @@ impl<'a> ModelInfo<'a> {
 
     // METHODS ----------------------------------------------------------------
 
+    /// Checks that `Eq` is not derived for `model` when any of its fields is a float.
+    pub fn check_eq_derive(&self, model: &str, fields: &[&FieldInfo]) -> Result<(), syn::Error> {
+        if !self.item_attributes.derives(model, "Eq") {
+            return Ok(());
+        }
+
+        if let Some(field) = fields.iter().find(|field| field.contains_float_type()) {
+            return Err(Error::Message(format!(
+                "Cannot derive Eq because the field \"{}\" contains a floating point type, derive only PartialEq instead",
+                field.name()
+            ))
+            .with_tokens(field.node.as_field().map(|v| &v.ty)));
+        }
+
+        Ok(())
+    }
+
     fn check_names(&self, options: &ModelOptions) -> Result<(), syn::Error> {
         let mut names = HashSet::with_capacity(self.item_fields.len());
 
@@ -233,6 +253,11 @@ impl<'a> ModelInfo<'a> {
             names.insert(&mutex);
         }
 
+        let schema_version = SCHEMA_VERSION_FIELD_DB_NAME.to_string();
+        if options.schema_version.is_some() {
+            names.insert(&schema_version);
+        }
+
         for field in &self.item_fields {
             let db_name = &field.db_name;
             if names.contains(db_name) {
@@ -245,6 +270,47 @@ impl<'a> ModelInfo<'a> {
         Ok(())
     }
 
+    /// Checks that no two API models get the same name, e.g. `build_api` and `build_Api`.
+    fn check_api_document_names(&self) -> Result<(), syn::Error> {
+        let mut models: Vec<_> = self.api_document_names.iter().collect();
+        models.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut names = HashMap::with_capacity(models.len());
+        for (model, name) in models {
+            if let Some(other) = names.insert(name.to_string(), model) {
+                return Err(Error::Message(format!(
+                    "The {} and {} models both generate the name {}, rename one of them",
+                    other, model, name
+                ))
+                .with_tokens(self.item.ident()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that no field of the API models collides with the `id` field they get from `db_key`.
+    fn check_api_id_field(&self, options: &ModelOptions) -> Result<(), syn::Error> {
+        let mut models: Vec<_> = options.build_models.iter().collect();
+        models.sort();
+
+        for model in models {
+            if let Some(field) = self
+                .fields_in_model(model)
+                .into_iter()
+                .find(|field| *field.name() == "id")
+            {
+                return Err(Error::Message(format!(
+                    "The field 'id' collides with the one generated from 'db_key' in the {} model, rename it or skip it in that model",
+                    model
+                ))
+                .with_tokens(&field.node));
+            }
+        }
+
+        Ok(())
+    }
+
     fn analyze_rest_functions(&mut self, mut items_iter: Iter<'a, Item>) -> Result<(), syn::Error> {
         if let Some(item) = items_iter.next() {
             return Err(Error::UnexpectedItem.with_tokens(item));
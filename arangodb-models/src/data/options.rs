@@ -1,7 +1,7 @@
 use proc_macro2::Ident;
 use quote::format_ident;
 use std::collections::HashSet;
-use syn::Attribute;
+use syn::{Attribute, Expr, ExprArray, ExprLit, Lit, LitInt, Meta, NestedMeta, Path};
 
 use crate::errors::Error;
 use crate::utils::{
@@ -12,13 +12,28 @@ pub const RELATIVE_IMPORTS_ATTRIBUTE: &str = "relative_imports";
 pub const BUILD_ATTRIBUTE_PREFIX: &str = "build_";
 pub const SKIP_IMPL_ATTRIBUTE: &str = "skip_impl";
 pub const SKIP_FIELDS_ATTRIBUTE: &str = "skip_fields";
+pub const API_DENY_UNKNOWN_FIELDS_ATTRIBUTE: &str = "api_deny_unknown_fields";
 pub const SYNC_LEVEL_ATTRIBUTE: &str = "sync_level";
 pub static SYNC_LEVEL_ATTRIBUTE_NAMES: &[&str] =
     &["document", "collection", "document_and_collection"];
 pub const SYNC_COLLECTION_KEY_METHOD_ATTRIBUTE: &str = "sync_collection_key_method";
+pub const SYNC_EXCLUSIVE_ATTRIBUTE: &str = "sync_exclusive";
 pub const COLLECTION_NAME_ATTRIBUTE: &str = "collection_name";
 pub const COLLECTION_TYPE_ATTRIBUTE: &str = "collection_type";
 pub const COLLECTION_KIND_ATTRIBUTE: &str = "collection_kind";
+pub const VALIDATE_WITH_ATTRIBUTE: &str = "validate_with";
+pub const VALIDATE_ON_WRITE_ATTRIBUTE: &str = "validate_on_write";
+pub const NORMALIZE_WITH_ATTRIBUTE: &str = "normalize_with";
+pub const KEY_FROM_ATTRIBUTE: &str = "key_from";
+pub const SINGLETON_COLLECTION_ATTRIBUTE: &str = "singleton_collection";
+pub const GENERATE_LOADERS_ATTRIBUTE: &str = "generate_loaders";
+pub const SCHEMA_VERSION_ATTRIBUTE: &str = "schema_version";
+pub const KEY_GENERATOR_ATTRIBUTE: &str = "key_generator";
+pub static KEY_GENERATOR_ATTRIBUTE_NAMES: &[&str] =
+    &["default", "simple", "base60", "base58", "sortable"];
+pub const TIMESTAMPS_ATTRIBUTE: &str = "timestamps";
+pub const TIMESTAMPS_CREATED_ARGUMENT: &str = "created";
+pub const TIMESTAMPS_UPDATED_ARGUMENT: &str = "updated";
 
 #[derive(Default)]
 pub struct ModelOptions {
@@ -26,12 +41,24 @@ pub struct ModelOptions {
     pub build_models: HashSet<String>,
     pub skip_impl: bool,
     pub skip_fields: bool,
+    pub api_deny_unknown_fields: bool,
     pub serialize_fields: bool,
     pub sync_level: SyncLevelType,
     pub sync_collection_key_method: Option<Ident>,
+    pub sync_exclusive: bool,
     pub collection_name: Option<Ident>,
     pub collection_type: Option<Ident>,
     pub collection_kind: Option<Ident>,
+    pub validate_with: Option<Path>,
+    pub validate_on_write: bool,
+    pub normalize_with: Option<Path>,
+    pub key_from: Vec<Ident>,
+    pub singleton_collection: bool,
+    pub generate_loaders: bool,
+    pub schema_version: Option<u32>,
+    pub key_generator: Option<KeyGeneratorType>,
+    pub timestamps_created: Option<Ident>,
+    pub timestamps_updated: Option<Ident>,
 }
 
 impl ModelOptions {
@@ -42,6 +69,18 @@ impl ModelOptions {
         #[allow(clippy::never_loop)]
         // Read every attribute, i.e. #[...]
         for attribute in attributes {
+            // The list of fields is not a valid meta, i.e. #![key_from(["a", "b"])]
+            if attribute.path.is_ident(KEY_FROM_ATTRIBUTE) {
+                result.key_from = Self::parse_key_from(attribute)?;
+                continue;
+            }
+
+            // The version is not a valid meta, i.e. #![schema_version(3)]
+            if attribute.path.is_ident(SCHEMA_VERSION_ATTRIBUTE) {
+                result.schema_version = Some(Self::parse_schema_version(attribute)?);
+                continue;
+            }
+
             // Transform the attribute as meta, i.e. removing the brackets.
             let meta = attribute.parse_meta()?;
 
@@ -62,6 +101,9 @@ impl ModelOptions {
                 SKIP_FIELDS_ATTRIBUTE => {
                     result.skip_fields = process_bool_literal(&meta, name, Some(true))?;
                 }
+                API_DENY_UNKNOWN_FIELDS_ATTRIBUTE => {
+                    result.api_deny_unknown_fields = process_bool_literal(&meta, name, Some(true))?;
+                }
                 SYNC_LEVEL_ATTRIBUTE => {
                     static ENUM_LIST_VALUES: &[SyncLevelType] = &[
                         SyncLevelType::OnlyDocument,
@@ -81,6 +123,9 @@ impl ModelOptions {
                     let value = process_string_literal(&meta, name, None)?;
                     result.sync_collection_key_method = Some(format_ident!("{}", value));
                 }
+                SYNC_EXCLUSIVE_ATTRIBUTE => {
+                    result.sync_exclusive = process_bool_literal(&meta, name, Some(true))?;
+                }
                 COLLECTION_NAME_ATTRIBUTE => {
                     let value = process_string_literal(&meta, name, None)?;
                     result.collection_name = Some(format_ident!("{}", value));
@@ -93,6 +138,53 @@ impl ModelOptions {
                     let value = process_string_literal(&meta, name, None)?;
                     result.collection_kind = Some(format_ident!("{}", value));
                 }
+                VALIDATE_WITH_ATTRIBUTE => {
+                    let value = process_string_literal(&meta, name, None)?;
+                    let path = syn::parse_str::<Path>(value.as_str()).map_err(|_| {
+                        Error::Message(format!("\"{}\" is not a valid function path", value))
+                            .with_tokens(attribute)
+                    })?;
+                    result.validate_with = Some(path);
+                }
+                VALIDATE_ON_WRITE_ATTRIBUTE => {
+                    result.validate_on_write = process_bool_literal(&meta, name, Some(true))?;
+                }
+                NORMALIZE_WITH_ATTRIBUTE => {
+                    let value = process_string_literal(&meta, name, None)?;
+                    let path = syn::parse_str::<Path>(value.as_str()).map_err(|_| {
+                        Error::Message(format!("\"{}\" is not a valid function path", value))
+                            .with_tokens(attribute)
+                    })?;
+                    result.normalize_with = Some(path);
+                }
+                SINGLETON_COLLECTION_ATTRIBUTE => {
+                    result.singleton_collection = process_bool_literal(&meta, name, Some(true))?;
+                }
+                GENERATE_LOADERS_ATTRIBUTE => {
+                    result.generate_loaders = process_bool_literal(&meta, name, Some(true))?;
+                }
+                KEY_GENERATOR_ATTRIBUTE => {
+                    static ENUM_LIST_VALUES: &[KeyGeneratorType] = &[
+                        KeyGeneratorType::Default,
+                        KeyGeneratorType::Simple,
+                        KeyGeneratorType::Base60,
+                        KeyGeneratorType::Base58,
+                        KeyGeneratorType::Sortable,
+                    ];
+
+                    result.key_generator = Some(process_enum_literal(
+                        &meta,
+                        KEY_GENERATOR_ATTRIBUTE_NAMES,
+                        ENUM_LIST_VALUES,
+                        name,
+                        None,
+                    )?);
+                }
+                TIMESTAMPS_ATTRIBUTE => {
+                    let (created, updated) = Self::parse_timestamps(&meta)?;
+                    result.timestamps_created = created;
+                    result.timestamps_updated = updated;
+                }
                 _ => {
                     if name.starts_with(BUILD_ATTRIBUTE_PREFIX) {
                         let final_name = name.trim_start_matches(BUILD_ATTRIBUTE_PREFIX);
@@ -107,6 +199,103 @@ impl ModelOptions {
 
         Ok(result)
     }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn parse_key_from(attribute: &Attribute) -> Result<Vec<Ident>, syn::Error> {
+        let error = || {
+            Error::Message(format!(
+                "The {} option must be a list of field names, e.g. {}([\"a\", \"b\"])",
+                KEY_FROM_ATTRIBUTE, KEY_FROM_ATTRIBUTE
+            ))
+            .with_tokens(attribute)
+        };
+
+        let array = attribute.parse_args::<ExprArray>().map_err(|_| error())?;
+        let fields = array
+            .elems
+            .iter()
+            .map(|element| match element {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(v), ..
+                }) => Ok(format_ident!("{}", v.value(), span = v.span())),
+                _ => Err(error()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if fields.is_empty() {
+            return Err(error());
+        }
+
+        Ok(fields)
+    }
+
+    fn parse_timestamps(meta: &Meta) -> Result<(Option<Ident>, Option<Ident>), syn::Error> {
+        let error = || {
+            Error::Message(format!(
+                "The {} option must contain the names of the fields, e.g. {}({} = \"a\", {} = \"b\")",
+                TIMESTAMPS_ATTRIBUTE,
+                TIMESTAMPS_ATTRIBUTE,
+                TIMESTAMPS_CREATED_ARGUMENT,
+                TIMESTAMPS_UPDATED_ARGUMENT
+            ))
+            .with_tokens(meta)
+        };
+
+        let meta_list = match meta {
+            Meta::List(v) => v,
+            _ => return Err(error()),
+        };
+
+        let mut created = None;
+        let mut updated = None;
+
+        for argument in &meta_list.nested {
+            let (name, value) = match argument {
+                NestedMeta::Meta(Meta::NameValue(v)) => match &v.lit {
+                    Lit::Str(lit) => (&v.path, lit),
+                    _ => return Err(error()),
+                },
+                _ => return Err(error()),
+            };
+            let value = Some(format_ident!("{}", value.value(), span = value.span()));
+
+            if name.is_ident(TIMESTAMPS_CREATED_ARGUMENT) {
+                created = value;
+            } else if name.is_ident(TIMESTAMPS_UPDATED_ARGUMENT) {
+                updated = value;
+            } else {
+                return Err(error());
+            }
+        }
+
+        if created.is_none() && updated.is_none() {
+            return Err(error());
+        }
+
+        Ok((created, updated))
+    }
+
+    fn parse_schema_version(attribute: &Attribute) -> Result<u32, syn::Error> {
+        let error = || {
+            Error::Message(format!(
+                "The {} option must be a positive integer, e.g. {}(1)",
+                SCHEMA_VERSION_ATTRIBUTE, SCHEMA_VERSION_ATTRIBUTE
+            ))
+            .with_tokens(attribute)
+        };
+
+        let version = attribute
+            .parse_args::<LitInt>()
+            .and_then(|v| v.base10_parse::<u32>())
+            .map_err(|_| error())?;
+
+        if version == 0 {
+            return Err(error());
+        }
+
+        Ok(version)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -142,3 +331,31 @@ impl Default for SyncLevelType {
         Self::None
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum KeyGeneratorType {
+    Default,
+    Simple,
+    Base60,
+    Base58,
+    Sortable,
+}
+
+impl KeyGeneratorType {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The name of the `DBUuid` constructor that generates the keys.
+    pub fn constructor_name(&self) -> &'static str {
+        match self {
+            KeyGeneratorType::Default => "new",
+            KeyGeneratorType::Simple => "new_simple",
+            KeyGeneratorType::Base60 => "new_base60",
+            KeyGeneratorType::Base58 => "new_base58",
+            KeyGeneratorType::Sortable => "new_sortable",
+        }
+    }
+}
@@ -39,7 +39,7 @@ pub fn build_api_model(
 
 pub fn build_api_struct(
     model: &str,
-    _options: &ModelOptions,
+    options: &ModelOptions,
     info: &ModelInfo,
     is_sub_model: bool,
     fields_in_model: &[&FieldInfo],
@@ -62,6 +62,15 @@ pub fn build_api_struct(
             quote! {}
         };
 
+    // Evaluate deny unknown fields attribute.
+    let deny_unknown_fields_attribute = if options.api_deny_unknown_fields {
+        quote! {
+            #[serde(deny_unknown_fields)]
+        }
+    } else {
+        quote! {}
+    };
+
     // Evaluate fields.
     let field_list = fields_in_model.iter().map(|field| {
         let node = field.node.as_field().unwrap();
@@ -69,6 +78,13 @@ pub fn build_api_struct(
         let name = field.name();
         let field_type = field.build_api_field_type(model);
         let deserialize_with = field.build_field_deserialize_with();
+        let flatten_attribute = if field.attributes.flatten {
+            quote! {
+                #[serde(flatten)]
+            }
+        } else {
+            quote! {}
+        };
 
         let attributes = &field.attributes.attributes;
         let attribute_list = field.attributes.attributes_by_model.get(model);
@@ -85,6 +101,7 @@ pub fn build_api_struct(
 
         quote! {
             #attributes
+            #flatten_attribute
             #deserialize_with
             #visibility #name: #field_type,
         }
@@ -136,6 +153,7 @@ pub fn build_api_struct(
         #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize)]
         #[serde(rename_all = "camelCase")]
         #default_attribute
+        #deny_unknown_fields_attribute
         #attributes
         #visibility struct #api_document_name #generics {
             #id_field
@@ -173,6 +191,25 @@ pub fn build_from_to(
             return None;
         }
 
+        // Encrypted fields are exposed decrypted.
+        if let Some(cipher) = &field.attributes.encrypted_with {
+            let base = quote! {
+                <#cipher as ::arangodb_types::traits::Cipher>::decrypt(v)
+            };
+
+            return match field.field_type_kind {
+                Some(FieldTypeKind::NullableOption) | Some(FieldTypeKind::Option) => Some(quote! {
+                    #name: value.#name.map(|v| #base),
+                }),
+                None => Some(quote! {
+                    #name: {
+                        let v = value.#name;
+                        #base
+                    },
+                }),
+            };
+        }
+
         let apply_into = field.attributes.inner_type_by_model.get(model).is_some();
 
         let base = match field.base_type_kind {
@@ -209,6 +246,17 @@ pub fn build_from_to(
                     }
                 }
             }
+            BaseTypeKind::VecBox => {
+                if apply_into {
+                    quote! {
+                        v.into_iter().map(|v| Box::new((*v).into())).collect()
+                    }
+                } else {
+                    quote! {
+                        v
+                    }
+                }
+            }
             BaseTypeKind::VecDBReference => {
                 if apply_into {
                     quote! {
@@ -220,7 +268,7 @@ pub fn build_from_to(
                     }
                 }
             }
-            BaseTypeKind::HashMap => {
+            BaseTypeKind::HashMap | BaseTypeKind::BTreeMap => {
                 if apply_into {
                     quote! {
                         v.into_iter().map(|(k, v)| (k, v.into())).collect()
@@ -279,97 +327,105 @@ pub fn build_from_to(
             return None;
         }
 
-        let apply_into = field.attributes.inner_type_by_model.get(model).is_some();
+        // Read-only fields keep the DB value.
+        if field.attributes.api_readonly {
+            return match field.field_type_kind {
+                Some(FieldTypeKind::NullableOption) => Some(quote! {
+                    #name: ::arangodb_types::types::NullableOption::Missing,
+                }),
+                Some(FieldTypeKind::Option) => Some(quote! {
+                    #name: None,
+                }),
+                None => unreachable!("Plain fields cannot be read-only"),
+            };
+        }
 
-        let base = match field.base_type_kind {
-            BaseTypeKind::Other => {
-                if apply_into {
-                    quote! {
-                        v.into()
-                    }
-                } else {
-                    quote! {
-                        v
-                    }
-                }
-            }
-            BaseTypeKind::Box => {
-                if apply_into {
-                    quote! {
-                        Box::new((*v).into())
-                    }
-                } else {
-                    quote! {
-                        v
-                    }
-                }
-            }
-            BaseTypeKind::Vec => {
-                if apply_into {
-                    quote! {
-                        v.into_iter().map(|v| v.into()).collect()
-                    }
-                } else {
-                    quote! {
-                        v
-                    }
-                }
-            }
-            BaseTypeKind::VecDBReference => {
-                if apply_into {
-                    quote! {
-                        v.into_iter().map(|v| v.map_to_db(|v| Box::new((*v).into()))).collect()
-                    }
-                } else {
-                    quote! {
-                        v.into_iter().map(|v| v.map_to_db(|v| Box::new(v))).collect()
-                    }
-                }
-            }
-            BaseTypeKind::HashMap => {
-                if apply_into {
-                    quote! {
-                        v.into_iter().map(|(k, v)| (k, v.into())).collect()
+        let (_, value) = build_to_db_value(model, field);
+
+        Some(quote! {
+            #name: #value,
+        })
+    });
+
+    // Evaluate patch fields.
+    let patch_field_list = fields_in_model.iter().filter_map(|field| {
+        let name = field.name();
+
+        if field.attributes.skip_in_model.contains(model)
+            || field.attributes.skip_in_model.contains(DB_MODEL_TAG)
+            || field.attributes.api_readonly
+        {
+            return None;
+        }
+
+        let (base, value) = build_to_db_value(model, field);
+
+        // Sub-models with their own API model are patched recursively.
+        let is_recursive = field.attributes.inner_model == InnerModelKind::Struct
+            && field.attributes.inner_type_by_model.contains_key(model)
+            && matches!(
+                field.base_type_kind,
+                BaseTypeKind::Other | BaseTypeKind::Box
+            );
+
+        if !is_recursive {
+            let result = match field.field_type_kind {
+                Some(FieldTypeKind::NullableOption) => quote! {
+                    if !value.#name.is_missing() {
+                        self.#name = #value;
                     }
-                } else {
-                    quote! {
-                        v
+                },
+                Some(FieldTypeKind::Option) => quote! {
+                    if value.#name.is_some() {
+                        self.#name = #value;
                     }
-                }
+                },
+                None => quote! {
+                    self.#name = #value;
+                },
+            };
+
+            return Some(result);
+        }
+
+        let patch = if let BaseTypeKind::Box = field.base_type_kind {
+            quote! {
+                ::arangodb_types::traits::ApplyPatch::apply_patch(&mut **s, *v);
             }
-            BaseTypeKind::DBReference => {
-                if apply_into {
-                    quote! {
-                        v.map_to_db(|v| Box::new((*v).into()))
-                    }
-                } else {
-                    quote! {
-                        v.map_to_db(|v| Box::new(v))
-                    }
-                }
+        } else {
+            quote! {
+                ::arangodb_types::traits::ApplyPatch::apply_patch(s, v);
             }
         };
 
         let result = match field.field_type_kind {
-            Some(FieldTypeKind::NullableOption) | Some(FieldTypeKind::Option) => {
-                if apply_into {
-                    quote! {
-                        #name: value.#name.map(|v| #base),
+            Some(FieldTypeKind::NullableOption) => quote! {
+                match (&mut self.#name, value.#name) {
+                    (_, ::arangodb_types::types::NullableOption::Missing) => {}
+                    (
+                        ::arangodb_types::types::NullableOption::Value(s),
+                        ::arangodb_types::types::NullableOption::Value(v),
+                    ) => {
+                        #patch
                     }
-                } else {
-                    quote! {
-                        #name: {
-                            let v = value.#name;
-                            #base
-                        },
+                    (s, v) => *s = v.map(|v| #base),
+                }
+            },
+            Some(FieldTypeKind::Option) => quote! {
+                match (&mut self.#name, value.#name) {
+                    (_, None) => {}
+                    (Some(s), Some(v)) => {
+                        #patch
                     }
+                    (s, v) => *s = v.map(|v| #base),
                 }
-            }
+            },
             None => quote! {
-                #name: {
+                {
+                    let s = &mut self.#name;
                     let v = value.#name;
-                    #base
-                },
+                    #patch
+                }
             },
         };
 
@@ -419,6 +475,13 @@ pub fn build_from_to(
                 }
             }
         }
+
+        impl #generics ::arangodb_types::traits::ApplyPatch<#api_document_name #generics> for #document_name #generics {
+            #[allow(unused_variables)]
+            fn apply_patch(&mut self, value: #api_document_name #generics) {
+                #(#patch_field_list)*
+            }
+        }
     })
 }
 
@@ -426,6 +489,144 @@ pub fn build_from_to(
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Builds the conversion of a field from the API model to the DB model, returning both the
+/// conversion of the inner value `v` and the whole one of `value.<field>`.
+fn build_to_db_value(model: &str, field: &FieldInfo) -> (TokenStream, TokenStream) {
+    let name = field.name();
+
+    // Encrypted fields are stored encrypted.
+    if let Some(cipher) = &field.attributes.encrypted_with {
+        let base = quote! {
+            <#cipher as ::arangodb_types::traits::Cipher>::encrypt(v)
+        };
+
+        let value = match field.field_type_kind {
+            Some(FieldTypeKind::NullableOption) | Some(FieldTypeKind::Option) => quote! {
+                value.#name.map(|v| #base)
+            },
+            None => quote! {
+                {
+                    let v = value.#name;
+                    #base
+                }
+            },
+        };
+
+        return (base, value);
+    }
+
+    let apply_into = field.attributes.inner_type_by_model.get(model).is_some();
+
+    let base = match field.base_type_kind {
+        BaseTypeKind::Other => {
+            if apply_into {
+                quote! {
+                    v.into()
+                }
+            } else {
+                quote! {
+                    v
+                }
+            }
+        }
+        BaseTypeKind::Box => {
+            if apply_into {
+                quote! {
+                    Box::new((*v).into())
+                }
+            } else {
+                quote! {
+                    v
+                }
+            }
+        }
+        BaseTypeKind::Vec => {
+            if apply_into {
+                quote! {
+                    v.into_iter().map(|v| v.into()).collect()
+                }
+            } else {
+                quote! {
+                    v
+                }
+            }
+        }
+        BaseTypeKind::VecBox => {
+            if apply_into {
+                quote! {
+                    v.into_iter().map(|v| Box::new((*v).into())).collect()
+                }
+            } else {
+                quote! {
+                    v
+                }
+            }
+        }
+        BaseTypeKind::VecDBReference => {
+            if apply_into {
+                quote! {
+                    v.into_iter().map(|v| v.map_to_db(|v| Box::new((*v).into()))).collect()
+                }
+            } else {
+                quote! {
+                    v.into_iter().map(|v| v.map_to_db(|v| Box::new(v))).collect()
+                }
+            }
+        }
+        BaseTypeKind::HashMap | BaseTypeKind::BTreeMap => {
+            if apply_into {
+                quote! {
+                    v.into_iter().map(|(k, v)| (k, v.into())).collect()
+                }
+            } else {
+                quote! {
+                    v
+                }
+            }
+        }
+        BaseTypeKind::DBReference => {
+            if apply_into {
+                quote! {
+                    v.map_to_db(|v| Box::new((*v).into()))
+                }
+            } else {
+                quote! {
+                    v.map_to_db(|v| Box::new(v))
+                }
+            }
+        }
+    };
+
+    let value = match field.field_type_kind {
+        Some(FieldTypeKind::NullableOption) | Some(FieldTypeKind::Option) => {
+            if apply_into {
+                quote! {
+                    value.#name.map(|v| #base)
+                }
+            } else {
+                quote! {
+                    {
+                        let v = value.#name;
+                        #base
+                    }
+                }
+            }
+        }
+        None => quote! {
+            {
+                let v = value.#name;
+                #base
+            }
+        },
+    };
+
+    (base, value)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 pub fn build_api_fields(
     model: &str,
     _options: &ModelOptions,
@@ -463,18 +664,28 @@ pub fn build_api_fields(
                         .map(|v| v.to_token_stream().to_string())
                         .unwrap_or_else(|| field.get_inner_db_type_name());
                     let inner_api_type_enum = format_ident!("{}Field", inner_api_type_name);
-                    let sub_pattern = format!("{}.{{}}", db_name);
 
-                    enum_fields.push(quote! {
-                        #name(Option<#inner_api_type_enum>),
-                    });
-                    path_fields.push(quote! {
-                        #api_field_enum_name::#name(v) => if let Some(v) = v {
-                            format!(#sub_pattern, v.path()).into()
-                        } else {
-                            #db_name.into()
-                        }
-                    });
+                    if field.attributes.flatten {
+                        enum_fields.push(quote! {
+                            #name(#inner_api_type_enum),
+                        });
+                        path_fields.push(quote! {
+                            #api_field_enum_name::#name(v) => v.path(),
+                        });
+                    } else {
+                        let sub_pattern = format!("{}.{{}}", db_name);
+
+                        enum_fields.push(quote! {
+                            #name(Option<#inner_api_type_enum>),
+                        });
+                        path_fields.push(quote! {
+                            #api_field_enum_name::#name(v) => if let Some(v) = v {
+                                format!(#sub_pattern, v.path()).into()
+                            } else {
+                                #db_name.into()
+                            }
+                        });
+                    }
                 }
             },
             InnerModelKind::Data | InnerModelKind::Enum => match field.base_type_kind {
@@ -537,6 +748,12 @@ pub fn build_api_fields(
                 }
             }
         }
+
+        impl ::std::fmt::Display for #api_field_enum_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(&self.path())
+            }
+        }
     })
 }
 
@@ -574,21 +791,23 @@ fn build_api_document_impl(
                     BaseTypeKind::Other | BaseTypeKind::Box => Some(quote! {
                         v.map_values_to_null();
                     }),
-                    BaseTypeKind::Vec => Some(quote! {
+                    BaseTypeKind::Vec | BaseTypeKind::VecBox => Some(quote! {
                         for v in v {
                             v.map_values_to_null();
                         }
                     }),
                     BaseTypeKind::VecDBReference => {
-                        panic!("Cannot declare a VecDBReference value as Struct or Enum model")
+                        unreachable!(
+                            "Cannot declare a VecDBReference value as Struct or Enum model"
+                        )
                     }
-                    BaseTypeKind::HashMap => Some(quote! {
+                    BaseTypeKind::HashMap | BaseTypeKind::BTreeMap => Some(quote! {
                         for (_, v) in v {
                             v.map_values_to_null();
                         }
                     }),
                     BaseTypeKind::DBReference => {
-                        panic!("Cannot declare a DBReference value as Struct or Enum model")
+                        unreachable!("Cannot declare a DBReference value as Struct or Enum model")
                     }
                 };
 
@@ -1,11 +1,15 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::format_ident;
 use quote::quote;
 use syn::spanned::Spanned;
 
-use crate::constants::{DB_MODEL_TAG, MUTEX_FIELD_DB_NAME, MUTEX_FIELD_NAME};
+use crate::constants::{
+    DB_MODEL_TAG, KEY_FROM_SEPARATOR, MUTEX_FIELD_DB_NAME, MUTEX_FIELD_NAME,
+    SCHEMA_VERSION_FIELD_DB_NAME, SCHEMA_VERSION_FIELD_NAME,
+};
 use crate::data::{
     BaseTypeKind, FieldInfo, FieldTypeKind, InnerModelKind, ModelInfo, ModelOptions,
+    KEY_GENERATOR_ATTRIBUTE, TIMESTAMPS_ATTRIBUTE,
 };
 use crate::errors::Error;
 use crate::utils::from_snake_case_to_pascal_case;
@@ -44,6 +48,12 @@ pub fn build_db_model(options: &ModelOptions, info: &ModelInfo) -> Result<TokenS
         quote! {}
     };
 
+    let singleton_collection_tokens = if options.singleton_collection {
+        build_singleton_collection(options, info, &fields_in_db)?
+    } else {
+        quote! {}
+    };
+
     let edge_db_document_impl_tokens = check_and_build_edge_db_impl(options, info, &fields_in_db)?;
     let aql_mapping_impl_tokens =
         build_db_struct_aql_mapping_impl(options, info, false, &fields_in_db)?;
@@ -54,6 +64,7 @@ pub fn build_db_model(options: &ModelOptions, info: &ModelInfo) -> Result<TokenS
         #impl_tokens
         #field_list_tokens
         #sync_impl_tokens
+        #singleton_collection_tokens
         #edge_db_document_impl_tokens
         #aql_mapping_impl_tokens
     })
@@ -72,6 +83,8 @@ fn build_struct(
     let generics = info.item.generics();
     let document_name = &info.document_name;
 
+    info.check_eq_derive(DB_MODEL_TAG, fields_in_db)?;
+
     let all_fields_are_optional_or_db_properties =
         info.check_all_db_fields_are_optional_or_properties();
 
@@ -100,19 +113,33 @@ fn build_struct(
         quote! {}
     };
 
+    // Evaluate schema version field.
+    let schema_version_field = if let Some(schema_version) = options.schema_version {
+        let name = format_ident!("{}", SCHEMA_VERSION_FIELD_NAME);
+        let db_name = SCHEMA_VERSION_FIELD_DB_NAME;
+
+        quote! {
+            #[serde(default)]
+            #[serde(rename = #db_name)]
+            pub #name: ::arangodb_types::types::DBSchemaVersion<#schema_version>,
+        }
+    } else {
+        quote! {}
+    };
+
     // Evaluate rest fields.
     let field_list = fields_in_db.iter().map(|field| {
         let node = field.node.as_field().unwrap();
         let visibility = &node.vis;
         let attribute_list = &field.attributes.attributes;
         let name = field.name();
-        let db_name = &field.db_name;
+        let name_attribute = field.build_db_field_name_attribute();
         let field_type = field.build_db_field_type();
         let deserialize_with = field.build_field_deserialize_with();
 
         quote! {
             #(#attribute_list)*
-            #[serde(rename = #db_name)]
+            #name_attribute
             #deserialize_with
             #visibility #name: #field_type,
         }
@@ -144,6 +171,8 @@ fn build_struct(
 
             #lock_field
 
+            #schema_version_field
+
             #(#field_list)*
         }
     })
@@ -204,10 +233,374 @@ fn build_impl(
         quote! {}
     };
 
+    // Evaluate with_key method.
+    let key_field = info.get_key_field().unwrap();
+    let with_key_method_tokens = if !info.item_attributes.skip_default
+        && all_fields_are_optional_or_db_properties
+        && matches!(key_field.field_type_kind, Some(FieldTypeKind::Option))
+    {
+        let key_name = key_field.name();
+        let key_type = key_field.inner_type.as_ref().unwrap();
+
+        quote! {
+            /// Creates a default document with only the key set.
+            #[allow(clippy::needless_update)]
+            pub fn with_key(key: #key_type) -> Self {
+                Self {
+                    #key_name: Some(key),
+                    ..Default::default()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Evaluate redact_except method.
+    let redact_except_method_tokens =
+        build_db_struct_redact_except_method(options, info, fields_in_db)?;
+
+    // Evaluate eq_ignoring_metadata method.
+    let eq_ignoring_metadata_method_tokens = {
+        let fields = fields_in_db
+            .iter()
+            .filter(|field| field.name() != key_field.name())
+            .map(|field| {
+                let name = field.name();
+
+                quote! {
+                    if ::arangodb_types::serde_json::to_value(&self.#name)?
+                        != ::arangodb_types::serde_json::to_value(&other.#name)?
+                    {
+                        return Ok(false);
+                    }
+                }
+            });
+
+        quote! {
+            /// Compares all the fields except the key, the revision and the mutex.
+            /// Fails if any of the fields cannot be serialized.
+            pub fn eq_ignoring_metadata(
+                &self,
+                other: &Self,
+            ) -> Result<bool, ::arangodb_types::serde_json::Error> {
+                #(#fields)*
+
+                Ok(true)
+            }
+        }
+    };
+
+    // Evaluate as_filter_aql method.
+    let as_filter_aql_method_tokens = build_db_struct_as_filter_aql_method(options, fields_in_db);
+
+    // Evaluate estimate_cost method.
+    let estimate_cost_method_tokens = build_db_struct_estimate_cost_method(options, fields_in_db);
+
+    // Evaluate as_contains_filter_aql method.
+    let as_contains_filter_aql_method_tokens =
+        build_db_struct_as_contains_filter_aql_method(options, fields_in_db);
+
+    // Evaluate estimate_contains_cost method.
+    let estimate_contains_cost_method_tokens =
+        build_db_struct_estimate_contains_cost_method(options, fields_in_db);
+
+    // Evaluate filter_report method.
+    let filter_report_method_tokens = if !options.skip_fields && !fields_in_db.is_empty() {
+        let enum_name = &info.field_enum_name;
+        let fields = fields_in_db.iter().filter_map(|field| {
+            let name = field.name();
+            let name_str = from_snake_case_to_pascal_case(&name.to_string());
+            let variant = format_ident!("{}", name_str, span = name.span());
+
+            match field.field_type_kind {
+                Some(FieldTypeKind::NullableOption) => Some(quote! {
+                    if filter.#name.is_missing() && !self.#name.is_missing() {
+                        self.#name = ::arangodb_types::types::NullableOption::Missing;
+                        cleared.push(#enum_name::#variant(None));
+                    }
+                }),
+                Some(FieldTypeKind::Option) => Some(quote! {
+                    if filter.#name.is_none() && self.#name.is_some() {
+                        self.#name = None;
+                        cleared.push(#enum_name::#variant(None));
+                    }
+                }),
+                None => None,
+            }
+        });
+
+        quote! {
+            /// Keeps only the fields present in `filter`, like the `return_fields` projection,
+            /// returning the fields that have been cleared.
+            pub fn filter_report(&mut self, filter: &Self) -> Vec<#enum_name> {
+                let mut cleared = Vec::new();
+
+                #(#fields)*
+
+                cleared
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Evaluate load methods.
+    let load_method_tokens = if options.generate_loaders {
+        fields_in_db
+            .iter()
+            .filter_map(|field| build_load_reference_method(field))
+            .collect()
+    } else {
+        quote! {}
+    };
+
+    // Evaluate migrate method.
+    let migrate_method_tokens = if let Some(schema_version) = options.schema_version {
+        let name = format_ident!("{}", SCHEMA_VERSION_FIELD_NAME);
+
+        quote! {
+            pub const SCHEMA_VERSION: u32 = #schema_version;
+
+            /// Upgrades the document to the current schema version one version at a time.
+            /// Returns whether the document has been migrated.
+            pub fn migrate(&mut self) -> Result<bool, ::arangodb_types::anyhow::Error> {
+                let is_outdated = self.#name.is_outdated();
+
+                while self.#name.is_outdated() {
+                    let version = self.#name.version();
+                    ::arangodb_types::traits::DBSchemaMigration::migrate_from(self, version)?;
+                    self.#name = ::arangodb_types::types::DBSchemaVersion::new(version + 1);
+                }
+
+                Ok(is_outdated)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Evaluate expiration methods.
+    let ttl_method_tokens = build_ttl_methods(fields_in_db)?;
+
     // Build result.
     Ok(quote! {
         impl #generics #document_name #generics {
             #all_null_method_tokens
+            #with_key_method_tokens
+            #redact_except_method_tokens
+            #eq_ignoring_metadata_method_tokens
+            #as_filter_aql_method_tokens
+            #estimate_cost_method_tokens
+            #as_contains_filter_aql_method_tokens
+            #estimate_contains_cost_method_tokens
+            #filter_report_method_tokens
+            #load_method_tokens
+            #migrate_method_tokens
+            #ttl_method_tokens
+        }
+    })
+}
+
+fn build_ttl_methods(fields_in_db: &[&FieldInfo]) -> Result<TokenStream, syn::Error> {
+    let mut ttl_fields = fields_in_db.iter().filter(|field| field.attributes.ttl);
+
+    let field = match ttl_fields.next() {
+        Some(v) => v,
+        None => return Ok(quote! {}),
+    };
+
+    if let Some(other) = ttl_fields.next() {
+        return Err(Error::Message(format!(
+            "Only one field can be marked as ttl but '{}' and '{}' are",
+            field.name(),
+            other.name()
+        ))
+        .with_tokens(&other.node));
+    }
+
+    let inner_type = field.inner_type.as_ref().unwrap().to_string();
+    let is_expiration = inner_type.ends_with("DBExpiration");
+
+    if field.base_type_kind != BaseTypeKind::Other
+        || !(is_expiration || inner_type.ends_with("DBDateTime"))
+    {
+        return Err(Error::Message(format!(
+            "The ttl field '{}' must be a DBExpiration or a DBDateTime",
+            field.name()
+        ))
+        .with_tokens(&field.node));
+    }
+
+    let name = field.name();
+    let is_expired = match field.field_type_kind {
+        Some(FieldTypeKind::NullableOption) => quote! {
+            match &self.#name {
+                ::arangodb_types::types::NullableOption::Value(v) => v.is_expired(),
+                _ => false,
+            }
+        },
+        Some(FieldTypeKind::Option) => quote! {
+            match &self.#name {
+                Some(v) => v.is_expired(),
+                None => false,
+            }
+        },
+        None => quote! {
+            self.#name.is_expired()
+        },
+    };
+
+    // TTL indexes only work with timestamps in seconds, i.e. DBExpiration.
+    let ttl_index = if is_expiration {
+        let db_name = &field.db_name;
+
+        quote! {
+            /// The definition of the TTL index that removes the expired documents.
+            /// Use it along with `DBInfo::ensure_index`.
+            pub fn ttl_index() -> ::arangodb_types::serde_json::Value {
+                ::arangodb_types::serde_json::json!({
+                    "type": "ttl",
+                    "fields": [#db_name],
+                    "expireAfter": 0,
+                })
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        /// Whether the document has expired. Documents without expiration never expire.
+        pub fn is_expired(&self) -> bool {
+            #is_expired
+        }
+
+        #ttl_index
+    })
+}
+
+fn build_max_len_checks(fields_in_db: &[&FieldInfo]) -> Result<Vec<TokenStream>, syn::Error> {
+    let mut result = Vec::new();
+
+    for field in fields_in_db {
+        let max_len = match &field.attributes.max_len {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let inner_type = field.inner_type.as_ref().unwrap().to_string();
+        if field.base_type_kind != BaseTypeKind::Other
+            || !(inner_type.ends_with("String") || inner_type.ends_with("ArcStr"))
+        {
+            return Err(Error::Message(format!(
+                "The max_len field '{}' must be a String or an ArcStr",
+                field.name()
+            ))
+            .with_tokens(&field.node));
+        }
+
+        let name = field.name();
+        let value = max_len.value;
+        let (length, unit) = if max_len.in_bytes {
+            (quote! { v.len() }, "bytes")
+        } else {
+            (quote! { v.chars().count() }, "characters")
+        };
+        let message = format!(
+            "The field '{}' exceeds the maximum length of {} {}",
+            name, value, unit
+        );
+
+        let check = quote! {
+            if #length > #value {
+                return Err(::arangodb_types::anyhow::anyhow!(#message));
+            }
+        };
+
+        result.push(match field.field_type_kind {
+            Some(FieldTypeKind::NullableOption) => quote! {
+                if let ::arangodb_types::types::NullableOption::Value(v) = &self.#name {
+                    #check
+                }
+            },
+            Some(FieldTypeKind::Option) => quote! {
+                if let Some(v) = &self.#name {
+                    #check
+                }
+            },
+            None => quote! {
+                {
+                    let v = &self.#name;
+                    #check
+                }
+            },
+        });
+    }
+
+    Ok(result)
+}
+
+fn build_load_reference_method(field: &FieldInfo) -> Option<TokenStream> {
+    if !matches!(
+        field.base_type_kind,
+        BaseTypeKind::DBReference | BaseTypeKind::VecDBReference
+    ) {
+        return None;
+    }
+
+    let name = field.name();
+    let method_name = format_ident!("load_{}", name, span = name.span());
+    let inner_type = field.inner_type.as_ref().unwrap();
+    let value = match field.field_type_kind {
+        Some(FieldTypeKind::NullableOption) => quote! { self.#name.as_mut_ref_option() },
+        Some(FieldTypeKind::Option) => quote! { self.#name.as_mut() },
+        None => quote! { Some(&mut self.#name) },
+    };
+
+    let load_tokens = if field.base_type_kind == BaseTypeKind::DBReference {
+        quote! {
+            if let Some(document) = value.resolve(db_info, None).await? {
+                *value = ::arangodb_types::types::DBReference::Document(Box::new(document));
+            }
+        }
+    } else {
+        quote! {
+            if value.is_empty() {
+                return Ok(());
+            }
+
+            let collection =
+                <#inner_type as ::arangodb_types::traits::DBDocumentCollection>::collection(db_info);
+            let keys = value.iter().map(|v| v.key()).collect::<Vec<_>>();
+            let documents = ::arangodb_types::traits::DBCollection::get_many_by_key(
+                collection.as_ref(),
+                keys.into_iter(),
+                None,
+            )
+            .await?;
+
+            for (reference, document) in value.iter_mut().zip(documents) {
+                if let Some(document) = document {
+                    *reference =
+                        ::arangodb_types::types::DBReference::Document(Box::new(document));
+                }
+            }
+        }
+    };
+
+    Some(quote! {
+        /// Replaces the references of the field by the documents they point to. References to
+        /// missing documents are kept as keys.
+        pub async fn #method_name(
+            &mut self,
+            db_info: &::std::sync::Arc<::arangodb_types::types::DBInfo>,
+        ) -> Result<(), ::arangodb_types::anyhow::Error> {
+            if let Some(value) = #value {
+                #load_tokens
+            }
+
+            Ok(())
         }
     })
 }
@@ -261,21 +654,21 @@ fn build_db_document_impl(
                         BaseTypeKind::Other | BaseTypeKind::Box => Some(quote! {
                             v.map_values_to_null();
                         }),
-                        BaseTypeKind::Vec => Some(quote! {
+                        BaseTypeKind::Vec | BaseTypeKind::VecBox => Some(quote! {
                             for v in v {
                                 v.map_values_to_null();
                             }
                         }),
                         BaseTypeKind::VecDBReference => {
-                            panic!("Cannot declare a VecDBReference value as Struct or Enum model")
+                            unreachable!("Cannot declare a VecDBReference value as Struct or Enum model")
                         }
-                        BaseTypeKind::HashMap => Some(quote! {
+                        BaseTypeKind::HashMap | BaseTypeKind::BTreeMap => Some(quote! {
                             for (_, v) in v {
                                 v.map_values_to_null();
                             }
                         }),
                         BaseTypeKind::DBReference => {
-                            panic!("Cannot declare a DBReference value as Struct or Enum model")
+                            unreachable!("Cannot declare a DBReference value as Struct or Enum model")
                         }
                     };
 
@@ -434,6 +827,195 @@ fn build_db_document_impl(
         }
     };
 
+    // Evaluate validate methods.
+    let max_len_checks = build_max_len_checks(fields_in_db)?;
+    let validate_method_tokens = match &options.validate_with {
+        Some(validate_with) => quote! {
+            fn validate(&self) -> Result<(), ::arangodb_types::anyhow::Error> {
+                #(#max_len_checks)*
+
+                #validate_with(self)
+            }
+        },
+        None if !max_len_checks.is_empty() => quote! {
+            fn validate(&self) -> Result<(), ::arangodb_types::anyhow::Error> {
+                #(#max_len_checks)*
+
+                Ok(())
+            }
+        },
+        None => quote! {},
+    };
+
+    // Evaluate normalize method.
+    let normalize_method_tokens = match &options.normalize_with {
+        Some(normalize_with) => quote! {
+            fn normalize(&mut self) -> bool {
+                #normalize_with(self)
+            }
+        },
+        None => quote! {},
+    };
+
+    // Evaluate compute key method.
+    let compute_key_method_tokens = if !options.key_from.is_empty() {
+        let mut parts = Vec::with_capacity(options.key_from.len());
+
+        for field_name in &options.key_from {
+            let field = match fields_in_db.iter().find(|v| v.name() == field_name) {
+                Some(v) => v,
+                None => {
+                    return Err(Error::Message(format!(
+                        "The field '{}' of the key_from option does not exist",
+                        field_name
+                    ))
+                    .with_tokens(field_name));
+                }
+            };
+            let name = field.name();
+
+            parts.push(match field.field_type_kind {
+                Some(FieldTypeKind::NullableOption) => quote! {
+                    match &self.#name {
+                        ::arangodb_types::types::NullableOption::Value(v) => v.to_string(),
+                        _ => return Ok(None),
+                    }
+                },
+                Some(FieldTypeKind::Option) => quote! {
+                    match &self.#name {
+                        Some(v) => v.to_string(),
+                        None => return Ok(None),
+                    }
+                },
+                None => quote! {
+                    self.#name.to_string()
+                },
+            });
+        }
+
+        let separator = KEY_FROM_SEPARATOR.to_string();
+
+        quote! {
+            fn compute_key(&self) -> Result<Option<Self::Key>, ::arangodb_types::anyhow::Error> {
+                let parts: &[String] = &[#(#parts),*];
+
+                // Reject the separator inside the parts to keep the key unambiguous.
+                if let Some(part) = parts.iter().find(|v| v.contains(#KEY_FROM_SEPARATOR)) {
+                    return Err(::arangodb_types::anyhow::anyhow!(
+                        "The part '{}' of the key cannot contain the separator '{}'",
+                        part,
+                        #KEY_FROM_SEPARATOR
+                    ));
+                }
+
+                let key = parts.join(#separator);
+                key.parse().map(Some).map_err(|_| {
+                    ::arangodb_types::anyhow::anyhow!("The computed key '{}' is not a valid key", key)
+                })
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Evaluate assign key method.
+    let assign_key_method_tokens = if let Some(key_generator) = &options.key_generator {
+        let key_field = info.get_key_field().unwrap();
+        let key_type = key_field.inner_type.as_ref().unwrap();
+
+        if !key_type.to_string().ends_with("DBUuid") {
+            return Err(Error::Message(format!(
+                "The {} option requires the key to be a DBUuid",
+                KEY_GENERATOR_ATTRIBUTE
+            ))
+            .with_tokens(key_type));
+        }
+
+        let constructor = format_ident!("{}", key_generator.constructor_name());
+
+        quote! {
+            fn assign_key(&mut self) {
+                if self.db_key.is_none() {
+                    self.db_key = Some(::arangodb_types::types::DBUuid::#constructor());
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Evaluate touch timestamps method.
+    let touch_timestamps_method_tokens = {
+        let created = match &options.timestamps_created {
+            Some(field_name) => {
+                let field = get_timestamp_field(fields_in_db, field_name)?;
+                let name = field.name();
+
+                match field.field_type_kind {
+                    Some(FieldTypeKind::NullableOption) => quote! {
+                        if is_new && !self.#name.is_value() {
+                            self.#name = ::arangodb_types::types::NullableOption::Value(now.clone());
+                        }
+                    },
+                    Some(FieldTypeKind::Option) => quote! {
+                        if is_new && self.#name.is_none() {
+                            self.#name = Some(now.clone());
+                        }
+                    },
+                    None => quote! {
+                        if is_new {
+                            self.#name = now.clone();
+                        }
+                    },
+                }
+            }
+            None => quote! {},
+        };
+
+        let updated = match &options.timestamps_updated {
+            Some(field_name) => {
+                let field = get_timestamp_field(fields_in_db, field_name)?;
+                let name = field.name();
+
+                match field.field_type_kind {
+                    Some(FieldTypeKind::NullableOption) => quote! {
+                        self.#name = ::arangodb_types::types::NullableOption::Value(now);
+                    },
+                    Some(FieldTypeKind::Option) => quote! {
+                        self.#name = Some(now);
+                    },
+                    None => quote! {
+                        self.#name = now;
+                    },
+                }
+            }
+            None => quote! {},
+        };
+
+        if options.timestamps_created.is_some() || options.timestamps_updated.is_some() {
+            quote! {
+                #[allow(unused_variables)]
+                fn touch_timestamps(&mut self, is_new: bool) {
+                    let now = ::arangodb_types::types::DBDateTime::now();
+                    #created
+                    #updated
+                }
+            }
+        } else {
+            quote! {}
+        }
+    };
+
+    let validate_on_write_method_tokens = if options.validate_on_write {
+        quote! {
+            fn validate_on_write() -> bool {
+                true
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // Build result.
     let key_field = info.get_key_field().unwrap();
     let key_type = key_field.inner_type.as_ref().unwrap();
@@ -463,6 +1045,7 @@ fn build_db_document_impl(
             #is_all_missing_method_tokens
             #is_all_null_method_tokens
             #is_all_null_or_missing_method_tokens
+            #validate_on_write_method_tokens
 
             // SETTERS --------------------------------------------------------
 
@@ -473,10 +1056,42 @@ fn build_db_document_impl(
             // METHODS ----------------------------------------------------------------
 
             #map_values_to_null_method_tokens
+            #validate_method_tokens
+            #normalize_method_tokens
+            #compute_key_method_tokens
+            #assign_key_method_tokens
+            #touch_timestamps_method_tokens
         }
     })
 }
 
+fn get_timestamp_field<'a, 'b>(
+    fields_in_db: &[&'b FieldInfo<'a>],
+    field_name: &Ident,
+) -> Result<&'b FieldInfo<'a>, syn::Error> {
+    let field = match fields_in_db.iter().find(|v| v.name() == field_name) {
+        Some(v) => *v,
+        None => {
+            return Err(Error::Message(format!(
+                "The field '{}' of the {} option does not exist",
+                field_name, TIMESTAMPS_ATTRIBUTE
+            ))
+            .with_tokens(field_name));
+        }
+    };
+
+    let inner_type = field.inner_type.as_ref().unwrap().to_string();
+    if field.base_type_kind != BaseTypeKind::Other || !inner_type.ends_with("DBDateTime") {
+        return Err(Error::Message(format!(
+            "The field '{}' of the {} option must be a DBDateTime",
+            field_name, TIMESTAMPS_ATTRIBUTE
+        ))
+        .with_tokens(field_name));
+    }
+
+    Ok(field)
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -548,19 +1163,30 @@ pub fn build_db_struct_field_list(
                     let inner_type_name = field.get_inner_db_type_name();
                     let inner_type_enum =
                         format_ident!("{}Field", inner_type_name, span = inner_type.span());
-                    let sub_pattern = format!("{}.{{}}", db_name);
 
-                    enum_fields.push(quote! {
-                        #[serde(rename = #db_name)]
-                        #name(Option<#inner_type_enum>),
-                    });
-                    path_fields.push(quote! {
-                        #enum_name::#name(v) => if let Some(v) = v {
-                            format!(#sub_pattern, v.path()).into()
-                        } else {
-                            #db_name.into()
-                        },
-                    });
+                    if field.attributes.flatten {
+                        enum_fields.push(quote! {
+                            #[serde(rename = #db_name)]
+                            #name(#inner_type_enum),
+                        });
+                        path_fields.push(quote! {
+                            #enum_name::#name(v) => v.path(),
+                        });
+                    } else {
+                        let sub_pattern = format!("{}.{{}}", db_name);
+
+                        enum_fields.push(quote! {
+                            #[serde(rename = #db_name)]
+                            #name(Option<#inner_type_enum>),
+                        });
+                        path_fields.push(quote! {
+                            #enum_name::#name(v) => if let Some(v) = v {
+                                format!(#sub_pattern, v.path()).into()
+                            } else {
+                                #db_name.into()
+                            },
+                        });
+                    }
                 }
             },
         }
@@ -587,6 +1213,12 @@ pub fn build_db_struct_field_list(
                 }
             }
         }
+
+        impl ::std::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(&self.path())
+            }
+        }
     })
 }
 
@@ -594,13 +1226,538 @@ pub fn build_db_struct_field_list(
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
-fn build_sync_impl(options: &ModelOptions, info: &ModelInfo) -> Result<TokenStream, syn::Error> {
-    let document_name = &info.document_name;
-    let collection_name = &info.collection_name;
-    let config_collection_key_method =
-        if let Some(sync_collection_key_method) = &options.sync_collection_key_method {
-            sync_collection_key_method.clone()
-        } else {
+pub fn build_db_struct_as_filter_aql_method(
+    options: &ModelOptions,
+    fields_in_db: &[&FieldInfo],
+) -> TokenStream {
+    let crate_path = if options.relative_imports {
+        quote!(crate)
+    } else {
+        quote!(::arangodb_types)
+    };
+
+    // Flattened fields live at the root of the document so they add their own filter.
+    let flattened_fields = fields_in_db
+        .iter()
+        .filter(|field| field.attributes.flatten)
+        .map(|field| {
+            let name = field.name();
+
+            quote! {
+                self.#name.as_filter_aql(aql)?;
+            }
+        });
+
+    let fields = fields_in_db
+        .iter()
+        .filter(|field| !field.attributes.flatten)
+        .map(|field| {
+            let name = field.name();
+            let db_name = &field.db_name;
+            let variable = if let Some(serde_with) = &field.attributes.serde_with {
+                // Bind the value with the same encoding it has in the DB.
+                quote! {
+                    aql.add_variable_as_json(#serde_with::serialize(
+                        &self.#name,
+                        #crate_path::serde_json::value::Serializer,
+                    )?)
+                }
+            } else {
+                quote! {
+                    aql.add_variable(&self.#name)?
+                }
+            };
+            let condition = quote! {
+                let variable = #variable.unwrap();
+                conditions.push(format!(
+                    "{}.{} == {}",
+                    #crate_path::aql::AQL_DOCUMENT_ID,
+                    #db_name,
+                    variable
+                ));
+            };
+
+            match field.field_type_kind {
+                Some(FieldTypeKind::NullableOption) => quote! {
+                    if !self.#name.is_missing() {
+                        #condition
+                    }
+                },
+                Some(FieldTypeKind::Option) => quote! {
+                    if self.#name.is_some() {
+                        #condition
+                    }
+                },
+                None => quote! {
+                    {
+                        #condition
+                    }
+                },
+            }
+        });
+
+    quote! {
+        /// Adds a filter to `aql` that matches the present fields of this document against
+        /// the ones of `i`, i.e. a query by example.
+        pub fn as_filter_aql(
+            &self,
+            aql: &mut #crate_path::aql::AqlBuilder,
+        ) -> Result<(), #crate_path::serde_json::Error> {
+            let mut conditions: Vec<String> = Vec::new();
+
+            #(#fields)*
+
+            if !conditions.is_empty() {
+                aql.filter_step(conditions.join(" && ").into());
+            }
+
+            #(#flattened_fields)*
+
+            Ok(())
+        }
+    }
+}
+
+pub fn build_db_struct_estimate_cost_method(
+    options: &ModelOptions,
+    fields_in_db: &[&FieldInfo],
+) -> TokenStream {
+    let crate_path = if options.relative_imports {
+        quote!(crate)
+    } else {
+        quote!(::arangodb_types)
+    };
+
+    let fields = fields_in_db.iter().map(|field| {
+        let name = field.name();
+        let db_name = &field.db_name;
+
+        // Flattened fields live at the root of the document.
+        if field.attributes.flatten {
+            return quote! {
+                cost.merge(self.#name.estimate_cost(indexed_fields));
+            };
+        }
+
+        match field.field_type_kind {
+            Some(FieldTypeKind::NullableOption) => quote! {
+                if !self.#name.is_missing() {
+                    cost.add_field(#db_name, indexed_fields);
+                }
+            },
+            Some(FieldTypeKind::Option) => quote! {
+                if self.#name.is_some() {
+                    cost.add_field(#db_name, indexed_fields);
+                }
+            },
+            None => quote! {
+                cost.add_field(#db_name, indexed_fields);
+            },
+        }
+    });
+
+    quote! {
+        /// Estimates the cost of the filter built by `as_filter_aql` flagging the fields
+        /// that are not in `indexed_fields`.
+        pub fn estimate_cost(
+            &self,
+            indexed_fields: &::std::collections::HashSet<String>,
+        ) -> #crate_path::aql::FilterCost {
+            let mut cost = #crate_path::aql::FilterCost::default();
+
+            #(#fields)*
+
+            cost
+        }
+    }
+}
+
+pub fn build_db_struct_as_contains_filter_aql_method(
+    options: &ModelOptions,
+    fields_in_db: &[&FieldInfo],
+) -> TokenStream {
+    let crate_path = if options.relative_imports {
+        quote!(crate)
+    } else {
+        quote!(::arangodb_types)
+    };
+
+    let flattened_fields: Vec<_> = fields_in_db
+        .iter()
+        .filter(|field| field.attributes.flatten)
+        .map(|field| {
+            let name = field.name();
+
+            quote! {
+                self.#name.as_contains_filter_aql(aql, negated)?;
+            }
+        })
+        .collect();
+
+    let fields: Vec<_> = fields_in_db
+        .iter()
+        .filter(|field| is_array_filter_field(field))
+        .map(|field| {
+            let name = field.name();
+            let db_name = &field.db_name;
+            let condition = quote! {
+                for value in values {
+                    let variable = aql.add_variable(value)?.unwrap();
+                    conditions.push((variable, #db_name));
+                }
+            };
+
+            match field.field_type_kind {
+                Some(FieldTypeKind::NullableOption) => quote! {
+                    if let #crate_path::types::NullableOption::Value(values) = &self.#name {
+                        #condition
+                    }
+                },
+                Some(FieldTypeKind::Option) => quote! {
+                    if let Some(values) = &self.#name {
+                        #condition
+                    }
+                },
+                None => quote! {
+                    {
+                        let values = &self.#name;
+                        #condition
+                    }
+                },
+            }
+        })
+        .collect();
+
+    let doc = quote! {
+        /// Adds a filter to `aql` that requires every element of the array fields present in
+        /// this document to be in the same field of `i`, i.e. `@value IN i.field`, or none of
+        /// them if `negated`. The other fields are ignored.
+    };
+
+    if fields.is_empty() && flattened_fields.is_empty() {
+        return quote! {
+            #doc
+            pub fn as_contains_filter_aql(
+                &self,
+                _aql: &mut #crate_path::aql::AqlBuilder,
+                _negated: bool,
+            ) -> Result<(), #crate_path::serde_json::Error> {
+                Ok(())
+            }
+        };
+    }
+
+    let conditions = if fields.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let mut conditions: Vec<(&str, &str)> = Vec::new();
+
+            #(#fields)*
+
+            if !conditions.is_empty() {
+                let operator = if negated { "NOT IN" } else { "IN" };
+                let conditions: Vec<_> = conditions
+                    .into_iter()
+                    .map(|(variable, path)| {
+                        format!(
+                            "{} {} {}.{}",
+                            variable,
+                            operator,
+                            #crate_path::aql::AQL_DOCUMENT_ID,
+                            path
+                        )
+                    })
+                    .collect();
+
+                aql.filter_step(conditions.join(" && ").into());
+            }
+        }
+    };
+
+    quote! {
+        #doc
+        pub fn as_contains_filter_aql(
+            &self,
+            aql: &mut #crate_path::aql::AqlBuilder,
+            negated: bool,
+        ) -> Result<(), #crate_path::serde_json::Error> {
+            #conditions
+            #(#flattened_fields)*
+
+            Ok(())
+        }
+    }
+}
+
+pub fn build_db_struct_estimate_contains_cost_method(
+    options: &ModelOptions,
+    fields_in_db: &[&FieldInfo],
+) -> TokenStream {
+    let crate_path = if options.relative_imports {
+        quote!(crate)
+    } else {
+        quote!(::arangodb_types)
+    };
+
+    let fields: Vec<_> = fields_in_db
+        .iter()
+        .filter(|field| field.attributes.flatten || is_array_filter_field(field))
+        .map(|field| {
+            let name = field.name();
+
+            // Flattened fields live at the root of the document.
+            if field.attributes.flatten {
+                return quote! {
+                    cost.merge(self.#name.estimate_contains_cost(indexed_fields));
+                };
+            }
+
+            // Array indexes are declared with the expansion operator.
+            let path = format!("{}[*]", field.db_name);
+
+            match field.field_type_kind {
+                Some(FieldTypeKind::NullableOption) => quote! {
+                    if let #crate_path::types::NullableOption::Value(values) = &self.#name {
+                        if !values.is_empty() {
+                            cost.add_field(#path, indexed_fields);
+                        }
+                    }
+                },
+                Some(FieldTypeKind::Option) => quote! {
+                    if let Some(values) = &self.#name {
+                        if !values.is_empty() {
+                            cost.add_field(#path, indexed_fields);
+                        }
+                    }
+                },
+                None => quote! {
+                    if !self.#name.is_empty() {
+                        cost.add_field(#path, indexed_fields);
+                    }
+                },
+            }
+        })
+        .collect();
+
+    let doc = quote! {
+        /// Estimates the cost of the filter built by `as_contains_filter_aql` flagging the
+        /// array fields whose `field[*]` path is not in `indexed_fields`.
+    };
+
+    if fields.is_empty() {
+        return quote! {
+            #doc
+            pub fn estimate_contains_cost(
+                &self,
+                _indexed_fields: &::std::collections::HashSet<String>,
+            ) -> #crate_path::aql::FilterCost {
+                #crate_path::aql::FilterCost::default()
+            }
+        };
+    }
+
+    quote! {
+        #doc
+        pub fn estimate_contains_cost(
+            &self,
+            indexed_fields: &::std::collections::HashSet<String>,
+        ) -> #crate_path::aql::FilterCost {
+            let mut cost = #crate_path::aql::FilterCost::default();
+
+            #(#fields)*
+
+            cost
+        }
+    }
+}
+
+/// Whether `as_contains_filter_aql` checks the elements of the field.
+fn is_array_filter_field(field: &FieldInfo) -> bool {
+    matches!(
+        field.base_type_kind,
+        BaseTypeKind::Vec | BaseTypeKind::VecBox
+    ) && !field.attributes.flatten
+        && field.attributes.serde_with.is_none()
+}
+
+pub fn build_db_struct_redact_except_method(
+    options: &ModelOptions,
+    info: &ModelInfo,
+    fields_in_db: &[&FieldInfo],
+) -> Result<TokenStream, syn::Error> {
+    // The method requires the field list.
+    if options.skip_fields || fields_in_db.is_empty() {
+        return Ok(quote! {});
+    }
+
+    let enum_name = &info.field_enum_name;
+
+    // Evaluate fields.
+    let mut flag_list = vec![];
+    let mut match_list = vec![];
+    let mut redact_list = vec![];
+
+    fields_in_db.iter().for_each(|field| {
+        let name = field.name();
+        let name_str = from_snake_case_to_pascal_case(&name.to_string());
+        let variant = format_ident!("{}", name_str, span = name.span());
+        let keep_flag = format_ident!("keep_{}", name);
+
+        let null_tokens = match field.field_type_kind {
+            Some(FieldTypeKind::NullableOption) => Some(quote! {
+                if self.#name.is_value() {
+                    self.#name = ::arangodb_types::types::NullableOption::Null;
+                }
+            }),
+            Some(FieldTypeKind::Option) => Some(quote! {
+                self.#name = None;
+            }),
+            None => None,
+        };
+
+        let is_struct = field.attributes.inner_model == InnerModelKind::Struct
+            && field.base_type_kind != BaseTypeKind::DBReference;
+
+        if field.attributes.flatten {
+            let sub_fields = format_ident!("{}_fields", name);
+
+            flag_list.push(quote! {
+                let mut #sub_fields = Vec::new();
+            });
+            match_list.push(quote! {
+                #enum_name::#variant(v) => #sub_fields.push(v.clone()),
+            });
+            redact_list.push(quote! {
+                self.#name.redact_except(&#sub_fields);
+            });
+        } else if is_struct {
+            let sub_fields = format_ident!("{}_fields", name);
+
+            let base = match field.base_type_kind {
+                BaseTypeKind::Vec | BaseTypeKind::VecBox => quote! {
+                    for v in v {
+                        v.redact_except(&#sub_fields);
+                    }
+                },
+                BaseTypeKind::HashMap | BaseTypeKind::BTreeMap => quote! {
+                    for (_, v) in v {
+                        v.redact_except(&#sub_fields);
+                    }
+                },
+                _ => quote! {
+                    v.redact_except(&#sub_fields);
+                },
+            };
+
+            let redact_tokens = match field.field_type_kind {
+                Some(FieldTypeKind::NullableOption) => quote! {
+                    if let ::arangodb_types::types::NullableOption::Value(v) = &mut self.#name {
+                        #base
+                    }
+                },
+                Some(FieldTypeKind::Option) => quote! {
+                    if let Some(v) = &mut self.#name {
+                        #base
+                    }
+                },
+                None => quote! {
+                    {
+                        let v = &mut self.#name;
+                        #base
+                    }
+                },
+            };
+
+            // Null the field only when none of its inner fields is kept.
+            let redact_tokens = match null_tokens {
+                Some(null_tokens) => quote! {
+                    if #sub_fields.is_empty() {
+                        #null_tokens
+                    } else {
+                        #redact_tokens
+                    }
+                },
+                None => redact_tokens,
+            };
+
+            flag_list.push(quote! {
+                let mut #keep_flag = false;
+                let mut #sub_fields = Vec::new();
+            });
+            match_list.push(quote! {
+                #enum_name::#variant(v) => match v {
+                    Some(v) => #sub_fields.push(v.clone()),
+                    None => #keep_flag = true,
+                },
+            });
+            redact_list.push(quote! {
+                if !#keep_flag {
+                    #redact_tokens
+                }
+            });
+        } else {
+            let pattern = if field.base_type_kind == BaseTypeKind::DBReference {
+                let variant_key = format_ident!("{}Key", name_str, span = name.span());
+
+                quote! {
+                    #enum_name::#variant(_) | #enum_name::#variant_key(_)
+                }
+            } else {
+                quote! {
+                    #enum_name::#variant(_)
+                }
+            };
+
+            match null_tokens {
+                Some(null_tokens) => {
+                    flag_list.push(quote! {
+                        let mut #keep_flag = false;
+                    });
+                    match_list.push(quote! {
+                        #pattern => #keep_flag = true,
+                    });
+                    redact_list.push(quote! {
+                        if !#keep_flag {
+                            #null_tokens
+                        }
+                    });
+                }
+                None => match_list.push(quote! {
+                    #pattern => {}
+                }),
+            }
+        }
+    });
+
+    // Build result.
+    Ok(quote! {
+        /// Nulls all the values except those in the given field paths.
+        pub fn redact_except(&mut self, fields: &[#enum_name]) {
+            #(#flag_list)*
+
+            for field in fields {
+                match field {
+                    #(#match_list)*
+                }
+            }
+
+            #(#redact_list)*
+        }
+    })
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn build_sync_impl(options: &ModelOptions, info: &ModelInfo) -> Result<TokenStream, syn::Error> {
+    let document_name = &info.document_name;
+    let collection_name = &info.collection_name;
+    let config_collection_key_method =
+        if let Some(sync_collection_key_method) = &options.sync_collection_key_method {
+            sync_collection_key_method.clone()
+        } else {
             format_ident!("collection_mutex_key")
         };
 
@@ -615,9 +1772,19 @@ fn build_sync_impl(options: &ModelOptions, info: &ModelInfo) -> Result<TokenStre
         }
     };
 
+    let exclusive_mutex = if options.sync_exclusive {
+        quote! {
+            const EXCLUSIVE_MUTEX: bool = true;
+        }
+    } else {
+        quote! {}
+    };
+
     // Build result.
     Ok(quote! {
         impl<'a> ::arangodb_types::traits::DBSynchronizedDocument<'a> for #document_name {
+            #exclusive_mutex
+
             #[allow(unused_variables)]
             fn collection_key() -> &'a Self::Key {
                 #collection_key_value
@@ -634,6 +1801,103 @@ fn build_sync_impl(options: &ModelOptions, info: &ModelInfo) -> Result<TokenStre
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+fn build_singleton_collection(
+    options: &ModelOptions,
+    info: &ModelInfo,
+    fields_in_db: &[&FieldInfo],
+) -> Result<TokenStream, syn::Error> {
+    let visibility = info.item.visibility();
+    let document_name = &info.document_name;
+    let collection_name = &info.collection_name;
+    let collection_kind = if let Some(collection_kind) = &options.collection_kind {
+        collection_kind.to_string()
+    } else {
+        format!("{}s", info.item.ident())
+    };
+    let not_initialized_message = format!("The {} singleton is not initialized", collection_name);
+    let is_edge = fields_in_db.iter().any(|field| field.db_name == "_from")
+        && fields_in_db.iter().any(|field| field.db_name == "_to");
+
+    // Build result.
+    Ok(quote! {
+        #[derive(Debug)]
+        #visibility struct #collection_name {
+            db_info: ::std::sync::Arc<::arangodb_types::types::DBInfo>,
+        }
+
+        impl #collection_name {
+            // CONSTRUCTORS ---------------------------------------------------
+
+            /// Creates the collection in DB if it does not exist and sets it as the singleton.
+            pub async fn new(
+                db_info: &::std::sync::Arc<::arangodb_types::types::DBInfo>,
+            ) -> Result<::std::sync::Arc<Self>, ::arangodb_types::anyhow::Error> {
+                let collection = ::std::sync::Arc::new(Self {
+                    db_info: db_info.clone(),
+                });
+                db_info
+                    .ensure_collection(
+                        <Self as ::arangodb_types::traits::DBCollection>::name(),
+                        ::arangodb_types::types::CollectionOptions {
+                            is_edge: #is_edge,
+                            ..Default::default()
+                        },
+                    )
+                    .await?;
+
+                *Self::singleton().write().unwrap() = Some(collection.clone());
+
+                Ok(collection)
+            }
+
+            // GETTERS --------------------------------------------------------
+
+            /// Gets the singleton set by the last call to `new`.
+            pub fn instance() -> ::std::sync::Arc<Self> {
+                Self::singleton()
+                    .read()
+                    .unwrap()
+                    .clone()
+                    .expect(#not_initialized_message)
+            }
+
+            // STATIC METHODS -------------------------------------------------
+
+            fn singleton() -> &'static ::std::sync::RwLock<Option<::std::sync::Arc<Self>>> {
+                static SINGLETON: ::std::sync::RwLock<Option<::std::sync::Arc<#collection_name>>> =
+                    ::std::sync::RwLock::new(None);
+                &SINGLETON
+            }
+        }
+
+        impl ::arangodb_types::traits::DBCollection for #collection_name {
+            type Document = #document_name;
+
+            fn name() -> &'static str {
+                #collection_kind
+            }
+
+            fn db_info(&self) -> &::std::sync::Arc<::arangodb_types::types::DBInfo> {
+                &self.db_info
+            }
+        }
+
+        impl ::arangodb_types::traits::DBCollectionFromInfo for #collection_name {
+            fn from_db_info(
+                db_info: &::std::sync::Arc<::arangodb_types::types::DBInfo>,
+            ) -> ::std::sync::Arc<Self> {
+                ::std::sync::Arc::new(Self {
+                    db_info: db_info.clone(),
+                })
+            }
+        }
+    })
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 fn check_and_build_edge_db_impl(
     _options: &ModelOptions,
     info: &ModelInfo,
@@ -687,7 +1951,9 @@ pub fn build_db_struct_aql_mapping_impl(
                         BaseTypeKind::Other
                         | BaseTypeKind::Box
                         | BaseTypeKind::Vec
-                        | BaseTypeKind::HashMap => None,
+                        | BaseTypeKind::VecBox
+                        | BaseTypeKind::HashMap
+                        | BaseTypeKind::BTreeMap => None,
                         BaseTypeKind::VecDBReference | BaseTypeKind::DBReference => Some(quote! {
                             v.include_let_steps(aql, path, next_id);
                         }),
@@ -760,7 +2026,9 @@ pub fn build_db_struct_aql_mapping_impl(
                     BaseTypeKind::Other
                     | BaseTypeKind::Box
                     | BaseTypeKind::Vec
-                    | BaseTypeKind::HashMap => {
+                    | BaseTypeKind::VecBox
+                    | BaseTypeKind::HashMap
+                    | BaseTypeKind::BTreeMap => {
                         quote! {
                             buffer.write_all(path.as_bytes()).unwrap();
                             buffer.write_all(#pattern3.as_bytes()).unwrap();
@@ -847,12 +2115,23 @@ pub fn build_db_struct_aql_mapping_impl(
                         }
                     }
                     None => {
-                        quote! {
-                            buffer.write_all(#pattern1.as_bytes()).unwrap();
+                        if field.attributes.flatten {
+                            // Write the inner fields without the surrounding braces.
+                            quote! {
+                                {
+                                    let mut sub_buffer = Vec::new();
+                                    self.#name.map_to_json(&mut sub_buffer, path, next_id);
+                                    buffer.write_all(&sub_buffer[1..sub_buffer.len() - 1]).unwrap();
+                                }
+                            }
+                        } else {
+                            quote! {
+                                buffer.write_all(#pattern1.as_bytes()).unwrap();
 
-                            let sub_path = format!(#pattern2, path);
-                            self.#name.map_to_json(buffer, sub_path.as_str(), next_id);
-                            buffer.write_all(b",").unwrap();
+                                let sub_path = format!(#pattern2, path);
+                                self.#name.map_to_json(buffer, sub_path.as_str(), next_id);
+                                buffer.write_all(b",").unwrap();
+                            }
                         }
                     }
                 }
@@ -906,6 +2185,19 @@ pub fn build_db_struct_aql_mapping_impl(
         quote! {}
     };
 
+    let schema_version_field = if options.schema_version.is_some() {
+        let pattern1 = format!("{}:", SCHEMA_VERSION_FIELD_DB_NAME);
+        let pattern3 = format!(".{},", SCHEMA_VERSION_FIELD_DB_NAME);
+
+        quote! {
+            buffer.write_all(#pattern1.as_bytes()).unwrap();
+            buffer.write_all(path.as_bytes()).unwrap();
+            buffer.write_all(#pattern3.as_bytes()).unwrap();
+        }
+    } else {
+        quote! {}
+    };
+
     let model_fields = if !is_sub_model {
         quote! {
             // Include always _key and _rev.
@@ -921,6 +2213,7 @@ pub fn build_db_struct_aql_mapping_impl(
             #from_to_fields
 
             #lock_field
+            #schema_version_field
         }
     } else {
         quote! {}
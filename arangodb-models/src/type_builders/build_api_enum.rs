@@ -409,5 +409,11 @@ fn build_field_list(
                 }
             }
         }
+
+        impl ::std::fmt::Display for #api_field_enum_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(&self.path())
+            }
+        }
     })
 }
@@ -60,21 +60,21 @@ fn build_impl(
                     BaseTypeKind::Other | BaseTypeKind::Box => Some(quote! {
                         v.map_values_to_null();
                     }),
-                    BaseTypeKind::Vec => Some(quote! {
+                    BaseTypeKind::Vec | BaseTypeKind::VecBox => Some(quote! {
                         for v in v {
                             v.map_values_to_null();
                         }
                     }),
                     BaseTypeKind::VecDBReference => {
-                        panic!("Cannot declare a DBReference value as Struct or Enum model")
+                        unreachable!("Cannot declare a DBReference value as Struct or Enum model")
                     }
-                    BaseTypeKind::HashMap => Some(quote! {
+                    BaseTypeKind::HashMap | BaseTypeKind::BTreeMap => Some(quote! {
                         for (_, v) in v {
                             v.map_values_to_null();
                         }
                     }),
                     BaseTypeKind::DBReference => {
-                        panic!("Cannot declare a DBReference value as Struct or Enum model")
+                        unreachable!("Cannot declare a DBReference value as Struct or Enum model")
                     }
                 };
 
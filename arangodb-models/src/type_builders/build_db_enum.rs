@@ -351,6 +351,12 @@ fn build_field_list(
                 }
             }
         }
+
+        impl ::std::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(&self.path())
+            }
+        }
     })
 }
 
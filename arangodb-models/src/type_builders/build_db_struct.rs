@@ -5,7 +5,12 @@ use quote::quote;
 use crate::data::{
     BaseTypeKind, FieldInfo, FieldTypeKind, InnerModelKind, ModelInfo, ModelOptions,
 };
-use crate::model_builders::{build_db_struct_aql_mapping_impl, build_db_struct_field_list};
+use crate::model_builders::{
+    build_db_struct_aql_mapping_impl, build_db_struct_as_contains_filter_aql_method,
+    build_db_struct_as_filter_aql_method, build_db_struct_estimate_contains_cost_method,
+    build_db_struct_estimate_cost_method, build_db_struct_field_list,
+    build_db_struct_redact_except_method,
+};
 
 pub fn build_db_struct_type(
     options: &ModelOptions,
@@ -50,6 +55,8 @@ fn build_struct(
     let generics = info.item.generics();
     let document_name = &info.document_name;
 
+    info.check_eq_derive(DB_MODEL_TAG, fields_in_db)?;
+
     let all_fields_are_optional_or_db_properties =
         info.check_all_db_fields_are_optional_or_properties();
 
@@ -69,7 +76,7 @@ fn build_struct(
         let node = field.node.as_field().unwrap();
         let visibility = &node.vis;
         let name = field.name();
-        let db_name = &field.db_name;
+        let name_attribute = field.build_db_field_name_attribute();
         let field_type = field.build_db_field_type();
         let deserialize_with = field.build_field_deserialize_with();
 
@@ -88,7 +95,7 @@ fn build_struct(
 
         quote! {
             #attributes
-            #[serde(rename = #db_name)]
+            #name_attribute
             #deserialize_with
             #visibility #name: #field_type,
         }
@@ -124,7 +131,7 @@ fn build_struct(
 // ----------------------------------------------------------------------------
 
 fn build_impl(
-    _options: &ModelOptions,
+    options: &ModelOptions,
     info: &ModelInfo,
     fields_in_db: &[&FieldInfo],
 ) -> Result<TokenStream, syn::Error> {
@@ -305,21 +312,21 @@ fn build_impl(
                         BaseTypeKind::Other | BaseTypeKind::Box => Some(quote! {
                             v.map_values_to_null();
                         }),
-                        BaseTypeKind::Vec => Some(quote! {
+                        BaseTypeKind::Vec | BaseTypeKind::VecBox => Some(quote! {
                             for v in v {
                                 v.map_values_to_null();
                             }
                         }),
                         BaseTypeKind::VecDBReference => {
-                            panic!("Cannot declare a VecDBReference value as Struct or Enum model")
+                            unreachable!("Cannot declare a VecDBReference value as Struct or Enum model")
                         }
-                        BaseTypeKind::HashMap => Some(quote! {
+                        BaseTypeKind::HashMap | BaseTypeKind::BTreeMap => Some(quote! {
                             for (_, v) in v {
                                 v.map_values_to_null();
                             }
                         }),
                         BaseTypeKind::DBReference => {
-                            panic!("Cannot declare a DBReference value as Struct or Enum model")
+                            unreachable!("Cannot declare a DBReference value as Struct or Enum model")
                         }
                     };
 
@@ -358,6 +365,24 @@ fn build_impl(
         }
     };
 
+    // Evaluate redact_except method.
+    let redact_except_method_tokens =
+        build_db_struct_redact_except_method(options, info, fields_in_db)?;
+
+    // Evaluate as_filter_aql method.
+    let as_filter_aql_method_tokens = build_db_struct_as_filter_aql_method(options, fields_in_db);
+
+    // Evaluate estimate_cost method.
+    let estimate_cost_method_tokens = build_db_struct_estimate_cost_method(options, fields_in_db);
+
+    // Evaluate as_contains_filter_aql method.
+    let as_contains_filter_aql_method_tokens =
+        build_db_struct_as_contains_filter_aql_method(options, fields_in_db);
+
+    // Evaluate estimate_contains_cost method.
+    let estimate_contains_cost_method_tokens =
+        build_db_struct_estimate_contains_cost_method(options, fields_in_db);
+
     // Build result.
     Ok(quote! {
         impl #generics #document_name #generics {
@@ -366,6 +391,11 @@ fn build_impl(
             #is_all_null_or_missing_method_tokens
             #all_null_method_tokens
             #map_values_to_null_method_tokens
+            #redact_except_method_tokens
+            #as_filter_aql_method_tokens
+            #estimate_cost_method_tokens
+            #as_contains_filter_aql_method_tokens
+            #estimate_contains_cost_method_tokens
         }
     })
 }
@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{TokenStream, TokenTree};
 use quote::quote;
 use syn::{GenericArgument, Lit, Meta, Path, PathArguments, Type};
 
@@ -259,3 +259,16 @@ pub fn get_inner_map_types_from_path(path: &Path) -> Option<(&Type, &Type)> {
         None
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Checks whether a type contains a floating point type, i.e. f32 or f64.
+pub fn contains_float_type(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "f32" || ident == "f64",
+        TokenTree::Group(group) => contains_float_type(group.stream()),
+        _ => false,
+    })
+}
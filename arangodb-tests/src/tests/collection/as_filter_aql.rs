@@ -0,0 +1,80 @@
+use arangodb_types::aql::{AqlBuilder, AqlReturn, AQL_DOCUMENT_ID};
+use arangodb_types::traits::DBCollection;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::{MutexCollection, MutexDBDocument};
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn as_filter_aql_query_by_example() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let key = DBUuid::new();
+    let documents = vec![
+        MutexDBDocument {
+            db_key: Some(key.clone()),
+            value: NullableOption::Value(7),
+            ..Default::default()
+        },
+        MutexDBDocument {
+            db_key: Some(DBUuid::new()),
+            value: NullableOption::Value(7),
+            ..Default::default()
+        },
+    ];
+
+    collection
+        .insert_many(&documents)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let example = MutexDBDocument {
+        db_key: Some(key.clone()),
+        value: NullableOption::Value(7),
+        ..Default::default()
+    };
+
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, MutexCollection::name());
+    example
+        .as_filter_aql(&mut aql)
+        .expect("The filter must be built");
+    aql.return_step(AqlReturn::new_document());
+
+    let result = collection
+        .send_aql(&aql)
+        .await
+        .expect("There is an error trying to get the documents");
+
+    // Check.
+    assert_eq!(result.results.len(), 1, "Incorrect document count");
+    assert_eq!(
+        result.results[0].db_key,
+        Some(key.clone()),
+        "Incorrect document"
+    );
+
+    // Execute with a different value.
+    let example = MutexDBDocument {
+        db_key: Some(key),
+        value: NullableOption::Value(8),
+        ..Default::default()
+    };
+
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, MutexCollection::name());
+    example
+        .as_filter_aql(&mut aql)
+        .expect("The filter must be built");
+    aql.return_step(AqlReturn::new_document());
+
+    let result = collection
+        .send_aql(&aql)
+        .await
+        .expect("There is an error trying to get the documents");
+
+    // Check.
+    assert!(result.results.is_empty(), "No document must match");
+}
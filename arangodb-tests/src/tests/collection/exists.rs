@@ -0,0 +1,38 @@
+use arangodb_types::traits::DBCollection;
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn exists_by_key_ok() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let exists = collection
+        .exists_by_key(&document_key)
+        .await
+        .expect("There is an error trying to check the document");
+    let missing = collection
+        .exists_by_key(&DBUuid::new())
+        .await
+        .expect("There is an error trying to check the document");
+
+    // Check.
+    assert!(exists, "The document must exist");
+    assert!(!missing, "The document must not exist");
+}
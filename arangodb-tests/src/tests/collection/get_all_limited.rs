@@ -0,0 +1,72 @@
+use arangodb_types::traits::DBCollection;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_all_limited_ok() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let keys = vec![DBUuid::new(), DBUuid::new()];
+    let documents = keys
+        .iter()
+        .map(|key| MutexDBDocument {
+            db_key: Some(key.clone()),
+            value: NullableOption::Value(5),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    collection
+        .insert_many(&documents)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let result = collection
+        .get_all_limited(Some(u64::MAX), None)
+        .await
+        .expect("There is an error trying to get the documents");
+
+    // Check.
+    for key in &keys {
+        assert!(
+            result.iter().any(|v| v.db_key.as_ref() == Some(key)),
+            "Missing document"
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_all_limited_exceeded() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let documents = (0..2)
+        .map(|_| MutexDBDocument {
+            db_key: Some(DBUuid::new()),
+            value: NullableOption::Value(5),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    collection
+        .insert_many(&documents)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let result = collection.get_all_limited(Some(1), None).await;
+
+    // Check.
+    assert!(result.is_err(), "The limit must be exceeded");
+}
@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use arangodb_types::traits::DBCollection;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_by_key_prefix_ok() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let prefix = DBUuid::new().to_string();
+    let keys = ["a", "b", "c"]
+        .iter()
+        .map(|v| DBUuid::from_str(format!("{}{}", prefix, v).as_str()).unwrap())
+        .collect::<Vec<_>>();
+
+    let mut documents = keys
+        .iter()
+        .map(|key| MutexDBDocument {
+            db_key: Some(key.clone()),
+            value: NullableOption::Value(5),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    // Other document sharing all but the last char of the prefix.
+    let mut other_prefix = prefix.clone();
+    other_prefix.pop();
+    documents.push(MutexDBDocument {
+        db_key: Some(DBUuid::from_str(format!("{}-", other_prefix).as_str()).unwrap()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    });
+
+    collection
+        .insert_many(&documents)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let mut result = collection
+        .get_by_key_prefix(prefix.as_str(), None, None)
+        .await
+        .expect("There is an error trying to get the documents");
+
+    // Check.
+    result.sort_by(|a, b| a.db_key.cmp(&b.db_key));
+    let result_keys = result
+        .into_iter()
+        .map(|v| v.db_key.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(result_keys, keys, "Incorrect documents");
+
+    // Execute with limit.
+    let result = collection
+        .get_by_key_prefix(prefix.as_str(), Some(2), None)
+        .await
+        .expect("There is an error trying to get the documents");
+
+    // Check.
+    assert_eq!(result.len(), 2, "Incorrect document count");
+}
@@ -0,0 +1,60 @@
+use arangodb_types::traits::DBCollection;
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_one_by_key_with_rev_ok() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let (db_document, rev) = collection
+        .get_one_by_key_with_rev(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    // Check.
+    assert_eq!(db_document.db_key, Some(document_key), "Incorrect db_key");
+    assert_eq!(
+        db_document.value,
+        NullableOption::Value(5),
+        "Incorrect value"
+    );
+    assert!(!rev.is_empty(), "Incorrect rev");
+    assert_eq!(Some(rev), document.db_rev, "Incorrect rev");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_one_by_key_with_rev_missing() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Execute.
+    let result = collection
+        .get_one_by_key_with_rev(&DBUuid::new(), None)
+        .await
+        .expect("There is an error trying to get the document");
+
+    // Check.
+    assert!(result.is_none(), "The document must not exist");
+}
@@ -0,0 +1,57 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBReference, DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+use crate::tests::models::model::LinkDBDocument;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn load_reference_field() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let mut link = LinkDBDocument {
+        target: NullableOption::Value(DBReference::new_key(document_key.clone())),
+        targets: NullableOption::Value(vec![
+            DBReference::new_key(document_key.clone()),
+            DBReference::new_key(DBUuid::new()),
+        ]),
+        ..Default::default()
+    };
+
+    // Execute.
+    link.load_target(&db_info)
+        .await
+        .expect("There is an error trying to load the reference");
+    link.load_targets(&db_info)
+        .await
+        .expect("There is an error trying to load the references");
+
+    // Check.
+    let target = link.target.unwrap_as_ref();
+    assert!(target.is_document(), "The reference must be loaded");
+    assert_eq!(
+        target.unwrap_document_as_ref().value,
+        NullableOption::Value(5),
+        "Incorrect value"
+    );
+
+    let targets = link.targets.unwrap_as_ref();
+    assert!(targets[0].is_document(), "The reference must be loaded");
+    assert!(
+        targets[1].is_key(),
+        "The reference to a missing document must be kept"
+    );
+}
@@ -0,0 +1,16 @@
+pub mod as_filter_aql;
+pub mod exists;
+pub mod get_all_limited;
+pub mod get_by_key_prefix;
+pub mod get_one;
+pub mod load_references;
+pub mod normalize_page;
+pub mod reference_cache;
+pub mod resolve_reference;
+pub mod send_generic_aql;
+pub mod send_raw_aql;
+pub mod send_scoped_aql;
+pub mod singleton;
+pub mod stream_keys;
+pub mod stream_pages;
+pub mod update_many;
@@ -0,0 +1,68 @@
+use arangodb_types::traits::{DBCollection, DBDocument};
+use arangodb_types::types::{CollectionOptions, DBUuid, NullableOption};
+
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+use crate::tests::models::model::{TagCollection, TagDBDocument};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn normalize_page() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, _collection) = init_db_connection().await;
+
+    db_info
+        .ensure_collection(TagCollection::name(), CollectionOptions::default())
+        .await
+        .expect("Cannot create collection");
+    let collection = TagCollection::new(&db_info);
+
+    // Preconditions.
+    let names = ["rust", " Arango ", "db", "AQL"];
+    let mut documents = Vec::with_capacity(names.len());
+    for name in names {
+        let document = TagDBDocument {
+            db_key: Some(DBUuid::new()),
+            name: NullableOption::Value(name.to_string()),
+            ..Default::default()
+        }
+        .insert(true, &collection)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+        documents.push(document);
+    }
+
+    let unmodified_rev = documents[0].db_rev.clone();
+
+    // Execute.
+    let updated = collection
+        .normalize_page(&mut documents)
+        .await
+        .expect("There is an error trying to normalize the page");
+
+    // Check.
+    assert_eq!(updated, 2, "Incorrect number of updated documents");
+    assert_eq!(
+        documents[0].db_rev, unmodified_rev,
+        "The unmodified document must not be updated"
+    );
+
+    for (document, name) in documents.iter().zip(["rust", "arango", "db", "aql"]) {
+        assert_eq!(
+            document.name,
+            NullableOption::Value(name.to_string()),
+            "Incorrect name"
+        );
+
+        let document = collection
+            .get_one_by_key(document.db_key.as_ref().unwrap(), None)
+            .await
+            .expect("There is an error trying to get the document")
+            .expect("The document does not exist in DB");
+        assert_eq!(
+            document.name,
+            NullableOption::Value(name.to_string()),
+            "Incorrect name in DB"
+        );
+    }
+}
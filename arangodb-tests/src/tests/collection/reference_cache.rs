@@ -0,0 +1,151 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBReference, DBUuid, NullableOption, ReferenceCache};
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resolve_reference_cached() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let cache = ReferenceCache::new(10);
+    let reference = DBReference::<MutexDBDocument>::new_key(document_key.clone());
+
+    // Execute.
+    let first = reference
+        .resolve_cached(&db_info, &cache)
+        .await
+        .expect("There is an error trying to resolve the reference")
+        .expect("The document does not exist in DB");
+
+    // Remove the document so a second request to the DB would return nothing.
+    document
+        .remove_and_ignore(None, collection.as_ref())
+        .await
+        .expect("Cannot remove the document from DB");
+
+    let second = reference
+        .resolve_cached(&db_info, &cache)
+        .await
+        .expect("There is an error trying to resolve the reference")
+        .expect("The document must be taken from the cache");
+
+    // Check.
+    assert_eq!(first.value, NullableOption::Value(5), "Incorrect value");
+    assert_eq!(second.db_key, Some(document_key), "Incorrect db_key");
+    assert_eq!(second.value, first.value, "Incorrect value");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resolve_many_references_cached() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let cached_key = DBUuid::new();
+    let db_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(db_key.clone()),
+        value: NullableOption::Value(2),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    // The cached document is not in DB to check it is not requested.
+    let cache = ReferenceCache::new(10);
+    cache.insert(MutexDBDocument {
+        db_key: Some(cached_key.clone()),
+        value: NullableOption::Value(1),
+        ..Default::default()
+    });
+
+    let references = vec![
+        DBReference::<MutexDBDocument>::new_key(cached_key.clone()),
+        DBReference::new_key(DBUuid::new()),
+        DBReference::new_key(db_key.clone()),
+    ];
+
+    // Execute.
+    let documents = DBReference::resolve_many_cached(&references, &db_info, &cache)
+        .await
+        .expect("There is an error trying to resolve the references");
+
+    // Check.
+    assert_eq!(documents.len(), 3, "Incorrect number of documents");
+    assert_eq!(
+        documents[0].as_ref().map(|v| &v.value),
+        Some(&NullableOption::Value(1)),
+        "Incorrect cached document"
+    );
+    assert!(documents[1].is_none(), "The document must not exist");
+    assert_eq!(
+        documents[2].as_ref().map(|v| &v.value),
+        Some(&NullableOption::Value(2)),
+        "Incorrect DB document"
+    );
+    assert!(cache.contains_key(&db_key), "The document must be cached");
+    assert_eq!(cache.len(), 2, "Missing documents must not be cached");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn reference_cache_evicts_least_recently_used() {
+    let keys: Vec<_> = (0..3).map(|_| DBUuid::new()).collect();
+    let cache = ReferenceCache::new(2);
+
+    for key in &keys[..2] {
+        cache.insert(MutexDBDocument {
+            db_key: Some(key.clone()),
+            ..Default::default()
+        });
+    }
+
+    // Use the first one so the second one is the least recently used.
+    assert!(cache.get(&keys[0]).is_some(), "The document must be cached");
+
+    cache.insert(MutexDBDocument {
+        db_key: Some(keys[2].clone()),
+        ..Default::default()
+    });
+
+    assert_eq!(cache.len(), 2, "Incorrect length");
+    assert!(cache.contains_key(&keys[0]), "The first key must be kept");
+    assert!(
+        !cache.contains_key(&keys[1]),
+        "The second key must be evicted"
+    );
+    assert!(cache.contains_key(&keys[2]), "The third key must be kept");
+
+    // Documents without key are ignored.
+    cache.insert(MutexDBDocument::default());
+    assert_eq!(cache.len(), 2, "Incorrect length");
+
+    assert!(
+        cache.remove(&keys[0]).is_some(),
+        "The document must be removed"
+    );
+    cache.clear();
+    assert!(cache.is_empty(), "The cache must be empty");
+}
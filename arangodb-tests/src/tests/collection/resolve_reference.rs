@@ -0,0 +1,44 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBReference, DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resolve_reference_with_db_info() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let reference = DBReference::<MutexDBDocument>::new_key(document_key.clone());
+    let document = reference
+        .resolve(&db_info, None)
+        .await
+        .expect("There is an error trying to resolve the reference")
+        .expect("The document does not exist in DB");
+
+    // Check.
+    assert_eq!(document.db_key, Some(document_key), "Incorrect db_key");
+    assert_eq!(document.value, NullableOption::Value(5), "Incorrect value");
+
+    // Missing document.
+    let reference = DBReference::<MutexDBDocument>::new_key(DBUuid::new());
+    let document = reference
+        .resolve(&db_info, None)
+        .await
+        .expect("There is an error trying to resolve the reference");
+
+    assert!(document.is_none(), "The document must not exist");
+}
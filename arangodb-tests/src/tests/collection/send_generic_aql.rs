@@ -0,0 +1,109 @@
+use arangodb_types::aql::{AqlBuilder, AqlLimit, AqlReturn, AQL_DOCUMENT_ID};
+use arangodb_types::documents::DBDocumentField;
+use arangodb_types::traits::DBCollection;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::{MutexCollection, MutexDBDocument};
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn send_generic_aql_full_count() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let documents = (0..3)
+        .map(|_| MutexDBDocument {
+            db_key: Some(DBUuid::new()),
+            value: NullableOption::Value(5),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    collection
+        .insert_many(&documents)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, MutexCollection::name());
+    aql.limit_step(AqlLimit {
+        offset: None,
+        count: 1,
+    });
+    aql.return_step(AqlReturn::new_document());
+    aql.set_full_count(true);
+
+    let result = collection
+        .send_generic_aql::<MutexDBDocument>(&aql)
+        .await
+        .expect("There is an error trying to send the query");
+
+    // Check.
+    assert_eq!(result.results.len(), 1, "Incorrect results");
+
+    let full_count = result.full_count.expect("The full count must be present");
+    assert!(full_count >= 3, "Incorrect full count");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn send_generic_aql_batched() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let keys = (0..5).map(|_| DBUuid::new()).collect::<Vec<_>>();
+    let documents = keys
+        .iter()
+        .map(|key| MutexDBDocument {
+            db_key: Some(key.clone()),
+            value: NullableOption::Value(5),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    collection
+        .insert_many(&documents)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, MutexCollection::name());
+    let keys_var = aql.add_variable(&keys).unwrap().unwrap();
+    aql.filter_step(
+        format!(
+            "{}.{} IN {}",
+            AQL_DOCUMENT_ID,
+            DBDocumentField::Key.path(),
+            keys_var
+        )
+        .into(),
+    );
+    aql.return_step(AqlReturn::new_document());
+
+    let result = collection
+        .send_generic_aql_batched::<MutexDBDocument>(&aql, Some(2))
+        .await
+        .expect("There is an error trying to send the query");
+
+    // Check.
+    let mut result_keys = result
+        .results
+        .into_iter()
+        .map(|v| v.db_key.unwrap())
+        .collect::<Vec<_>>();
+    result_keys.sort();
+
+    let mut keys = keys;
+    keys.sort();
+
+    assert_eq!(
+        result_keys, keys,
+        "The results of every batch must be returned"
+    );
+}
@@ -0,0 +1,54 @@
+use arangodb_types::aql::{AqlBuilder, AqlLimit, AqlReturn, AQL_DOCUMENT_ID};
+use arangodb_types::traits::DBCollection;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::{MutexCollection, MutexDBDocument};
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn send_raw_aql_full_count() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let documents = (0..3)
+        .map(|_| MutexDBDocument {
+            db_key: Some(DBUuid::new()),
+            value: NullableOption::Value(5),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    collection
+        .insert_many(&documents)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, MutexCollection::name());
+    aql.limit_step(AqlLimit {
+        offset: None,
+        count: 1,
+    });
+    aql.return_step(AqlReturn::new_document());
+    aql.set_full_count(true);
+    aql.set_batch_size(Some(1));
+
+    let response = collection
+        .send_raw_aql(&aql)
+        .await
+        .expect("There is an error trying to send the query");
+
+    // Check.
+    assert_eq!(
+        response["result"].as_array().map(|v| v.len()),
+        Some(1),
+        "Incorrect results"
+    );
+
+    let full_count = response["extra"]["stats"]["fullCount"]
+        .as_u64()
+        .expect("The full count must be present");
+    assert!(full_count >= 3, "Incorrect full count");
+}
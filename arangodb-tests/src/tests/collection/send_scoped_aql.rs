@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use arangodb_types::traits::DBCollection;
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn send_scoped_aql_ok() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    }
+    .insert(false, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let query = "FOR i IN @@collection FILTER i._key == @key RETURN i";
+    let mut bind_vars = HashMap::new();
+    bind_vars.insert("key", serde_json::to_value(&document_key).unwrap());
+
+    let result = collection
+        .send_scoped_aql::<MutexDBDocument>(query, bind_vars)
+        .await
+        .expect("There is an error trying to send the query");
+
+    // Check.
+    assert!(
+        !query.contains(<MutexDBDocument as DBDocument>::Collection::name()),
+        "The collection must be bound, not interpolated"
+    );
+    assert_eq!(result.len(), 1, "Incorrect result count");
+    assert_eq!(result[0].db_key, Some(document_key), "Incorrect db_key");
+    assert_eq!(result[0].value, NullableOption::Value(5), "Incorrect value");
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use arangodb_types::traits::{DBCollection, DBDocument};
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::init_db_connection;
+use crate::tests::models::model::{SingletonCollection, SingletonDBDocument};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn singleton_instance_after_new() {
+    let (db_info, _collection) = init_db_connection().await;
+
+    // Execute.
+    let collection = SingletonCollection::new(&db_info)
+        .await
+        .expect("Cannot create collection");
+    let instance = SingletonCollection::instance();
+
+    // Check.
+    assert!(
+        Arc::ptr_eq(&collection, &instance),
+        "The instance must be the collection created by new"
+    );
+    assert_eq!(SingletonCollection::name(), "Singletons", "Incorrect name");
+
+    let document_key = DBUuid::new();
+    SingletonDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    }
+    .insert(true, instance.as_ref())
+    .await
+    .expect("Cannot insert the document");
+
+    let document = SingletonCollection::instance()
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+    assert_eq!(document.value, NullableOption::Value(5), "Incorrect value");
+}
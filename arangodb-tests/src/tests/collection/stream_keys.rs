@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use arangodb_types::traits::DBCollection;
+use arangodb_types::types::{DBUuid, NullableOption};
+use futures_util::StreamExt;
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn stream_keys_ok() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let keys = (0..5).map(|_| DBUuid::new()).collect::<Vec<_>>();
+    let documents = keys
+        .iter()
+        .map(|key| MutexDBDocument {
+            db_key: Some(key.clone()),
+            value: NullableOption::Value(5),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    collection
+        .insert_many(&documents)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let result = collection
+        .stream_keys()
+        .map(|key| key.expect("There is an error trying to stream the keys"))
+        .collect::<Vec<_>>()
+        .await;
+
+    // Check.
+    let result_keys = result.iter().cloned().collect::<HashSet<_>>();
+    assert_eq!(result_keys.len(), result.len(), "Duplicated keys");
+
+    for key in &keys {
+        assert!(result_keys.contains(key), "Missing key: {}", key);
+    }
+}
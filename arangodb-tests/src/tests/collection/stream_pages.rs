@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use arangodb_types::aql::{AqlBuilder, AqlReturn, AqlSort, AQL_DOCUMENT_ID};
+use arangodb_types::traits::DBCollection;
+use arangodb_types::types::{DBUuid, NullableOption};
+use futures_util::StreamExt;
+
+use crate::tests::db_mutex::model::{MutexCollection, MutexDBDocument};
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn stream_pages_ok() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let keys = (0..7).map(|_| DBUuid::new()).collect::<Vec<_>>();
+    let documents = keys
+        .iter()
+        .map(|key| MutexDBDocument {
+            db_key: Some(key.clone()),
+            value: NullableOption::Value(5),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    collection
+        .insert_many(&documents)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let pages = AtomicUsize::new(0);
+    let result = collection
+        .stream_pages::<DBUuid, _>(3, |limit| {
+            pages.fetch_add(1, Ordering::SeqCst);
+
+            let mut aql =
+                AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, MutexCollection::name());
+            let keys_var = aql.add_variable(&keys).unwrap().unwrap();
+            aql.filter_step(format!("{}._key IN {}", AQL_DOCUMENT_ID, keys_var).into());
+            aql.sort_step(vec![AqlSort {
+                is_descending: false,
+                expression: format!("{}._key", AQL_DOCUMENT_ID).into(),
+            }]);
+            aql.limit_step(limit);
+            aql.return_step(AqlReturn::new_expression(
+                format!("{}._key", AQL_DOCUMENT_ID).into(),
+            ));
+            aql
+        })
+        .map(|key| key.expect("There is an error trying to stream the pages"))
+        .collect::<Vec<_>>()
+        .await;
+
+    // Check.
+    let result_keys = result.iter().cloned().collect::<HashSet<_>>();
+    assert_eq!(result_keys.len(), result.len(), "Duplicated keys");
+    assert_eq!(
+        result_keys,
+        keys.into_iter().collect::<HashSet<_>>(),
+        "Incorrect keys"
+    );
+    assert_eq!(pages.load(Ordering::SeqCst), 3, "Incorrect pages");
+}
@@ -0,0 +1,69 @@
+use arangodb_types::traits::{DBCollection, DBDocument};
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn update_many() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let keys = vec![DBUuid::new(), DBUuid::new(), DBUuid::new()];
+    for key in &keys {
+        MutexDBDocument {
+            db_key: Some(key.clone()),
+            value: NullableOption::Value(1),
+            ..Default::default()
+        }
+        .insert(true, collection.as_ref())
+        .await
+        .expect("Cannot add preconditions to DB");
+    }
+
+    // Execute.
+    let missing_key = DBUuid::new();
+    let documents = keys
+        .iter()
+        .chain(std::iter::once(&missing_key))
+        .enumerate()
+        .map(|(i, key)| MutexDBDocument {
+            db_key: Some(key.clone()),
+            value: NullableOption::Value(10 + i as u64),
+            ..Default::default()
+        })
+        .collect();
+
+    let results = collection
+        .update_many(documents, true)
+        .await
+        .expect("There is an error trying to update the documents");
+
+    // Check.
+    assert_eq!(results.len(), 4, "Incorrect number of results");
+
+    for (i, key) in keys.iter().enumerate() {
+        let document = results[i].as_ref().expect("The document must be updated");
+        assert_eq!(document.db_key.as_ref(), Some(key), "Incorrect db_key");
+        assert_eq!(
+            document.value,
+            NullableOption::Value(10 + i as u64),
+            "Incorrect value"
+        );
+
+        let document = collection
+            .get_one_by_key(key, None)
+            .await
+            .expect("There is an error trying to get the document")
+            .expect("The document does not exist in DB");
+        assert_eq!(
+            document.value,
+            NullableOption::Value(10 + i as u64),
+            "Incorrect value in DB"
+        );
+    }
+
+    assert!(results[3].is_err(), "The missing document must fail");
+}
@@ -0,0 +1,61 @@
+use arangodb_types::types::DBUuid;
+
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn create_and_drop_database() {
+    let (db_info, _collection) = init_db_connection().await;
+
+    // Execute.
+    let database_name = format!("Test{}", DBUuid::new());
+    let database = db_info
+        .create_database(&database_name)
+        .await
+        .expect("Cannot create the database");
+
+    assert!(
+        db_info.create_database(&database_name).await.is_err(),
+        "The database must not be created twice"
+    );
+
+    // Use it.
+    database
+        .create_collection("Items")
+        .await
+        .expect("Cannot create the collection");
+    database
+        .aql_str::<serde_json::Value>("INSERT { V: 5 } INTO Items")
+        .await
+        .expect("Cannot insert the document");
+
+    let values: Vec<u64> = database
+        .aql_str("FOR i IN Items RETURN i.V")
+        .await
+        .expect("Cannot read the documents");
+    assert_eq!(values, vec![5], "Incorrect documents");
+
+    // Drop it.
+    db_info
+        .drop_database(&database_name)
+        .await
+        .expect("Cannot drop the database");
+
+    let databases = db_info
+        .connection()
+        .accessible_databases()
+        .await
+        .expect("Cannot list the databases");
+    assert!(
+        !databases.contains_key(&database_name),
+        "The database must be dropped"
+    );
+
+    // The current database cannot be dropped.
+    assert!(
+        db_info
+            .drop_database(db_info.database().name())
+            .await
+            .is_err(),
+        "The current database must not be dropped"
+    );
+}
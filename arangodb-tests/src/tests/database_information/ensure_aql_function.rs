@@ -0,0 +1,51 @@
+use arangodb_types::types::{AqlFunction, DBUuid};
+
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn ensure_aql_function_idempotent() {
+    let (db_info, _collection) = init_db_connection().await;
+
+    let mut function = AqlFunction {
+        namespace: format!("Test{}", DBUuid::new_simple()),
+        name: "DOUBLE".to_string(),
+        code: "function (value) { return value * 2; }".to_string(),
+        deterministic: true,
+    };
+
+    // Execute.
+    let registered = db_info
+        .ensure_aql_function(&function)
+        .await
+        .expect("Cannot register the function");
+    assert!(registered, "The function must be registered");
+
+    let registered = db_info
+        .ensure_aql_function(&function)
+        .await
+        .expect("Cannot register the function");
+    assert!(!registered, "The function must not be registered twice");
+
+    // Use it.
+    let values: Vec<u64> = db_info
+        .database()
+        .aql_str(&format!("RETURN {}(2)", function.full_name()))
+        .await
+        .expect("Cannot call the function");
+    assert_eq!(values, vec![4], "Incorrect result");
+
+    // Change the code.
+    function.code = "function (value) { return value * 3; }".to_string();
+
+    let registered = db_info
+        .ensure_aql_function(&function)
+        .await
+        .expect("Cannot register the function");
+    assert!(registered, "The new code must be registered");
+
+    // Clean.
+    db_info
+        .remove_all_aql_function(&function.namespace)
+        .await
+        .expect("Cannot remove the function");
+}
@@ -0,0 +1,64 @@
+use arangodb_types::types::{CollectionKeyGenerator, CollectionKeyOptions, CollectionOptions};
+
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn ensure_collection_document_and_edge() {
+    let (db_info, _collection) = init_db_connection().await;
+
+    // Execute: document collection.
+    let document_options = CollectionOptions {
+        key_options: Some(CollectionKeyOptions {
+            generator: CollectionKeyGenerator::Padded,
+            allow_user_keys: true,
+            increment: None,
+            offset: None,
+        }),
+        ..Default::default()
+    };
+
+    db_info
+        .ensure_collection("EnsureDocuments", document_options.clone())
+        .await
+        .expect("The document collection must be created");
+    db_info
+        .ensure_collection("EnsureDocuments", document_options)
+        .await
+        .expect("Ensuring an existing document collection must succeed");
+
+    // Execute: edge collection.
+    let edge_options = CollectionOptions {
+        is_edge: true,
+        ..Default::default()
+    };
+
+    db_info
+        .ensure_collection("EnsureEdges", edge_options.clone())
+        .await
+        .expect("The edge collection must be created");
+    db_info
+        .ensure_collection("EnsureEdges", edge_options.clone())
+        .await
+        .expect("Ensuring an existing edge collection must succeed");
+
+    // Incompatible options.
+    db_info
+        .ensure_collection("EnsureDocuments", edge_options)
+        .await
+        .expect_err("Ensuring a document collection as edge must fail");
+    db_info
+        .ensure_collection(
+            "EnsureDocuments",
+            CollectionOptions {
+                key_options: Some(CollectionKeyOptions {
+                    generator: CollectionKeyGenerator::Uuid,
+                    allow_user_keys: true,
+                    increment: None,
+                    offset: None,
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("Ensuring a collection with another key generator must fail");
+}
@@ -0,0 +1,6 @@
+pub mod create_database;
+pub mod ensure_aql_function;
+pub mod ensure_collection;
+pub mod query_cache;
+pub mod run_migration;
+pub mod tail;
@@ -0,0 +1,44 @@
+use arangodb_types::aql::{AqlBuilder, AqlReturn, AQL_DOCUMENT_ID};
+use arangodb_types::traits::DBCollection;
+use arangodb_types::types::AqlQueryCacheMode;
+
+use crate::tests::db_mutex::model::MutexCollection;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn query_cache_on_demand() {
+    // Avoid other tests invalidating the cache.
+    let _test_lock = TEST_RWLOCK.write().await;
+    let (db_info, collection) = init_db_connection().await;
+
+    db_info
+        .set_query_cache_mode(AqlQueryCacheMode::Demand)
+        .await
+        .expect("Cannot set the query cache mode");
+
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, MutexCollection::name());
+    aql.return_step(AqlReturn::new_document());
+    aql.set_query_cache(Some(true));
+
+    // Execute.
+    collection
+        .send_raw_aql(&aql)
+        .await
+        .expect("Cannot send the query");
+    let response = collection
+        .send_raw_aql(&aql)
+        .await
+        .expect("Cannot send the query");
+
+    db_info
+        .set_query_cache_mode(AqlQueryCacheMode::Off)
+        .await
+        .expect("Cannot set the query cache mode");
+
+    assert_eq!(
+        response["cached"],
+        serde_json::json!(true),
+        "The results must be cached"
+    );
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use arangodb_types::types::{CollectionOptions, DBUuid, MigrationStep};
+
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn run_migration_twice() {
+    let (db_info, _collection) = init_db_connection().await;
+
+    // Preconditions.
+    let migration_name = format!("Migration{}", DBUuid::new());
+    let steps = vec![
+        MigrationStep::EnsureCollection {
+            name: "MigrationDocuments".to_string(),
+            options: CollectionOptions::default(),
+        },
+        MigrationStep::RunAql {
+            query: "INSERT { M: @name } INTO MigrationDocuments".to_string(),
+            bind_vars: {
+                let mut bind_vars = HashMap::new();
+                bind_vars.insert("name".to_string(), migration_name.clone().into());
+                bind_vars
+            },
+        },
+    ];
+
+    // Execute.
+    let applied = db_info
+        .run_migration(&migration_name, steps.clone())
+        .await
+        .expect("The migration must succeed");
+    assert!(applied, "The migration must be applied");
+
+    let applied = db_info
+        .run_migration(&migration_name, steps)
+        .await
+        .expect("The second migration must succeed");
+    assert!(!applied, "The migration must not be applied twice");
+
+    // Check DB.
+    let mut bind_vars = HashMap::new();
+    bind_vars.insert("name", migration_name.into());
+
+    let result: Vec<u64> = db_info
+        .send_aql_with_retries(
+            "FOR i IN MigrationDocuments FILTER i.M == @name COLLECT WITH COUNT INTO count RETURN count",
+            bind_vars,
+        )
+        .await
+        .expect("There is an error trying to count the documents");
+
+    assert_eq!(result, vec![1], "Incorrect document count");
+}
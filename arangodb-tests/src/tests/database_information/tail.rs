@@ -0,0 +1,67 @@
+use arangodb_types::traits::{DBCollection, DBDocument};
+use arangodb_types::types::{ChangeEventKind, DBUuid, NullableOption};
+use futures_util::StreamExt;
+
+use crate::tests::db_mutex::model::{MutexCollection, MutexDBDocument};
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn tail_document_changes() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let from_tick = db_info
+        .last_tick()
+        .await
+        .expect("There is an error trying to get the last tick");
+
+    let document_key = DBUuid::new();
+    let document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    document
+        .remove(None, collection.as_ref())
+        .await
+        .expect("Cannot remove the document from DB");
+
+    // Execute.
+    let stream = db_info
+        .tail(MutexCollection::name(), from_tick)
+        .await
+        .expect("There is an error trying to tail the collection");
+    let events: Vec<_> = stream
+        .filter(|v| {
+            let is_document = matches!(v, Ok(v) if v.key == document_key.to_string());
+            async move { is_document }
+        })
+        .take(2)
+        .map(|v| v.expect("There is an error trying to read the changes"))
+        .collect()
+        .await;
+
+    // Check.
+    assert_eq!(
+        events[0].kind,
+        ChangeEventKind::Upsert,
+        "Incorrect first kind"
+    );
+    assert_eq!(
+        events[0].document.get("V"),
+        Some(&5.into()),
+        "Incorrect document"
+    );
+    assert_eq!(
+        events[1].kind,
+        ChangeEventKind::Remove,
+        "Incorrect second kind"
+    );
+    assert!(events[0].tick < events[1].tick, "Incorrect tick order");
+}
@@ -1,6 +1,9 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use arangodb_types::traits::DBCollection;
 use arangodb_types::traits::DBDocument;
-use arangodb_types::types::{DBDateTime, DBMutex};
+use arangodb_types::types::{CollectionOptions, DBDateTime, DBMutex};
 use arangodb_types::types::{DBUuid, NullableOption};
 use arangodb_types::utilities::{DBMutexError, DBMutexGuard};
 
@@ -8,6 +11,7 @@ use crate::tests::constants::NODE_ID;
 use crate::tests::db_mutex::model::MutexDBDocument;
 use crate::tests::db_mutex::TEST_RWLOCK;
 use crate::tests::init_db_connection;
+use crate::tests::models::model::{AuditCollection, AuditDBDocument};
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn acquire_ok() {
@@ -176,3 +180,288 @@ async fn acquire_missing() {
         _ => unreachable!(),
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn acquire_already_locked_sub_second_timeout() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let change_flag = DBUuid::new();
+    let expiration = DBDateTime::now().after_seconds(200000);
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        db_mutex: NullableOption::Value(DBMutex {
+            expiration: expiration.clone(),
+            change_flag: change_flag.clone(),
+            node: NODE_ID.into(),
+        }),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let start = Instant::now();
+    let error = DBMutexGuard::<MutexDBDocument>::acquire_document_with_timeout(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        Some(Duration::from_millis(300)),
+        &collection,
+    )
+    .await;
+    let elapsed = start.elapsed();
+
+    match error {
+        Ok(_) => panic!("Locking must fail"),
+        Err(DBMutexError::Timeout) => {}
+        _ => unreachable!(),
+    }
+
+    assert!(
+        elapsed >= Duration::from_millis(250),
+        "The timeout expired too early: {:?}",
+        elapsed
+    );
+    assert!(
+        elapsed < Duration::from_secs(1),
+        "The timeout expired too late: {:?}",
+        elapsed
+    );
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    let db_mutex = document.db_mutex.unwrap_as_ref();
+    assert_eq!(db_mutex.expiration, expiration, "Incorrect expiration");
+    assert_eq!(db_mutex.change_flag, change_flag, "Incorrect change_flag");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn acquire_or_create_with_fields() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    let document_key = DBUuid::new();
+    let fields = MutexDBDocument {
+        db_key: Some(DBUuid::default()),
+        ..Default::default()
+    };
+
+    // Execute.
+    let (document, _mutex) = DBMutexGuard::<MutexDBDocument>::acquire_or_create_document(
+        &document_key,
+        &NODE_ID.into(),
+        Some(&fields),
+        None,
+        &collection,
+        || MutexDBDocument {
+            db_key: Some(document_key.clone()),
+            value: NullableOption::Value(5),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("Locking must succeed");
+
+    assert_eq!(document.db_key, Some(document_key.clone()), "Incorrect key");
+    assert!(
+        document.value.is_missing(),
+        "The value must be projected out"
+    );
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert_eq!(document.value, NullableOption::Value(5), "Incorrect value");
+    assert!(document.db_mutex.is_value(), "Incorrect mutex");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn acquire_or_create_concurrently() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    let document_key = DBUuid::new();
+
+    // Execute.
+    let tasks: Vec<_> = (0..8)
+        .map(|i| {
+            let document_key = document_key.clone();
+            let collection = collection.clone();
+
+            tokio::spawn(async move {
+                DBMutexGuard::<MutexDBDocument>::acquire_or_create_document(
+                    &document_key,
+                    &NODE_ID.into(),
+                    None,
+                    Some(0),
+                    &collection,
+                    || MutexDBDocument {
+                        db_key: Some(document_key.clone()),
+                        value: NullableOption::Value(i),
+                        ..Default::default()
+                    },
+                )
+                .await
+            })
+        })
+        .collect();
+
+    let mut holders = Vec::new();
+    for task in tasks {
+        match task.await.expect("The task must not panic") {
+            Ok(v) => holders.push(v),
+            Err(DBMutexError::Timeout) => {}
+            Err(e) => panic!("Incorrect error: {:?}", e),
+        }
+    }
+
+    // Check.
+    assert_eq!(holders.len(), 1, "Only one call must acquire the document");
+
+    let (document, _mutex) = holders.pop().unwrap();
+    let documents = collection
+        .get_all(None)
+        .await
+        .expect("There is an error trying to get the documents");
+    let documents: Vec<_> = documents
+        .into_iter()
+        .filter(|v| v.db_key.as_ref() == Some(&document_key))
+        .collect();
+
+    assert_eq!(documents.len(), 1, "The document must be created once");
+    assert_eq!(documents[0].value, document.value, "Incorrect value");
+    assert_eq!(
+        documents[0].db_mutex.unwrap_as_ref().change_flag,
+        document.db_mutex.unwrap_as_ref().change_flag,
+        "Incorrect mutex"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn acquire_or_create_existing_ignores_default() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, _collection) = init_db_connection().await;
+
+    db_info
+        .ensure_collection(AuditCollection::name(), CollectionOptions::default())
+        .await
+        .expect("Cannot create collection");
+    let collection = Arc::new(AuditCollection::new(&db_info));
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    AuditDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(1),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let (document, _mutex) = DBMutexGuard::<AuditDBDocument>::acquire_or_create_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+        // An invalid default must not prevent locking the existing document.
+        || AuditDBDocument {
+            value: NullableOption::Value(1000),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("Locking must succeed");
+
+    assert_eq!(document.value, NullableOption::Value(1), "Incorrect value");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn acquire_or_create_locked_does_not_write() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let (document, _mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    // Execute.
+    let other_node_id = format!("Other{}", NODE_ID);
+    let result = DBMutexGuard::<MutexDBDocument>::acquire_or_create_document(
+        &document_key,
+        &other_node_id.as_str().into(),
+        None,
+        Some(0),
+        &collection,
+        || MutexDBDocument {
+            db_key: Some(document_key.clone()),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    assert!(
+        matches!(result, Err(DBMutexError::Timeout)),
+        "Locking must time out"
+    );
+
+    // Check DB.
+    let db_document = collection
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert_eq!(db_document.db_rev, document.db_rev, "Incorrect revision");
+}
@@ -163,3 +163,134 @@ async fn acquire_list_mix() {
         }
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn acquire_list_in_batches() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let mut document_keys = Vec::new();
+    let mut documents = Vec::new();
+
+    for i in 0..5000_u32 {
+        let document_key = DBUuid::new();
+
+        // Leave some gaps to check the order is preserved between batches.
+        if i % 7 != 0 {
+            documents.push(MutexDBDocument {
+                db_key: Some(document_key.clone()),
+                ..Default::default()
+            });
+        }
+
+        document_keys.push(document_key);
+    }
+
+    collection
+        .insert_many(&documents)
+        .await
+        .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let (documents, mutex) = DBMutexGuard::<MutexDBDocument>::acquire_list(
+        &document_keys,
+        &NODE_ID.into(),
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    assert_eq!(documents.len(), document_keys.len(), "Incorrect length");
+
+    // Check DB.
+    for (i, (document_key, document)) in document_keys.iter().zip(documents).enumerate() {
+        if i % 7 == 0 {
+            assert!(document.is_none(), "Incorrect document at {}", i);
+            assert!(!mutex.contains_key(document_key).await, "Incorrect lock");
+            continue;
+        }
+
+        let document = document.expect("Incorrect document");
+
+        assert_eq!(
+            document.db_key,
+            Some(document_key.clone()),
+            "Incorrect db_key"
+        );
+        assert!(document.db_mutex.is_value(), "Incorrect mutex");
+        assert!(mutex.contains_key(document_key).await, "Incorrect lock");
+    }
+
+    mutex.release();
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn acquire_list_available() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let mut document_keys = Vec::new();
+    let mut available_keys = Vec::new();
+    let expiration = DBDateTime::now().after_seconds(200000);
+
+    for i in 0..10_u8 {
+        let document_key = DBUuid::new();
+        let db_mutex = if i % 2 == 0 {
+            available_keys.push(document_key.clone());
+            NullableOption::Missing
+        } else {
+            // Already locked.
+            NullableOption::Value(DBMutex {
+                expiration: expiration.clone(),
+                change_flag: DBUuid::new(),
+                node: NODE_ID.into(),
+            })
+        };
+
+        let _document = MutexDBDocument {
+            db_key: Some(document_key.clone()),
+            db_mutex,
+            ..Default::default()
+        }
+        .insert(true, collection.as_ref())
+        .await
+        .expect("Cannot add preconditions to DB");
+
+        document_keys.push(document_key);
+    }
+
+    // Missing document.
+    document_keys.push(DBUuid::new());
+
+    // Execute.
+    let (documents, mutex) = DBMutexGuard::<MutexDBDocument>::acquire_list_available(
+        &document_keys,
+        &NODE_ID.into(),
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    // Check.
+    let keys = documents
+        .iter()
+        .map(|v| v.db_key.clone().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(keys, available_keys, "Incorrect documents");
+
+    for key in &available_keys {
+        assert!(mutex.contains_key(key).await, "The document must be locked");
+    }
+}
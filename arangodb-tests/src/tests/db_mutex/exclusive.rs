@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use arangodb_types::traits::{DBCollection, DBDocument, DBSynchronizedDocument};
+use arangodb_types::types::{CollectionOptions, DBUuid, NullableOption};
+use arangodb_types::utilities::DBMutexGuard;
+
+use crate::tests::constants::NODE_ID;
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+use crate::tests::models::model::{ExclusiveCollection, ExclusiveDBDocument};
+
+#[test]
+fn exclusive_option() {
+    fn is_exclusive<T: DBSynchronizedDocument<'static>>() -> bool {
+        T::EXCLUSIVE_MUTEX
+    }
+
+    assert!(
+        is_exclusive::<ExclusiveDBDocument>(),
+        "The mutex must be exclusive"
+    );
+    assert!(
+        !is_exclusive::<MutexDBDocument>(),
+        "The mutex must not be exclusive by default"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn acquire_exclusive() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, _collection) = init_db_connection().await;
+
+    db_info
+        .ensure_collection(ExclusiveCollection::name(), CollectionOptions::default())
+        .await
+        .expect("Cannot create collection");
+    let collection = Arc::new(ExclusiveCollection::new(&db_info));
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = ExclusiveDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let (document, mutex) = DBMutexGuard::<ExclusiveDBDocument>::acquire_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    // Check.
+    assert_eq!(document.db_key, Some(document_key), "Incorrect db_key");
+    assert_eq!(document.value, NullableOption::Value(5), "Incorrect value");
+    assert_eq!(
+        &document.db_mutex.unwrap_as_ref().node,
+        &NODE_ID,
+        "Incorrect node"
+    );
+
+    mutex.release();
+}
@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use tokio::time::sleep;
+
+use arangodb_types::constants::MUTEX_ALIVE_INTERVAL;
+use arangodb_types::traits::DBCollection;
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBMutex, DBUuid, NullableOption};
+use arangodb_types::utilities::DBMutexGuard;
+
+use crate::tests::constants::NODE_ID;
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn heal_dead_alive_job() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let (document, mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    assert!(mutex.is_alive().await, "The mutex must be alive");
+    assert!(!mutex.heal().await, "An alive mutex must not be healed");
+
+    let db_mutex = document.db_mutex.unwrap_as_ref().clone();
+    let prev_expiration = db_mutex.expiration.clone();
+
+    // Make the alive job die by changing the mutex in DB.
+    MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        db_mutex: NullableOption::Value(DBMutex {
+            change_flag: DBUuid::new(),
+            ..db_mutex.clone()
+        }),
+        ..Default::default()
+    }
+    .update(true, collection.as_ref())
+    .await
+    .expect("Cannot change the mutex");
+
+    // Wait until the alive job dies.
+    sleep(Duration::from_secs(MUTEX_ALIVE_INTERVAL + 1)).await;
+
+    assert!(!mutex.is_alive().await, "The mutex must not be alive");
+
+    // Restore the mutex in DB.
+    MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        db_mutex: NullableOption::Value(db_mutex.clone()),
+        ..Default::default()
+    }
+    .update(true, collection.as_ref())
+    .await
+    .expect("Cannot restore the mutex");
+
+    // Execute.
+    assert!(mutex.heal().await, "The mutex must be healed");
+    assert!(mutex.is_alive().await, "The mutex must be alive again");
+
+    // Wait until the alive is completed.
+    sleep(Duration::from_secs(MUTEX_ALIVE_INTERVAL + 1)).await;
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert!(document.db_mutex.is_value(), "Incorrect mutex");
+
+    let db_mutex = document.db_mutex.unwrap_as_ref();
+    assert_ne!(db_mutex.expiration, prev_expiration, "Incorrect expiration");
+
+    // Released mutexes cannot be healed.
+    let handle = mutex.clone_handle();
+    mutex.release();
+
+    // Wait until the release is completed.
+    sleep(Duration::from_millis(500)).await;
+
+    assert!(!handle.heal().await, "A released mutex must not be healed");
+}
@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+use tokio::time::sleep;
+
+use arangodb_types::constants::MUTEX_ALIVE_INTERVAL;
+use arangodb_types::traits::DBCollection;
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::DBUuid;
+use arangodb_types::utilities::DBMutexGuard;
+
+use crate::tests::constants::NODE_ID;
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn merge_ok() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let keys = vec![DBUuid::new(), DBUuid::new()];
+    for key in &keys {
+        let _document = MutexDBDocument {
+            db_key: Some(key.clone()),
+            ..Default::default()
+        }
+        .insert(true, collection.as_ref())
+        .await
+        .expect("Cannot add preconditions to DB");
+    }
+
+    let (document, mut mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &keys[0],
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    let change_flag = document.db_mutex.unwrap_as_ref().change_flag.clone();
+
+    let (other_document, other_mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &keys[1],
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    let prev_expiration = other_document.db_mutex.unwrap_as_ref().expiration.clone();
+
+    // Execute.
+    mutex
+        .merge(&other_mutex)
+        .await
+        .expect("Merging must succeed");
+
+    for key in &keys {
+        assert!(mutex.contains_key(key).await, "The key must be merged");
+    }
+
+    // Wait until the alive is completed.
+    sleep(Duration::from_secs(MUTEX_ALIVE_INTERVAL + 1)).await;
+
+    assert!(mutex.is_alive().await, "The mutex must be alive");
+
+    // Check DB.
+    for key in &keys {
+        let document = collection
+            .get_one_by_key(key, None)
+            .await
+            .expect("There is an error trying to get the document")
+            .expect("The document does not exist in DB");
+
+        let db_mutex = document.db_mutex.unwrap_as_ref();
+        assert_eq!(&db_mutex.node, &NODE_ID, "Incorrect node");
+        assert_eq!(db_mutex.change_flag, change_flag, "Incorrect change_flag");
+        assert_ne!(db_mutex.expiration, prev_expiration, "Incorrect expiration");
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn merge_concurrently_in_opposite_directions() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let keys = vec![DBUuid::new(), DBUuid::new()];
+    for key in &keys {
+        let _document = MutexDBDocument {
+            db_key: Some(key.clone()),
+            ..Default::default()
+        }
+        .insert(true, collection.as_ref())
+        .await
+        .expect("Cannot add preconditions to DB");
+    }
+
+    let (_, mut mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &keys[0],
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    let (_, mut other_mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &keys[1],
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    let mutex_handle = mutex.clone_handle();
+    let other_mutex_handle = other_mutex.clone_handle();
+
+    // Execute.
+    let merges = async {
+        tokio::join!(
+            mutex.merge(&other_mutex_handle),
+            other_mutex.merge(&mutex_handle)
+        )
+    };
+
+    let (result, other_result) = tokio::time::timeout(Duration::from_secs(10), merges)
+        .await
+        .expect("The merges must not deadlock");
+
+    result.expect("Merging must succeed");
+    other_result.expect("Merging must succeed");
+
+    // Check.
+    for key in &keys {
+        let in_mutex = mutex.contains_key(key).await;
+        let in_other_mutex = other_mutex.contains_key(key).await;
+        assert_ne!(
+            in_mutex, in_other_mutex,
+            "The key must be in exactly one of the mutexes"
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn merge_different_nodes_keeps_other_locked() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let keys = vec![DBUuid::new(), DBUuid::new()];
+    for key in &keys {
+        let _document = MutexDBDocument {
+            db_key: Some(key.clone()),
+            ..Default::default()
+        }
+        .insert(true, collection.as_ref())
+        .await
+        .expect("Cannot add preconditions to DB");
+    }
+
+    let (_, mut mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &keys[0],
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    let other_node_id = format!("Other{}", NODE_ID);
+    let (other_document, other_mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &keys[1],
+        &other_node_id.as_str().into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    let change_flag = other_document.db_mutex.unwrap_as_ref().change_flag.clone();
+
+    // Execute.
+    mutex
+        .merge(&other_mutex)
+        .await
+        .expect_err("Merging mutexes of different nodes must fail");
+
+    // Check.
+    assert!(
+        other_mutex.contains_key(&keys[1]).await,
+        "The key must still be in the other mutex"
+    );
+    assert!(
+        !mutex.contains_key(&keys[1]).await,
+        "The key must not be merged"
+    );
+    assert!(
+        other_mutex.is_alive().await,
+        "The other mutex must be alive"
+    );
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&keys[1], None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    let db_mutex = document.db_mutex.unwrap_as_ref();
+    assert_eq!(db_mutex.node.as_str(), other_node_id, "Incorrect node");
+    assert_eq!(db_mutex.change_flag, change_flag, "Incorrect change_flag");
+}
@@ -0,0 +1,91 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBDateTime, DBMutex, DBUuid, NullableOption};
+use arangodb_types::utilities::DBMutexGuard;
+
+use crate::tests::constants::NODE_ID;
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn min_remaining_ok() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let keys = [DBUuid::new(), DBUuid::new()];
+    for key in &keys {
+        let _document = MutexDBDocument {
+            db_key: Some(key.clone()),
+            ..Default::default()
+        }
+        .insert(true, collection.as_ref())
+        .await
+        .expect("Cannot add preconditions to DB");
+    }
+
+    let (documents, mutex) =
+        DBMutexGuard::<MutexDBDocument>::acquire_list(&keys, &NODE_ID.into(), None, &collection)
+            .await
+            .expect("Locking must succeed");
+
+    // Set different expirations keeping the mutex before the alive job renews them.
+    let now = DBDateTime::now();
+    for (document, seconds) in documents.iter().zip([1000, 100]) {
+        let document = document.as_ref().expect("The document must be locked");
+        let db_mutex = document.db_mutex.unwrap_as_ref();
+
+        MutexDBDocument {
+            db_key: document.db_key.clone(),
+            db_mutex: NullableOption::Value(DBMutex {
+                node: db_mutex.node.clone(),
+                change_flag: db_mutex.change_flag.clone(),
+                expiration: now.after_seconds(seconds),
+            }),
+            ..Default::default()
+        }
+        .update(true, collection.as_ref())
+        .await
+        .expect("Cannot update the expiration");
+    }
+
+    // Execute.
+    let remaining = mutex
+        .min_remaining()
+        .await
+        .expect("There is an error trying to get the remaining time")
+        .expect("The documents must be locked");
+
+    // Check.
+    assert!(
+        (98..=100).contains(&*remaining),
+        "Incorrect remaining time: {}",
+        *remaining
+    );
+
+    mutex.release();
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn min_remaining_empty() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Execute.
+    let (_documents, mutex) =
+        DBMutexGuard::<MutexDBDocument>::acquire_list(&[], &NODE_ID.into(), None, &collection)
+            .await
+            .expect("Locking must succeed");
+
+    let remaining = mutex
+        .min_remaining()
+        .await
+        .expect("There is an error trying to get the remaining time");
+
+    // Check.
+    assert!(remaining.is_none(), "There must not be remaining time");
+}
@@ -6,10 +6,17 @@ pub mod acquire_aql;
 pub mod acquire_list;
 pub mod alive;
 pub mod alive_list;
+pub mod exclusive;
+pub mod heal;
+pub mod merge;
+pub mod min_remaining;
 pub mod model;
 pub mod release;
 pub mod release_list;
+pub mod reload;
+pub mod rollback;
 pub mod types;
+pub mod update;
 
 lazy_static! {
     pub static ref TEST_RWLOCK: RwLock<()> = RwLock::new(());
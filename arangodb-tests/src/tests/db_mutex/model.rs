@@ -3,9 +3,9 @@ use std::sync::Arc;
 
 use arangodb_types::models::model;
 
-use arangodb_types::traits::DBCollection;
-use arangodb_types::types::DBInfo;
+use arangodb_types::traits::{DBCollection, DBCollectionFromInfo};
 use arangodb_types::types::DBUuid;
+use arangodb_types::types::{CollectionOptions, DBInfo};
 
 #[derive(Debug)]
 pub struct MutexCollection {
@@ -16,13 +16,13 @@ impl MutexCollection {
     // CONSTRUCTORS -----------------------------------------------------------
 
     pub async fn new(db_info: &Arc<DBInfo>) -> Result<Arc<Self>, anyhow::Error> {
-        let database = &db_info.database;
-
         // Initialize collection.
         let collection = Arc::new(MutexCollection {
             db_info: db_info.clone(),
         });
-        let _ = database.create_collection(MutexCollection::name()).await; // Ignore error because it means already created.
+        db_info
+            .ensure_collection(MutexCollection::name(), CollectionOptions::default())
+            .await?;
 
         Ok(collection)
     }
@@ -40,6 +40,14 @@ impl DBCollection for MutexCollection {
     }
 }
 
+impl DBCollectionFromInfo for MutexCollection {
+    fn from_db_info(db_info: &Arc<DBInfo>) -> Arc<Self> {
+        Arc::new(MutexCollection {
+            db_info: db_info.clone(),
+        })
+    }
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
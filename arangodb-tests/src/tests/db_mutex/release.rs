@@ -4,7 +4,8 @@ use tokio::time::sleep;
 
 use arangodb_types::traits::DBCollection;
 use arangodb_types::traits::DBDocument;
-use arangodb_types::types::DBUuid;
+use arangodb_types::types::{DBDateTime, DBMutex};
+use arangodb_types::types::{DBUuid, NullableOption};
 use arangodb_types::utilities::DBMutexGuard;
 
 use crate::tests::constants::NODE_ID;
@@ -97,3 +98,180 @@ async fn release_manually() {
 
     assert!(!document.db_mutex.is_value(), "Incorrect mutex");
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn release_shared_handles() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    let (_document, mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    let handle = mutex.clone_handle();
+    drop(mutex);
+
+    // Wait until a hypothetical release is completed.
+    sleep(Duration::from_secs(3)).await;
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert!(document.db_mutex.is_value(), "The mutex must be kept");
+    assert!(
+        handle.contains_key(&document_key).await,
+        "The handle must keep the key"
+    );
+
+    drop(handle);
+
+    // Wait until the release is completed.
+    sleep(Duration::from_secs(3)).await;
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert!(!document.db_mutex.is_value(), "Incorrect mutex");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn release_stale() {
+    let _test_lock = TEST_RWLOCK.write().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let expired_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(expired_key.clone()),
+        db_mutex: NullableOption::Value(DBMutex {
+            expiration: DBDateTime::now(),
+            change_flag: DBUuid::new(),
+            node: "OtherNode".into(),
+        }),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let alive_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(alive_key.clone()),
+        db_mutex: NullableOption::Value(DBMutex {
+            expiration: DBDateTime::now().after_seconds(200000),
+            change_flag: DBUuid::new(),
+            node: "OtherNode".into(),
+        }),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    // Execute.
+    DBMutexGuard::<MutexDBDocument>::release_stale(&collection).await;
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&expired_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert!(!document.db_mutex.is_value(), "Incorrect mutex");
+
+    let document = collection
+        .get_one_by_key(&alive_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert!(document.db_mutex.is_value(), "Incorrect mutex");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn release_detached() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let (document, mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    let previous_mutex = document.db_mutex.unwrap_as_ref().clone();
+
+    // Execute.
+    mutex.into_detached().await;
+
+    // Wait until a hypothetical release is completed.
+    sleep(Duration::from_secs(3)).await;
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert!(document.db_mutex.is_value(), "The mutex must be kept");
+
+    let db_mutex = document.db_mutex.unwrap_as_ref();
+    assert_eq!(&db_mutex.node, &NODE_ID, "Incorrect node");
+    assert_eq!(
+        db_mutex.change_flag, previous_mutex.change_flag,
+        "Incorrect change_flag"
+    );
+}
@@ -115,3 +115,57 @@ async fn release_list_manually() {
         assert!(!document.db_mutex.is_value(), "Incorrect mutex");
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn release_list_returning() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let mut document_keys = Vec::new();
+
+    for _ in 0..10_u8 {
+        let document_key = DBUuid::new();
+        let _document = MutexDBDocument {
+            db_key: Some(document_key.clone()),
+            ..Default::default()
+        }
+        .insert(true, collection.as_ref())
+        .await
+        .expect("Cannot add preconditions to DB");
+
+        document_keys.push(document_key);
+    }
+
+    // Execute.
+    let (_documents, mutex) = DBMutexGuard::<MutexDBDocument>::acquire_list(
+        &document_keys,
+        &NODE_ID.into(),
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    let mut released_keys = mutex.release_returning().await;
+
+    // Check.
+    released_keys.sort();
+    document_keys.sort();
+    assert_eq!(released_keys, document_keys, "Incorrect released keys");
+
+    // Check DB.
+    for document_key in document_keys {
+        let document = collection
+            .get_one_by_key(&document_key, None)
+            .await
+            .expect("There is an error trying to get the document")
+            .expect("The document does not exist in DB");
+
+        assert!(!document.db_mutex.is_value(), "Incorrect mutex");
+    }
+}
@@ -0,0 +1,117 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBDateTime, DBMutex, DBUuid, NullableOption};
+use arangodb_types::utilities::{DBMutexError, DBMutexGuard};
+
+use crate::tests::constants::NODE_ID;
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn reload_locked_document() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(1),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let (_document, mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    // Modify the document while it is locked.
+    mutex
+        .update_document(
+            &MutexDBDocument {
+                db_key: Some(document_key.clone()),
+                value: NullableOption::Value(2),
+                ..Default::default()
+            },
+            true,
+        )
+        .await
+        .expect("The update must succeed");
+
+    // Execute.
+    let document = mutex
+        .reload(&document_key)
+        .await
+        .expect("The reload must succeed")
+        .expect("The document does not exist in DB");
+
+    // Check.
+    assert_eq!(document.value, NullableOption::Value(2), "Incorrect value");
+
+    mutex.release();
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn reload_lost_lock() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let (_document, mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    // Another node takes the lock.
+    MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        db_mutex: NullableOption::Value(DBMutex {
+            expiration: DBDateTime::now().after_seconds(200000),
+            change_flag: DBUuid::new(),
+            node: "OtherNode".into(),
+        }),
+        ..Default::default()
+    }
+    .update(true, collection.as_ref())
+    .await
+    .expect("Cannot change the mutex in DB");
+
+    // Execute.
+    let error = mutex
+        .reload(&document_key)
+        .await
+        .expect_err("The reload must fail");
+
+    // Check.
+    assert!(
+        matches!(error, DBMutexError::NotFound),
+        "Incorrect error: {}",
+        error
+    );
+}
@@ -0,0 +1,70 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBUuid, NullableOption};
+use arangodb_types::utilities::DBMutexGuard;
+
+use crate::tests::constants::NODE_ID;
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn rollback_on_error() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(1),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let (mut document, mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    // Execute.
+    let result: Result<(), &str> = mutex.with_rollback(&mut document, |document| {
+        document.value = NullableOption::Value(2);
+        Err("failure")
+    });
+
+    assert_eq!(result, Err("failure"), "Incorrect result");
+    assert_eq!(document.value, NullableOption::Value(1), "Incorrect value");
+
+    let result: Result<u64, &str> = mutex.with_rollback(&mut document, |document| {
+        document.value = NullableOption::Value(3);
+        Ok(3)
+    });
+
+    assert_eq!(result, Ok(3), "Incorrect result");
+    assert_eq!(document.value, NullableOption::Value(3), "Incorrect value");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn snapshot_restore() {
+    let mut document = MutexDBDocument {
+        value: NullableOption::Value(1),
+        ..Default::default()
+    };
+
+    let snapshot = document.snapshot();
+    document.value = NullableOption::Null;
+
+    document.restore(snapshot);
+    assert_eq!(document.value, NullableOption::Value(1), "Incorrect value");
+}
@@ -0,0 +1,229 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::time::sleep;
+
+use arangodb_types::traits::DBCollection;
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{CollectionOptions, DBUuid, NullableOption};
+use arangodb_types::utilities::{DBMutexError, DBMutexGuard};
+
+use crate::tests::constants::NODE_ID;
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+use crate::tests::models::model::{AuditCollection, AuditDBDocument};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn update_document_ok() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(1),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let (document, mutex) = DBMutexGuard::<MutexDBDocument>::acquire_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    let previous_mutex = document.db_mutex.unwrap_as_ref().clone();
+
+    // Wait to get a different expiration.
+    sleep(Duration::from_secs(1)).await;
+
+    // Execute.
+    let result = mutex
+        .update_document(
+            &MutexDBDocument {
+                db_key: Some(document_key.clone()),
+                value: NullableOption::Value(2),
+                ..Default::default()
+            },
+            true,
+        )
+        .await
+        .expect("The update must succeed");
+
+    assert_eq!(result.value, NullableOption::Value(2), "Incorrect value");
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert_eq!(document.value, NullableOption::Value(2), "Incorrect value");
+    assert!(document.db_mutex.is_value(), "Incorrect mutex");
+
+    let db_mutex = document.db_mutex.unwrap_as_ref();
+    assert_eq!(&db_mutex.node, &NODE_ID, "Incorrect node");
+    assert_eq!(
+        db_mutex.change_flag, previous_mutex.change_flag,
+        "Incorrect change_flag"
+    );
+    assert_ne!(
+        db_mutex.expiration, previous_mutex.expiration,
+        "Incorrect expiration"
+    );
+    assert!(!db_mutex.expiration.is_expired(), "Incorrect expiration");
+
+    mutex.release();
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn update_document_not_locked() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (_db_info, collection) = init_db_connection().await;
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let _document = MutexDBDocument {
+        db_key: Some(document_key.clone()),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let (_list, mutex) =
+        DBMutexGuard::<MutexDBDocument>::acquire_list(&[], &NODE_ID.into(), None, &collection)
+            .await
+            .expect("Locking must succeed");
+
+    // Execute.
+    let error = mutex
+        .update_document(
+            &MutexDBDocument {
+                db_key: Some(document_key.clone()),
+                value: NullableOption::Value(2),
+                ..Default::default()
+            },
+            true,
+        )
+        .await
+        .expect_err("The update must fail");
+
+    assert!(
+        matches!(error, DBMutexError::NotFound),
+        "Incorrect error: {}",
+        error
+    );
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert!(document.value.is_missing(), "Incorrect value");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn update_document_timestamps_and_validation() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, _collection) = init_db_connection().await;
+
+    db_info
+        .ensure_collection(AuditCollection::name(), CollectionOptions::default())
+        .await
+        .expect("Cannot create collection");
+    let collection = Arc::new(AuditCollection::new(&db_info));
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let inserted = AuditDBDocument {
+        db_key: Some(document_key.clone()),
+        value: NullableOption::Value(1),
+        ..Default::default()
+    }
+    .insert(true, collection.as_ref())
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    let (_document, mutex) = DBMutexGuard::<AuditDBDocument>::acquire_document(
+        &document_key,
+        &NODE_ID.into(),
+        None,
+        None,
+        &collection,
+    )
+    .await
+    .expect("Locking must succeed");
+
+    // Wait to get a different timestamp.
+    sleep(Duration::from_secs(1)).await;
+
+    // Execute.
+    let result = mutex
+        .update_document(
+            &AuditDBDocument {
+                db_key: Some(document_key.clone()),
+                value: NullableOption::Value(2),
+                ..Default::default()
+            },
+            true,
+        )
+        .await
+        .expect("The update must succeed");
+
+    assert!(result.updated_at.is_value(), "Incorrect updated_at");
+    assert_ne!(
+        result.updated_at, inserted.updated_at,
+        "The updated_at must change"
+    );
+
+    let error = mutex
+        .update_document(
+            &AuditDBDocument {
+                db_key: Some(document_key.clone()),
+                value: NullableOption::Value(200),
+                ..Default::default()
+            },
+            true,
+        )
+        .await
+        .expect_err("The update must fail");
+
+    assert!(
+        matches!(error, DBMutexError::Other(_)),
+        "Incorrect error: {}",
+        error
+    );
+
+    // Check DB.
+    let document = collection
+        .get_one_by_key(&document_key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert_eq!(document.value, NullableOption::Value(2), "Incorrect value");
+    assert_eq!(
+        document.updated_at, result.updated_at,
+        "Incorrect updated_at"
+    );
+}
@@ -1,19 +1,25 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use arangodb_types::types::DBInfo;
 
 use crate::tests::constants::{DB_NAME, DB_PASSWORD, DB_URL, DB_USERNAME};
 use crate::tests::db_mutex::model::MutexCollection;
 
+pub mod collection;
 pub mod constants;
+pub mod database_information;
 pub mod db_mutex;
+pub mod models;
 
 async fn init_db_connection() -> (Arc<DBInfo>, Arc<MutexCollection>) {
-    let db_info = DBInfo::connect(
+    let db_info = DBInfo::connect_with_retries(
         DB_URL.into(),
         DB_NAME.into(),
         DB_USERNAME.into(),
         DB_PASSWORD.into(),
+        5,
+        Duration::from_millis(500),
     )
     .await
     .expect("Cannot connect with DB");
@@ -0,0 +1,80 @@
+use arangodb_types::traits::ApplyPatch;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::models::model::{
+    ApiProfileContact, ProfileApiDocument, ProfileContact, ProfileDBDocument,
+};
+
+#[test]
+fn apply_partial_patch() {
+    let key = DBUuid::new();
+    let mut db_document = ProfileDBDocument {
+        db_key: Some(key.clone()),
+        name: NullableOption::Value("name".to_string()),
+        age: NullableOption::Value(30),
+        contact: NullableOption::Value(ProfileContact {
+            email: NullableOption::Value("user@example.com".to_string()),
+            phone: NullableOption::Value("555".to_string()),
+        }),
+        ..Default::default()
+    };
+
+    let patch = ProfileApiDocument {
+        id: Some(DBUuid::new()),
+        age: NullableOption::Null,
+        contact: NullableOption::Value(ApiProfileContact {
+            phone: NullableOption::Value("777".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    db_document.apply_patch(patch);
+
+    assert_eq!(db_document.db_key, Some(key), "The key must not be patched");
+    assert_eq!(
+        db_document.name,
+        NullableOption::Value("name".to_string()),
+        "Missing fields must be kept"
+    );
+    assert_eq!(db_document.age, NullableOption::Null, "Incorrect age");
+
+    let contact = db_document.contact.unwrap();
+    assert_eq!(
+        contact.email,
+        NullableOption::Value("user@example.com".to_string()),
+        "Missing sub-model fields must be kept"
+    );
+    assert_eq!(
+        contact.phone,
+        NullableOption::Value("777".to_string()),
+        "Incorrect phone"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn apply_patch_to_missing_sub_model() {
+    let mut db_document = ProfileDBDocument::default();
+
+    let patch = ProfileApiDocument {
+        contact: NullableOption::Value(ApiProfileContact {
+            email: NullableOption::Value("user@example.com".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    db_document.apply_patch(patch);
+
+    let contact = db_document.contact.unwrap();
+    assert_eq!(
+        contact.email,
+        NullableOption::Value("user@example.com".to_string()),
+        "Incorrect email"
+    );
+    assert_eq!(contact.phone, NullableOption::Missing, "Incorrect phone");
+}
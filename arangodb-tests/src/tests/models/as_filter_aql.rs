@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+
+use arangodb_types::aql::{AqlBuilder, AqlReturn, AQL_DOCUMENT_ID};
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::models::model::{FlattenDBDocument, RedactDBDocument, RedactInfo, TagList};
+
+#[test]
+fn as_filter_aql_two_fields() {
+    let example = RedactDBDocument {
+        name: NullableOption::Value("John".to_string()),
+        age: NullableOption::Null,
+        ..Default::default()
+    };
+
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Redacts");
+    example
+        .as_filter_aql(&mut aql)
+        .expect("The filter must be built");
+    aql.return_step(AqlReturn::new_document());
+
+    assert_eq!(
+        aql.build_query(),
+        "FOR i IN Redacts FILTER i.N == @v0 && i.A == @v1 RETURN i",
+        "Incorrect query"
+    );
+}
+
+#[test]
+fn as_filter_aql_empty() {
+    let example = RedactDBDocument::default();
+
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Redacts");
+    example
+        .as_filter_aql(&mut aql)
+        .expect("The filter must be built");
+    aql.return_step(AqlReturn::new_document());
+
+    assert_eq!(
+        aql.build_query(),
+        "FOR i IN Redacts RETURN i",
+        "Missing fields must not be filtered"
+    );
+}
+
+#[test]
+fn as_filter_aql_flatten() {
+    let example = FlattenDBDocument {
+        db_key: None,
+        db_rev: None,
+        name: NullableOption::Value("John".to_string()),
+        info: RedactInfo {
+            email: NullableOption::Value("john@example.com".to_string()),
+            phone: NullableOption::Missing,
+        },
+    };
+
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Flattens");
+    example
+        .as_filter_aql(&mut aql)
+        .expect("The filter must be built");
+    aql.return_step(AqlReturn::new_document());
+
+    assert_eq!(
+        aql.build_query(),
+        "FOR i IN Flattens FILTER i.N == @v0 FILTER i.E == @v1 RETURN i",
+        "Flattened fields must be filtered at the root"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn estimate_cost_unindexed_field() {
+    let example = RedactDBDocument {
+        db_key: Some(DBUuid::new()),
+        name: NullableOption::Value("John".to_string()),
+        age: NullableOption::Null,
+        ..Default::default()
+    };
+    let indexed_fields = HashSet::from(["N".to_string()]);
+
+    let cost = example.estimate_cost(&indexed_fields);
+    assert_eq!(cost.fields, vec!["_key", "N", "A"], "Incorrect fields");
+    assert_eq!(
+        cost.unindexed_fields,
+        vec!["A"],
+        "Incorrect unindexed fields"
+    );
+    assert!(!cost.is_fully_indexed(), "The age must be flagged");
+    assert!(!cost.requires_full_scan(), "The name is indexed");
+
+    let example = RedactDBDocument {
+        age: NullableOption::Value(5),
+        ..Default::default()
+    };
+
+    let cost = example.estimate_cost(&indexed_fields);
+    assert!(cost.requires_full_scan(), "The age is not indexed");
+}
+
+#[test]
+fn estimate_cost_flatten() {
+    let example = FlattenDBDocument {
+        db_key: None,
+        db_rev: None,
+        name: NullableOption::Value("John".to_string()),
+        info: RedactInfo {
+            email: NullableOption::Value("john@example.com".to_string()),
+            phone: NullableOption::Missing,
+        },
+    };
+    let indexed_fields = HashSet::from(["E".to_string()]);
+
+    let cost = example.estimate_cost(&indexed_fields);
+    assert_eq!(cost.fields, vec!["N", "E"], "Incorrect fields");
+    assert_eq!(
+        cost.unindexed_fields,
+        vec!["N"],
+        "Incorrect unindexed fields"
+    );
+    assert!(!cost.requires_full_scan(), "The email is indexed");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+fn build_tag_list() -> TagList {
+    TagList {
+        name: NullableOption::Value("John".to_string()),
+        tags: NullableOption::Value(vec!["a".to_string(), "b".to_string()]),
+    }
+}
+
+#[test]
+fn as_contains_filter_aql() {
+    let example = build_tag_list();
+
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Tags");
+    example
+        .as_contains_filter_aql(&mut aql, false)
+        .expect("The filter must be built");
+    aql.return_step(AqlReturn::new_document());
+
+    assert_eq!(
+        aql.build_query(),
+        "FOR i IN Tags FILTER @v0 IN i.T && @v1 IN i.T RETURN i",
+        "Incorrect query"
+    );
+}
+
+#[test]
+fn as_contains_filter_aql_negated() {
+    let example = build_tag_list();
+
+    let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Tags");
+    example
+        .as_contains_filter_aql(&mut aql, true)
+        .expect("The filter must be built");
+    aql.return_step(AqlReturn::new_document());
+
+    assert_eq!(
+        aql.build_query(),
+        "FOR i IN Tags FILTER @v0 NOT IN i.T && @v1 NOT IN i.T RETURN i",
+        "Incorrect query"
+    );
+}
+
+#[test]
+fn estimate_contains_cost_unindexed_field() {
+    let example = build_tag_list();
+
+    let cost = example.estimate_contains_cost(&HashSet::new());
+    assert_eq!(cost.fields, vec!["T[*]"], "Incorrect fields");
+    assert!(cost.requires_full_scan(), "The tags are not indexed");
+
+    let cost = example.estimate_contains_cost(&HashSet::from(["T[*]".to_string()]));
+    assert!(cost.is_fully_indexed(), "The tags are indexed");
+}
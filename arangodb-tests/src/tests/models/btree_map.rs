@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::models::model::{RankingApiDocument, RankingDBDocument};
+
+#[test]
+fn btree_map_api_db_conversion() {
+    let key = DBUuid::new();
+    let mut scores = BTreeMap::new();
+    scores.insert("b".to_string(), 2);
+    scores.insert("a".to_string(), 1);
+
+    let db_document = RankingDBDocument {
+        db_key: Some(key.clone()),
+        scores: NullableOption::Value(scores.clone()),
+        ..Default::default()
+    };
+
+    let api_document = RankingApiDocument::from(db_document);
+    assert_eq!(api_document.id, Some(key.clone()), "Incorrect id");
+    assert_eq!(
+        api_document.scores,
+        NullableOption::Value(scores.clone()),
+        "Incorrect API scores"
+    );
+
+    let db_document = RankingDBDocument::from(api_document);
+    assert_eq!(db_document.db_key, Some(key), "Incorrect db_key");
+    assert_eq!(
+        db_document.scores,
+        NullableOption::Value(scores),
+        "Incorrect DB scores"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn btree_map_serialization_is_sorted() {
+    let mut scores = BTreeMap::new();
+    scores.insert("b".to_string(), 2);
+    scores.insert("a".to_string(), 1);
+
+    let document = RankingDBDocument {
+        scores: NullableOption::Value(scores),
+        ..Default::default()
+    };
+
+    let serialization = serde_json::to_string(&document).unwrap();
+    assert_eq!(serialization, r#"{"S":{"a":1,"b":2}}"#);
+}
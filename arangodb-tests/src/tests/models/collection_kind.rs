@@ -0,0 +1,58 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::DBUuid;
+
+use crate::tests::db_mutex::model::{CollectionKind, MutexDBDocument};
+use crate::tests::models::model::{RedactDBDocument, TestCollectionKind};
+
+#[test]
+fn db_id_default_collection_type() {
+    let key = DBUuid::new();
+    let document = MutexDBDocument {
+        db_key: Some(key.clone()),
+        ..Default::default()
+    };
+
+    let db_id = document.db_id().expect("The id must be present");
+    assert_eq!(
+        db_id.collection(),
+        &CollectionKind::Mutexes,
+        "Incorrect kind"
+    );
+
+    let json = serde_json::to_string(&db_id).unwrap();
+    assert_eq!(json, format!("\"Mutexes/{}\"", key), "Incorrect id");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn db_id_custom_collection_type() {
+    let key = DBUuid::new();
+    let document = RedactDBDocument {
+        db_key: Some(key.clone()),
+        ..Default::default()
+    };
+
+    let db_id = document.db_id().expect("The id must be present");
+    assert_eq!(
+        db_id.collection(),
+        &TestCollectionKind::Redacts,
+        "Incorrect kind"
+    );
+
+    let json = serde_json::to_string(&db_id).unwrap();
+    assert_eq!(json, format!("\"Redacts/{}\"", key), "Incorrect id");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn db_id_without_key() {
+    let document = RedactDBDocument::default();
+
+    assert!(document.db_id().is_none(), "The id must be missing");
+}
@@ -0,0 +1,74 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::models::model::PageDBDocument;
+
+#[test]
+fn compound_key_computed() {
+    let document = PageDBDocument {
+        tenant: NullableOption::Value("acme".to_string()),
+        slug: NullableOption::Value("home".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        document.compute_key().unwrap(),
+        Some("acme-home".parse::<DBUuid>().unwrap()),
+        "Incorrect key"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn compound_key_missing_field() {
+    let document = PageDBDocument {
+        tenant: NullableOption::Value("acme".to_string()),
+        slug: NullableOption::Null,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        document.compute_key().unwrap(),
+        None,
+        "The key must not be computed"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn compound_key_with_separator() {
+    let document = PageDBDocument {
+        tenant: NullableOption::Value("acme-corp".to_string()),
+        slug: NullableOption::Value("home".to_string()),
+        ..Default::default()
+    };
+
+    assert!(
+        document.compute_key().is_err(),
+        "The separator must be rejected inside the parts"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn compound_key_invalid() {
+    let document = PageDBDocument {
+        tenant: NullableOption::Value("acme corp".to_string()),
+        slug: NullableOption::Value("home".to_string()),
+        ..Default::default()
+    };
+
+    assert!(
+        document.compute_key().is_err(),
+        "The invalid key must be an error"
+    );
+}
@@ -0,0 +1,43 @@
+use arangodb_types::traits::{to_api_vec, to_db_vec};
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::models::model::{ReadonlyApiDocument, ReadonlyDBDocument};
+
+#[test]
+fn convert_vec() {
+    let keys = [DBUuid::new(), DBUuid::new()];
+    let db_documents: Vec<_> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| ReadonlyDBDocument {
+            db_key: Some(key.clone()),
+            name: NullableOption::Value(format!("name{}", i)),
+            ..Default::default()
+        })
+        .collect();
+
+    // DB to API.
+    let api_documents: Vec<ReadonlyApiDocument> = to_api_vec(db_documents);
+
+    assert_eq!(api_documents.len(), 2, "Incorrect length");
+    for (i, (document, key)) in api_documents.iter().zip(&keys).enumerate() {
+        assert_eq!(document.id.as_ref(), Some(key), "Incorrect id");
+        assert_eq!(
+            document.name,
+            NullableOption::Value(format!("name{}", i)),
+            "Incorrect name"
+        );
+    }
+
+    // API to DB.
+    let db_documents: Vec<ReadonlyDBDocument> = to_db_vec(api_documents);
+
+    assert_eq!(db_documents.len(), 2, "Incorrect length");
+    for (document, key) in db_documents.iter().zip(&keys) {
+        assert_eq!(document.db_key.as_ref(), Some(key), "Incorrect db_key");
+    }
+
+    // Empty lists.
+    let api_documents: Vec<ReadonlyApiDocument> = to_api_vec(Vec::<ReadonlyDBDocument>::new());
+    assert!(api_documents.is_empty(), "The list must be empty");
+}
@@ -0,0 +1,25 @@
+use arangodb_types::types::NullableOption;
+
+use crate::tests::models::model::{SecretApiDocument, SecretDBDocument};
+
+#[test]
+fn deny_unknown_fields_only_in_api() {
+    let result = serde_json::from_str::<SecretApiDocument>(r#"{"name":"name","extra":5}"#);
+    assert!(result.is_err(), "The API model must reject unknown fields");
+
+    let document = serde_json::from_str::<SecretApiDocument>(r#"{"name":"name"}"#)
+        .expect("The API model must accept known fields");
+    assert_eq!(
+        document.name,
+        NullableOption::Value("name".to_string()),
+        "Incorrect name"
+    );
+
+    let document = serde_json::from_str::<SecretDBDocument>(r#"{"N":"name","_rev":"1","extra":5}"#)
+        .expect("The DB model must accept unknown fields");
+    assert_eq!(
+        document.name,
+        NullableOption::Value("name".to_string()),
+        "Incorrect name"
+    );
+}
@@ -0,0 +1,70 @@
+use arangodb_types::types::NullableOption;
+
+use crate::tests::models::model::FloatInfo;
+
+#[test]
+fn derive_partial_eq_with_float_fields() {
+    let info = FloatInfo {
+        value: NullableOption::Value(1.5),
+    };
+
+    assert_eq!(
+        info,
+        FloatInfo {
+            value: NullableOption::Value(1.5),
+        },
+        "Incorrect value"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn derive_eq_with_float_fields() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/derive_eq_with_float_fields.rs");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn reference_fields_as_inner_models() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/model_db_reference_as_struct.rs");
+    cases.compile_fail("tests/ui/model_vec_db_reference_as_enum.rs");
+    cases.compile_fail("tests/ui/type_model_db_reference_as_struct.rs");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn api_id_field_collision() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/model_api_id_collision.rs");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn api_name_collision() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/model_api_name_collision.rs");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn encrypted_non_string_field() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/model_encrypted_non_string.rs");
+}
@@ -0,0 +1,21 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::DBUuid;
+
+use crate::tests::models::model::RedactDBDocument;
+
+#[test]
+fn document_handle_matches_db_id() {
+    let document = RedactDBDocument::with_key(DBUuid::new());
+    let db_id = serde_json::to_value(document.db_id().unwrap()).unwrap();
+
+    assert_eq!(
+        document.document_handle().map(serde_json::Value::String),
+        Some(db_id),
+        "Incorrect document handle"
+    );
+    assert_eq!(
+        RedactDBDocument::default().document_handle(),
+        None,
+        "A document without key has no handle"
+    );
+}
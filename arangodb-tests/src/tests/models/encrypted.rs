@@ -0,0 +1,48 @@
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::models::model::{SecretApiDocument, SecretDBDocument};
+
+#[test]
+fn encrypted_round_trip() {
+    let key = DBUuid::new();
+    let api_document = SecretApiDocument {
+        id: Some(key.clone()),
+        name: NullableOption::Value("name".to_string()),
+        email: NullableOption::Value("user@example.com".to_string()),
+    };
+
+    let db_document = SecretDBDocument::from(api_document);
+    assert_eq!(
+        db_document.name,
+        NullableOption::Value("name".to_string()),
+        "The plain fields must not be encrypted"
+    );
+    assert_eq!(
+        db_document.email,
+        NullableOption::Value("enc:moc.elpmaxe@resu".to_string()),
+        "The DB model must store the ciphertext"
+    );
+
+    let api_document = SecretApiDocument::from(db_document);
+    assert_eq!(api_document.id, Some(key), "Incorrect id");
+    assert_eq!(
+        api_document.email,
+        NullableOption::Value("user@example.com".to_string()),
+        "The API model must see the plaintext"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn encrypted_null_is_kept() {
+    let api_document = SecretApiDocument {
+        email: NullableOption::Null,
+        ..Default::default()
+    };
+
+    let db_document = SecretDBDocument::from(api_document);
+    assert_eq!(db_document.email, NullableOption::Null, "Incorrect email");
+}
@@ -0,0 +1,40 @@
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::models::model::RedactDBDocument;
+
+#[test]
+fn eq_ignoring_metadata() {
+    let document = RedactDBDocument {
+        db_key: Some(DBUuid::new()),
+        db_rev: Some("rev1".into()),
+        name: NullableOption::Value("name".to_string()),
+        age: NullableOption::Value(20),
+        ..Default::default()
+    };
+    let other = RedactDBDocument {
+        db_key: Some(DBUuid::new()),
+        db_rev: Some("rev2".into()),
+        name: NullableOption::Value("name".to_string()),
+        age: NullableOption::Value(20),
+        ..Default::default()
+    };
+
+    assert!(
+        document
+            .eq_ignoring_metadata(&other)
+            .expect("The documents must be serializable"),
+        "The documents must be equal"
+    );
+
+    let other = RedactDBDocument {
+        age: NullableOption::Value(21),
+        ..other
+    };
+
+    assert!(
+        !document
+            .eq_ignoring_metadata(&other)
+            .expect("The documents must be serializable"),
+        "The documents must not be equal"
+    );
+}
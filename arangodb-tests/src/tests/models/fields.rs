@@ -0,0 +1,23 @@
+use arangodb_types::documents::DBDocumentField;
+
+use crate::tests::models::model::{RedactDBDocumentField, RedactInfoField};
+
+#[test]
+fn field_display() {
+    let field = RedactDBDocumentField::Name(None);
+    assert_eq!(format!("{}", field), field.path(), "Incorrect field");
+
+    let field = RedactDBDocumentField::Info(Some(RedactInfoField::Email(None)));
+    assert_eq!(format!("{}", field), field.path(), "Incorrect sub field");
+    assert_eq!(format!("{}", field), "I.E", "Incorrect sub field path");
+
+    let field = RedactInfoField::Phone(None);
+    assert_eq!(format!("{}", field), field.path(), "Incorrect type field");
+
+    let field = DBDocumentField::Key;
+    assert_eq!(
+        format!("{}", field),
+        field.path(),
+        "Incorrect document field"
+    );
+}
@@ -0,0 +1,42 @@
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::models::model::{RedactDBDocument, RedactDBDocumentField};
+
+#[test]
+fn filter_report_cleared_fields() {
+    let key = DBUuid::new();
+    let mut document = RedactDBDocument {
+        db_key: Some(key.clone()),
+        name: NullableOption::Value("John".to_string()),
+        age: NullableOption::Null,
+        ..Default::default()
+    };
+    let filter = RedactDBDocument {
+        db_key: Some(DBUuid::new()),
+        info: NullableOption::Null,
+        ..Default::default()
+    };
+
+    let cleared = document.filter_report(&filter);
+
+    assert_eq!(
+        cleared,
+        vec![
+            RedactDBDocumentField::Name(None),
+            RedactDBDocumentField::Age(None)
+        ],
+        "Incorrect cleared fields"
+    );
+    assert_eq!(
+        cleared.iter().map(|v| v.path()).collect::<Vec<_>>(),
+        vec!["N", "A"],
+        "Incorrect cleared paths"
+    );
+    assert_eq!(document.db_key, Some(key), "The key must be kept");
+    assert!(document.name.is_missing(), "The name must be cleared");
+    assert!(document.age.is_missing(), "The age must be cleared");
+    assert!(document.info.is_missing(), "The info must remain missing");
+
+    let cleared = document.filter_report(&filter);
+    assert!(cleared.is_empty(), "Nothing else must be cleared");
+}
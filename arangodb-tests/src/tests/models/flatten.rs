@@ -0,0 +1,69 @@
+use arangodb_types::traits::AQLMapping;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::models::model::{
+    FlattenDBDocument, FlattenDBDocumentField, RedactInfo, RedactInfoField,
+};
+
+fn build_document() -> FlattenDBDocument {
+    FlattenDBDocument {
+        db_rev: None,
+        db_key: Some(DBUuid::new()),
+        name: NullableOption::Value("name".to_string()),
+        info: RedactInfo {
+            email: NullableOption::Value("email".to_string()),
+            phone: NullableOption::Missing,
+        },
+    }
+}
+
+#[test]
+fn flatten_serialization() {
+    let document = build_document();
+    let json = serde_json::to_value(&document).unwrap();
+
+    assert_eq!(json["N"], "name", "Incorrect name");
+    assert_eq!(json["E"], "email", "Incorrect flattened email");
+    assert!(json.get("P").is_none(), "Incorrect flattened phone");
+    assert!(json.get("I").is_none(), "The field must be flattened");
+    assert!(json.get("info").is_none(), "The field must be flattened");
+
+    let deserialized: FlattenDBDocument = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized.db_key, document.db_key, "Incorrect key");
+    assert_eq!(
+        deserialized.info.email, document.info.email,
+        "Incorrect email"
+    );
+    assert!(deserialized.info.phone.is_missing(), "Incorrect phone");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn flatten_field_path() {
+    let field = FlattenDBDocumentField::Info(RedactInfoField::Email(None));
+    assert_eq!(field.path(), "E", "Incorrect flattened path");
+
+    let field = FlattenDBDocumentField::Name(None);
+    assert_eq!(field.path(), "N", "Incorrect path");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn flatten_map_to_json() {
+    let document = build_document();
+    let mut buffer = Vec::new();
+    let mut next_id = 0;
+    document.map_to_json(&mut buffer, "i", &mut next_id);
+
+    let json = String::from_utf8(buffer).unwrap();
+    assert!(json.contains("N:i.N,"), "Incorrect name projection");
+    assert!(json.contains("E:i.E,"), "Incorrect flattened projection");
+    assert!(!json.contains("I:"), "The field must be flattened");
+    assert!(json.ends_with(",}"), "Incorrect end of projection");
+}
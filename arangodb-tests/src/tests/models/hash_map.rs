@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use arangodb_types::aql::AqlBuilder;
+use arangodb_types::traits::AQLMapping;
+use arangodb_types::types::NullableOption;
+
+use crate::tests::models::model::{InfoMap, RedactInfo};
+
+#[test]
+fn hash_map_map_to_json() {
+    let mut infos = HashMap::new();
+    infos.insert(
+        "a".to_string(),
+        RedactInfo {
+            email: NullableOption::Value(String::new()),
+            phone: NullableOption::Missing,
+        },
+    );
+
+    let fields = InfoMap {
+        infos: NullableOption::Value(infos),
+    };
+
+    let mut buffer = Vec::new();
+    let mut next_id = 0;
+    fields.map_to_json(&mut buffer, "i", &mut next_id);
+
+    let json = String::from_utf8(buffer).unwrap();
+    assert_eq!(
+        json, r#"{I:{"a":{E:i.I["a"].E,},},}"#,
+        "Incorrect projection"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn hash_map_return_step_with_fields() {
+    let mut infos = HashMap::new();
+    infos.insert(
+        "a\"b".to_string(),
+        RedactInfo {
+            email: NullableOption::Missing,
+            phone: NullableOption::Null,
+        },
+    );
+
+    let fields = InfoMap {
+        infos: NullableOption::Value(infos),
+    };
+
+    let mut aql = AqlBuilder::new_for_in_collection("i", "Infos");
+    aql.return_step_with_fields("i", &fields);
+
+    assert_eq!(
+        aql.build_query(),
+        r#"FOR i IN Infos RETURN {I:{"a\"b":{P:i.I["a\"b"].P,},},}"#,
+        "Incorrect query"
+    );
+}
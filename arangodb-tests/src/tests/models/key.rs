@@ -0,0 +1,43 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::DBUuid;
+
+use crate::tests::models::model::RedactDBDocument;
+
+#[test]
+fn key_present() {
+    let key = DBUuid::new();
+    let document = RedactDBDocument {
+        db_key: Some(key.clone()),
+        ..Default::default()
+    };
+
+    assert_eq!(document.key(), key, "Incorrect key");
+    assert_eq!(
+        document.try_key().expect("The key must be present"),
+        key,
+        "Incorrect key"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn try_key_missing() {
+    let document = RedactDBDocument::default();
+
+    assert!(document.try_key().is_err(), "The key must be missing");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+#[should_panic(expected = "Missing db_key in document")]
+fn key_missing() {
+    let document = RedactDBDocument::default();
+
+    document.key();
+}
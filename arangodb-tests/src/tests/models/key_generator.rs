@@ -0,0 +1,66 @@
+use arangodb_types::traits::{DBCollection, DBDocument};
+use arangodb_types::types::{CollectionOptions, DBUuid, NullableOption};
+
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+use crate::tests::models::model::{TicketCollection, TicketDBDocument};
+
+fn is_base58(key: &DBUuid) -> bool {
+    key.as_string()
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l'))
+}
+
+#[test]
+fn key_generator_assign_key() {
+    let mut document = TicketDBDocument::default();
+    document.assign_key();
+
+    let key = document.db_key.clone().expect("The key must be assigned");
+    assert!(is_base58(&key), "Incorrect key charset: {}", key);
+
+    // Keep the existing key.
+    document.assign_key();
+    assert_eq!(document.db_key, Some(key), "The key must not change");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn key_generator_on_insert() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, _collection) = init_db_connection().await;
+
+    db_info
+        .ensure_collection(TicketCollection::name(), CollectionOptions::default())
+        .await
+        .expect("Cannot create collection");
+    let collection = TicketCollection::new(&db_info);
+
+    // Execute.
+    let document = TicketDBDocument {
+        title: NullableOption::Value("title".to_string()),
+        ..Default::default()
+    }
+    .insert(false, &collection)
+    .await
+    .expect("Cannot insert the document");
+
+    // Check.
+    let key = document.db_key.clone().expect("The key must be assigned");
+    assert!(is_base58(&key), "Incorrect key charset: {}", key);
+
+    let document = collection
+        .get_one_by_key(&key, None)
+        .await
+        .expect("There is an error trying to get the document")
+        .expect("The document does not exist in DB");
+
+    assert_eq!(
+        document.title,
+        NullableOption::Value("title".to_string()),
+        "Incorrect title"
+    );
+}
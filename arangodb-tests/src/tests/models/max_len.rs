@@ -0,0 +1,54 @@
+use arcstr::ArcStr;
+
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::NullableOption;
+
+use crate::tests::models::model::LimitDBDocument;
+
+#[test]
+fn max_len_accepts_at_limit() {
+    let document = LimitDBDocument {
+        name: NullableOption::Value("ñandú".to_string()),
+        code: Some(ArcStr::from("abcd")),
+        ..Default::default()
+    };
+
+    document.validate().expect("The document must be valid");
+
+    let document = LimitDBDocument::default();
+    document
+        .validate()
+        .expect("Missing fields must not be validated");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn max_len_rejects_over_limit() {
+    let document = LimitDBDocument {
+        name: NullableOption::Value("abcdef".to_string()),
+        ..Default::default()
+    };
+
+    let error = document.validate().expect_err("The name must be rejected");
+    assert_eq!(
+        error.to_string(),
+        "The field 'name' exceeds the maximum length of 5 characters",
+        "Incorrect error"
+    );
+
+    // Counts bytes instead of characters.
+    let document = LimitDBDocument {
+        code: Some(ArcStr::from("ñañ")),
+        ..Default::default()
+    };
+
+    let error = document.validate().expect_err("The code must be rejected");
+    assert_eq!(
+        error.to_string(),
+        "The field 'code' exceeds the maximum length of 4 bytes",
+        "Incorrect error"
+    );
+}
@@ -0,0 +1,30 @@
+pub mod apply_patch;
+pub mod as_filter_aql;
+pub mod btree_map;
+pub mod collection_kind;
+pub mod compound_key;
+pub mod convert;
+pub mod deny_unknown_fields;
+pub mod derive;
+pub mod document_handle;
+pub mod encrypted;
+pub mod eq;
+pub mod fields;
+pub mod filter_report;
+pub mod flatten;
+pub mod hash_map;
+pub mod key;
+pub mod key_generator;
+pub mod max_len;
+pub mod model;
+pub mod readonly;
+pub mod redact;
+pub mod reference;
+pub mod schema_version;
+pub mod send_sync;
+pub mod serde_with;
+pub mod timestamps;
+pub mod ttl;
+pub mod validate;
+pub mod vec_box;
+pub mod with_key;
@@ -0,0 +1,915 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use arcstr::ArcStr;
+
+use arangodb_types::models::{model, type_model};
+
+use arangodb_types::traits::{Cipher, DBCollection, DBSchemaMigration};
+use arangodb_types::types::DBDateTime;
+use arangodb_types::types::DBExpiration;
+use arangodb_types::types::DBInfo;
+use arangodb_types::types::DBReference;
+use arangodb_types::types::DBUuid;
+use arangodb_types::types::NullableOption;
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+
+#[derive(Debug)]
+pub struct RedactCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for RedactCollection {
+    type Document = RedactDBDocument;
+
+    fn name() -> &'static str {
+        "Redacts"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct FlattenCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for FlattenCollection {
+    type Document = FlattenDBDocument;
+
+    fn name() -> &'static str {
+        "Flattens"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct ReadonlyCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for ReadonlyCollection {
+    type Document = ReadonlyDBDocument;
+
+    fn name() -> &'static str {
+        "Readonlies"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct RankingCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for RankingCollection {
+    type Document = RankingDBDocument;
+
+    fn name() -> &'static str {
+        "Rankings"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct SecretCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for SecretCollection {
+    type Document = SecretDBDocument;
+
+    fn name() -> &'static str {
+        "Secrets"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct PageCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for PageCollection {
+    type Document = PageDBDocument;
+
+    fn name() -> &'static str {
+        "Pages"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct ProfileCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for ProfileCollection {
+    type Document = ProfileDBDocument;
+
+    fn name() -> &'static str {
+        "Profiles"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct CounterCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for CounterCollection {
+    type Document = CounterDBDocument;
+
+    fn name() -> &'static str {
+        "Counters"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct LinkCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for LinkCollection {
+    type Document = LinkDBDocument;
+
+    fn name() -> &'static str {
+        "Links"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct ArticleCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for ArticleCollection {
+    type Document = ArticleDBDocument;
+
+    fn name() -> &'static str {
+        "Articles"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct ExclusiveCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl ExclusiveCollection {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(db_info: &Arc<DBInfo>) -> Self {
+        ExclusiveCollection {
+            db_info: db_info.clone(),
+        }
+    }
+}
+
+impl DBCollection for ExclusiveCollection {
+    type Document = ExclusiveDBDocument;
+
+    fn name() -> &'static str {
+        "Exclusives"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct AuditCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl AuditCollection {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(db_info: &Arc<DBInfo>) -> Self {
+        AuditCollection {
+            db_info: db_info.clone(),
+        }
+    }
+}
+
+impl DBCollection for AuditCollection {
+    type Document = AuditDBDocument;
+
+    fn name() -> &'static str {
+        "Audits"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct TicketCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl TicketCollection {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(db_info: &Arc<DBInfo>) -> Self {
+        TicketCollection {
+            db_info: db_info.clone(),
+        }
+    }
+}
+
+impl DBCollection for TicketCollection {
+    type Document = TicketDBDocument;
+
+    fn name() -> &'static str {
+        "Tickets"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct SessionCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for SessionCollection {
+    type Document = SessionDBDocument;
+
+    fn name() -> &'static str {
+        "Sessions"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct LimitCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl DBCollection for LimitCollection {
+    type Document = LimitDBDocument;
+
+    fn name() -> &'static str {
+        "Limits"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct NoteCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl NoteCollection {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(db_info: &Arc<DBInfo>) -> Self {
+        NoteCollection {
+            db_info: db_info.clone(),
+        }
+    }
+}
+
+impl DBCollection for NoteCollection {
+    type Document = NoteDBDocument;
+
+    fn name() -> &'static str {
+        "Notes"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct TagCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl TagCollection {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(db_info: &Arc<DBInfo>) -> Self {
+        TagCollection {
+            db_info: db_info.clone(),
+        }
+    }
+}
+
+impl DBCollection for TagCollection {
+    type Document = TagDBDocument;
+
+    fn name() -> &'static str {
+        "Tags"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+#[derive(Debug)]
+pub struct ValidateCollection {
+    db_info: Arc<DBInfo>,
+}
+
+impl ValidateCollection {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(db_info: &Arc<DBInfo>) -> Self {
+        ValidateCollection {
+            db_info: db_info.clone(),
+        }
+    }
+}
+
+impl DBCollection for ValidateCollection {
+    type Document = ValidateDBDocument;
+
+    fn name() -> &'static str {
+        "Validates"
+    }
+
+    fn db_info(&self) -> &Arc<DBInfo> {
+        &self.db_info
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TestCollectionKind {
+    Redacts,
+    Flattens,
+    Validates,
+    Readonlies,
+    Rankings,
+    Secrets,
+    Pages,
+    Profiles,
+    Singletons,
+    Counters,
+    Links,
+    Articles,
+    Exclusives,
+    Tickets,
+    Sessions,
+    Limits,
+    Notes,
+    Tags,
+    Audits,
+}
+
+impl std::fmt::Display for TestCollectionKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestCollectionKind::Redacts => write!(f, "Redacts"),
+            TestCollectionKind::Flattens => write!(f, "Flattens"),
+            TestCollectionKind::Validates => write!(f, "Validates"),
+            TestCollectionKind::Readonlies => write!(f, "Readonlies"),
+            TestCollectionKind::Rankings => write!(f, "Rankings"),
+            TestCollectionKind::Secrets => write!(f, "Secrets"),
+            TestCollectionKind::Pages => write!(f, "Pages"),
+            TestCollectionKind::Profiles => write!(f, "Profiles"),
+            TestCollectionKind::Singletons => write!(f, "Singletons"),
+            TestCollectionKind::Counters => write!(f, "Counters"),
+            TestCollectionKind::Links => write!(f, "Links"),
+            TestCollectionKind::Articles => write!(f, "Articles"),
+            TestCollectionKind::Exclusives => write!(f, "Exclusives"),
+            TestCollectionKind::Tickets => write!(f, "Tickets"),
+            TestCollectionKind::Sessions => write!(f, "Sessions"),
+            TestCollectionKind::Limits => write!(f, "Limits"),
+            TestCollectionKind::Notes => write!(f, "Notes"),
+            TestCollectionKind::Tags => write!(f, "Tags"),
+            TestCollectionKind::Audits => write!(f, "Audits"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Redacts"]
+
+    pub struct Redact {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "N"]
+        pub name: NullableOption<String>,
+
+        #[db_name = "A"]
+        pub age: NullableOption<u64>,
+
+        #[db_name = "I"]
+        #[inner_model = "struct"]
+        pub info: NullableOption<RedactInfo>,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Flattens"]
+
+    pub struct Flatten {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "N"]
+        pub name: NullableOption<String>,
+
+        #[inner_model = "struct"]
+        #[flatten]
+        pub info: RedactInfo,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Validates"]
+    #![validate_with = "validate_age"]
+    #![validate_on_write]
+
+    pub struct Validate {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "A"]
+        pub age: NullableOption<u64>,
+    }
+);
+
+model!(
+    #![build_api]
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Readonlies"]
+
+    pub struct Readonly {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "N"]
+        pub name: NullableOption<String>,
+
+        #[db_name = "C"]
+        #[api_readonly]
+        pub created_at: NullableOption<DBDateTime>,
+    }
+);
+
+model!(
+    #![build_api]
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Rankings"]
+
+    pub struct Ranking {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "S"]
+        pub scores: NullableOption<BTreeMap<String, u64>>,
+    }
+);
+
+model!(
+    #![build_api]
+    #![api_deny_unknown_fields]
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Secrets"]
+
+    pub struct Secret {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "N"]
+        pub name: NullableOption<String>,
+
+        #[db_name = "E"]
+        #[encrypted = "ReverseCipher"]
+        pub email: NullableOption<String>,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Pages"]
+    #![key_from(["tenant", "slug"])]
+
+    pub struct Page {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "T"]
+        pub tenant: NullableOption<String>,
+
+        #[db_name = "S"]
+        pub slug: NullableOption<String>,
+    }
+);
+
+model!(
+    #![build_api]
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Profiles"]
+
+    pub struct Profile {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "N"]
+        pub name: NullableOption<String>,
+
+        #[db_name = "A"]
+        pub age: NullableOption<u64>,
+
+        #[db_name = "C"]
+        #[inner_model = "struct"]
+        #[inner_type_api = "ApiProfileContact"]
+        pub contact: NullableOption<ProfileContact>,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Singletons"]
+    #![singleton_collection]
+
+    pub struct Singleton {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "V"]
+        pub value: NullableOption<u64>,
+    }
+);
+
+model!(
+    #![build_api]
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Counters"]
+
+    pub struct Counter {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "V"]
+        #[serde_with = "u64_as_string"]
+        pub value: NullableOption<u64>,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Links"]
+    #![generate_loaders]
+
+    pub struct Link {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "T"]
+        pub target: NullableOption<DBReference<MutexDBDocument>>,
+
+        #[db_name = "L"]
+        pub targets: NullableOption<Vec<DBReference<MutexDBDocument>>>,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Articles"]
+    #![schema_version(3)]
+
+    pub struct Article {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "T"]
+        pub title: NullableOption<String>,
+
+        #[db_name = "S"]
+        pub slug: NullableOption<String>,
+    }
+);
+
+impl DBSchemaMigration for ArticleDBDocument {
+    fn migrate_from(&mut self, version: u32) -> Result<(), anyhow::Error> {
+        match version {
+            // v2 trims the titles.
+            1 => {
+                if let NullableOption::Value(title) = &mut self.title {
+                    *title = title.trim().to_string();
+                }
+            }
+            // v3 adds the slug.
+            2 => {
+                if let NullableOption::Value(title) = &self.title {
+                    self.slug = NullableOption::Value(title.to_lowercase().replace(' ', "-"));
+                }
+            }
+            _ => unreachable!("Unknown schema version {}", version),
+        }
+
+        Ok(())
+    }
+}
+
+model!(
+    #![sync_level = "document"]
+    #![sync_exclusive]
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Exclusives"]
+
+    pub struct Exclusive {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "V"]
+        pub value: NullableOption<u64>,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Tickets"]
+    #![key_generator = "base58"]
+
+    pub struct Ticket {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "T"]
+        pub title: NullableOption<String>,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Sessions"]
+
+    pub struct Session {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "E"]
+        #[ttl]
+        pub expires_at: NullableOption<DBExpiration>,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Limits"]
+
+    pub struct Limit {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "N"]
+        #[max_len(5)]
+        pub name: NullableOption<String>,
+
+        #[db_name = "C"]
+        #[max_len(4, bytes)]
+        pub code: Option<ArcStr>,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Notes"]
+    #![timestamps(created = "created_at", updated = "updated_at")]
+
+    pub struct Note {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "T"]
+        pub text: NullableOption<String>,
+
+        #[db_name = "createdAt"]
+        pub created_at: NullableOption<DBDateTime>,
+
+        #[db_name = "updatedAt"]
+        pub updated_at: NullableOption<DBDateTime>,
+    }
+);
+
+model!(
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Tags"]
+    #![normalize_with = "normalize_tag"]
+
+    pub struct Tag {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "N"]
+        pub name: NullableOption<String>,
+    }
+);
+
+model!(
+    #![sync_level = "document"]
+    #![collection_type = "TestCollectionKind"]
+    #![collection_kind = "Audits"]
+    #![timestamps(updated = "updated_at")]
+    #![validate_with = "validate_audit"]
+    #![validate_on_write]
+
+    pub struct Audit {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "V"]
+        pub value: NullableOption<u64>,
+
+        #[db_name = "updatedAt"]
+        pub updated_at: NullableOption<DBDateTime>,
+    }
+);
+
+/// A stub cipher that reverses the text.
+pub struct ReverseCipher;
+
+impl Cipher for ReverseCipher {
+    fn encrypt(plaintext: String) -> String {
+        format!("enc:{}", plaintext.chars().rev().collect::<String>())
+    }
+
+    fn decrypt(ciphertext: String) -> String {
+        ciphertext
+            .trim_start_matches("enc:")
+            .chars()
+            .rev()
+            .collect()
+    }
+}
+
+/// A serde module that encodes the numbers as strings.
+pub mod u64_as_string {
+    use arangodb_types::types::NullableOption;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &NullableOption<u64>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            NullableOption::Value(v) => serializer.serialize_str(v.to_string().as_str()),
+            _ => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<NullableOption<u64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(v) => v
+                .parse()
+                .map(NullableOption::Value)
+                .map_err(serde::de::Error::custom),
+            None => Ok(NullableOption::Null),
+        }
+    }
+}
+
+fn validate_age(document: &ValidateDBDocument) -> Result<(), anyhow::Error> {
+    match document.age {
+        NullableOption::Value(age) if age > 150 => Err(anyhow::anyhow!("Invalid age: {}", age)),
+        _ => Ok(()),
+    }
+}
+
+fn validate_audit(document: &AuditDBDocument) -> Result<(), anyhow::Error> {
+    match document.value {
+        NullableOption::Value(value) if value > 100 => {
+            Err(anyhow::anyhow!("Invalid value: {}", value))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn normalize_tag(document: &mut TagDBDocument) -> bool {
+    match &mut document.name {
+        NullableOption::Value(name) => {
+            let normalized = name.trim().to_lowercase();
+            if *name == normalized {
+                return false;
+            }
+
+            *name = normalized;
+            true
+        }
+        _ => false,
+    }
+}
+
+type_model!(
+    pub struct RedactInfo {
+        #[db_name = "E"]
+        pub email: NullableOption<String>,
+
+        #[db_name = "P"]
+        pub phone: NullableOption<String>,
+    }
+);
+
+type_model!(
+    #[db_attr(derive(PartialEq))]
+    pub struct FloatInfo {
+        #[db_name = "V"]
+        pub value: NullableOption<f64>,
+    }
+);
+
+type_model!(
+    pub struct InfoMap {
+        #[db_name = "I"]
+        #[inner_model = "struct"]
+        pub infos: NullableOption<HashMap<String, RedactInfo>>,
+    }
+);
+
+type_model!(
+    pub struct BoxedInfoList {
+        #[db_name = "I"]
+        #[inner_model = "struct"]
+        pub infos: NullableOption<Vec<Box<RedactInfo>>>,
+    }
+);
+
+type_model!(
+    pub struct TagList {
+        #[db_name = "N"]
+        pub name: NullableOption<String>,
+
+        #[db_name = "T"]
+        pub tags: NullableOption<Vec<String>>,
+    }
+);
+
+type_model!(
+    #![build_api]
+
+    pub struct ProfileContact {
+        #[db_name = "E"]
+        pub email: NullableOption<String>,
+
+        #[db_name = "P"]
+        pub phone: NullableOption<String>,
+    }
+);
@@ -0,0 +1,47 @@
+use arangodb_types::types::{DBDateTime, DBUuid, NullableOption};
+
+use crate::tests::models::model::{ReadonlyApiDocument, ReadonlyDBDocument};
+
+#[test]
+fn readonly_not_carried_to_db() {
+    let key = DBUuid::new();
+    let api_document = ReadonlyApiDocument {
+        id: Some(key.clone()),
+        name: NullableOption::Value("name".to_string()),
+        created_at: NullableOption::Value(DBDateTime::now()),
+    };
+
+    let db_document = ReadonlyDBDocument::from(api_document);
+
+    assert_eq!(db_document.db_key, Some(key), "Incorrect db_key");
+    assert_eq!(
+        db_document.name,
+        NullableOption::Value("name".to_string()),
+        "Incorrect name"
+    );
+    assert!(
+        db_document.created_at.is_missing(),
+        "The read-only field must not be carried"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn readonly_carried_to_api() {
+    let created_at = DBDateTime::now();
+    let db_document = ReadonlyDBDocument {
+        created_at: NullableOption::Value(created_at.clone()),
+        ..Default::default()
+    };
+
+    let api_document = ReadonlyApiDocument::from(db_document);
+
+    assert_eq!(
+        api_document.created_at,
+        NullableOption::Value(created_at),
+        "Incorrect created_at"
+    );
+}
@@ -0,0 +1,61 @@
+use arangodb_types::types::NullableOption;
+
+use crate::tests::models::model::{
+    RedactDBDocument, RedactDBDocumentField, RedactInfo, RedactInfoField,
+};
+
+#[test]
+fn redact_except() {
+    let mut document = RedactDBDocument {
+        name: NullableOption::Value("name".to_string()),
+        age: NullableOption::Value(20),
+        info: NullableOption::Value(RedactInfo {
+            email: NullableOption::Value("email".to_string()),
+            phone: NullableOption::Value("phone".to_string()),
+        }),
+        ..Default::default()
+    };
+
+    document.redact_except(&[
+        RedactDBDocumentField::Name(None),
+        RedactDBDocumentField::Info(Some(RedactInfoField::Email(None))),
+    ]);
+
+    assert_eq!(
+        document.name,
+        NullableOption::Value("name".to_string()),
+        "Incorrect name"
+    );
+    assert_eq!(document.age, NullableOption::Null, "Incorrect age");
+
+    let info = document.info.unwrap_as_ref();
+    assert_eq!(
+        info.email,
+        NullableOption::Value("email".to_string()),
+        "Incorrect email"
+    );
+    assert_eq!(info.phone, NullableOption::Null, "Incorrect phone");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn redact_except_nothing() {
+    let mut document = RedactDBDocument {
+        name: NullableOption::Value("name".to_string()),
+        age: NullableOption::Value(20),
+        info: NullableOption::Value(RedactInfo {
+            email: NullableOption::Value("email".to_string()),
+            phone: NullableOption::Value("phone".to_string()),
+        }),
+        ..Default::default()
+    };
+
+    document.redact_except(&[]);
+
+    assert_eq!(document.name, NullableOption::Null, "Incorrect name");
+    assert_eq!(document.age, NullableOption::Null, "Incorrect age");
+    assert!(document.info.is_null(), "Incorrect info");
+}
@@ -0,0 +1,175 @@
+use arangodb_types::traits::DBCollection;
+use arangodb_types::types::{
+    APIReference, DBReference, DBReferenceKey, DBUuid, FromField, KeyField, NullableOption,
+};
+
+use crate::tests::db_mutex::model::{MutexCollection, MutexDBDocument};
+use crate::tests::models::model::ReadonlyApiDocument;
+
+#[test]
+fn reference_partial_document() {
+    let key = DBUuid::new();
+    let json = format!("{{\"_key\":\"{}\",\"V\":5}}", key);
+    let reference: DBReference<MutexDBDocument> =
+        serde_json::from_str(&json).expect("The reference must be deserialized");
+
+    assert!(reference.is_document(), "Incorrect variant");
+    assert_eq!(reference.key(), key, "Incorrect key");
+
+    let document = reference
+        .as_partial()
+        .expect("The document must be present");
+    assert_eq!(document.value, NullableOption::Value(5), "Incorrect value");
+    assert!(document.db_mutex.is_missing(), "Incorrect mutex");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn reference_only_key() {
+    let key = DBUuid::new();
+    let json = format!("{{\"_key\":\"{}\"}}", key);
+    let reference: DBReference<MutexDBDocument> =
+        serde_json::from_str(&json).expect("The reference must be deserialized");
+
+    assert_eq!(reference.key(), key, "Incorrect key");
+
+    let document = reference
+        .as_partial()
+        .expect("The document must be present");
+    assert!(document.value.is_missing(), "Incorrect value");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn reference_key() {
+    let key = DBUuid::new();
+    let reference = DBReference::<MutexDBDocument>::new_key(key.clone());
+
+    assert!(reference.as_partial().is_none(), "Incorrect document");
+    assert_eq!(reference.key(), key, "Incorrect key");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn reference_key_custom_field() {
+    let key = DBUuid::new();
+    let reference = DBReferenceKey::<DBUuid, FromField>::new(key.clone());
+
+    let json = serde_json::to_string(&reference).unwrap();
+    assert_eq!(json, format!("{{\"_from\":\"{}\"}}", key), "Incorrect json");
+
+    let reference: DBReferenceKey<DBUuid, FromField> =
+        serde_json::from_str(&json).expect("The reference must be deserialized");
+    assert_eq!(reference.key(), &key, "Incorrect key");
+
+    let result = serde_json::from_str::<DBReferenceKey<DBUuid, KeyField>>(&json);
+    assert!(result.is_err(), "The '_key' field must be required");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn reference_document_to_key() {
+    let key = DBUuid::new();
+    let reference = DBReference::Document(Box::new(MutexDBDocument {
+        db_key: Some(key.clone()),
+        value: NullableOption::Value(5),
+        ..Default::default()
+    }));
+    let expected_json =
+        serde_json::to_string(&DBReference::<MutexDBDocument>::new_key(key.clone())).unwrap();
+
+    let copy = reference.to_key();
+    assert!(copy.is_key(), "Incorrect variant");
+    assert!(reference.is_document(), "The original must be kept");
+
+    let reference = reference.into_key();
+    assert!(reference.is_key(), "Incorrect variant");
+    assert_eq!(reference.key(), key, "Incorrect key");
+    assert_eq!(
+        serde_json::to_string(&reference).unwrap(),
+        expected_json,
+        "Incorrect json"
+    );
+    assert_eq!(
+        serde_json::to_string(&copy).unwrap(),
+        expected_json,
+        "Incorrect json"
+    );
+
+    // Keys are kept unchanged.
+    let reference = reference.into_key();
+    assert_eq!(reference.key(), key, "Incorrect key");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn api_reference_document_to_key() {
+    let key = DBUuid::new();
+    let reference = APIReference::Document(Box::new(ReadonlyApiDocument {
+        id: Some(key.clone()),
+        name: NullableOption::Value("name".to_string()),
+        ..Default::default()
+    }));
+    let expected_json =
+        serde_json::to_string(&APIReference::<ReadonlyApiDocument>::new_key(key.clone())).unwrap();
+
+    let copy = reference.to_key();
+    assert!(copy.is_key(), "Incorrect variant");
+    assert!(reference.is_document(), "The original must be kept");
+
+    let reference = reference.into_key();
+    assert!(reference.is_key(), "Incorrect variant");
+    assert_eq!(reference.key(), key, "Incorrect key");
+    assert_eq!(
+        serde_json::to_string(&reference).unwrap(),
+        expected_json,
+        "Incorrect json"
+    );
+    assert_eq!(
+        serde_json::to_string(&copy).unwrap(),
+        expected_json,
+        "Incorrect json"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn reference_from_handle() {
+    let key = DBUuid::new();
+    let reference = DBReference::<MutexDBDocument>::from_handle(&format!(
+        "{}/{}",
+        MutexCollection::name(),
+        key
+    ))
+    .expect("The handle must be parsed");
+
+    assert!(reference.is_key(), "Incorrect variant");
+    assert_eq!(reference.key(), key, "Incorrect key");
+
+    let result = DBReference::<MutexDBDocument>::from_handle(&format!("Other/{}", key));
+    assert!(result.is_err(), "The collection must be rejected");
+
+    let result = DBReference::<MutexDBDocument>::from_handle(&key.to_string());
+    assert!(
+        result.is_err(),
+        "The handle without collection must be rejected"
+    );
+}
@@ -0,0 +1,40 @@
+use arangodb_types::types::NullableOption;
+
+use crate::tests::models::model::ArticleDBDocument;
+
+#[test]
+fn test_schema_version_default() {
+    let document = ArticleDBDocument::default();
+
+    assert_eq!(ArticleDBDocument::SCHEMA_VERSION, 3);
+    assert_eq!(document.db_schema_version.version(), 3);
+
+    let json = serde_json::to_value(&document).unwrap();
+    assert_eq!(json["_v"], 3);
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_schema_version_migrate() {
+    let mut document: ArticleDBDocument =
+        serde_json::from_str(r#"{"_key":"a","_v":2,"T":"Hello World"}"#).unwrap();
+
+    assert_eq!(document.db_schema_version.version(), 2);
+    assert!(document.slug.is_missing());
+
+    let migrated = document.migrate().expect("The migration must succeed");
+
+    assert!(migrated, "The document must be migrated");
+    assert_eq!(document.db_schema_version.version(), 3);
+    assert_eq!(
+        document.slug,
+        NullableOption::Value("hello-world".to_string())
+    );
+
+    let migrated = document.migrate().expect("The migration must succeed");
+
+    assert!(!migrated, "The document is already up to date");
+}
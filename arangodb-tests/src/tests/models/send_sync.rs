@@ -0,0 +1,22 @@
+use arangodb_types::types::{APIReference, DBReference};
+use arangodb_types::utilities::DBMutexGuard;
+
+use crate::tests::db_mutex::model::MutexDBDocument;
+use crate::tests::models::model::ProfileApiDocument;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn mutex_guard_is_send_sync() {
+    assert_send_sync::<DBMutexGuard<MutexDBDocument>>();
+}
+
+#[test]
+fn db_reference_is_send_sync() {
+    assert_send_sync::<DBReference<MutexDBDocument>>();
+}
+
+#[test]
+fn api_reference_is_send_sync() {
+    assert_send_sync::<APIReference<ProfileApiDocument>>();
+}
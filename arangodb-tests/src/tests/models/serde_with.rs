@@ -0,0 +1,44 @@
+use arangodb_types::types::NullableOption;
+
+use crate::tests::models::model::{CounterApiDocument, CounterDBDocument};
+
+#[test]
+fn serde_with_db_document() {
+    let document = CounterDBDocument {
+        value: NullableOption::Value(42),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_value(&document).unwrap();
+    assert_eq!(json, serde_json::json!({ "V": "42" }), "Incorrect encoding");
+
+    let decoded: CounterDBDocument = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded.value, document.value, "Incorrect decoding");
+
+    let decoded: CounterDBDocument = serde_json::from_str(r#"{ "V": null }"#).unwrap();
+    assert_eq!(decoded.value, NullableOption::Null, "Incorrect null");
+
+    let decoded: CounterDBDocument = serde_json::from_str("{}").unwrap();
+    assert_eq!(decoded.value, NullableOption::Missing, "Incorrect missing");
+
+    let json = serde_json::to_value(&decoded).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({}),
+        "The missing value must be skipped"
+    );
+}
+
+#[test]
+fn serde_with_api_document() {
+    let document = CounterApiDocument::from(CounterDBDocument {
+        value: NullableOption::Value(42),
+        ..Default::default()
+    });
+
+    let json = serde_json::to_value(&document).unwrap();
+    assert_eq!(json["value"], serde_json::json!("42"), "Incorrect encoding");
+
+    let decoded: CounterApiDocument = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded.value, document.value, "Incorrect decoding");
+}
@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use tokio::time::sleep;
+
+use arangodb_types::traits::{DBCollection, DBDocument};
+use arangodb_types::types::{CollectionOptions, DBDateTime, DBUuid, NullableOption};
+
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+use crate::tests::models::model::{NoteCollection, NoteDBDocument};
+
+#[test]
+fn touch_timestamps() {
+    let mut document = NoteDBDocument::default();
+    document.touch_timestamps(true);
+
+    assert!(document.created_at.is_value(), "Incorrect created_at");
+    assert_eq!(
+        document.created_at, document.updated_at,
+        "Both timestamps must be equal on insert"
+    );
+
+    // Keep the creation timestamp.
+    let created_at = DBDateTime::now().after_seconds_checked(-10).unwrap();
+    document.created_at = NullableOption::Value(created_at.clone());
+    document.touch_timestamps(true);
+    assert_eq!(
+        document.created_at,
+        NullableOption::Value(created_at),
+        "The created_at must not change"
+    );
+
+    // Updates only touch updated_at.
+    let mut document = NoteDBDocument::default();
+    document.touch_timestamps(false);
+    assert!(document.created_at.is_missing(), "Incorrect created_at");
+    assert!(document.updated_at.is_value(), "Incorrect updated_at");
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn timestamps_on_update() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, _collection) = init_db_connection().await;
+
+    db_info
+        .ensure_collection(NoteCollection::name(), CollectionOptions::default())
+        .await
+        .expect("Cannot create collection");
+    let collection = NoteCollection::new(&db_info);
+
+    // Preconditions.
+    let document_key = DBUuid::new();
+    let inserted = NoteDBDocument {
+        db_key: Some(document_key.clone()),
+        text: NullableOption::Value("a".to_string()),
+        ..Default::default()
+    }
+    .insert(false, &collection)
+    .await
+    .expect("Cannot add preconditions to DB");
+
+    sleep(Duration::from_millis(10)).await;
+
+    // Execute.
+    let updated = NoteDBDocument {
+        db_key: Some(document_key),
+        text: NullableOption::Value("b".to_string()),
+        ..Default::default()
+    }
+    .update(false, &collection)
+    .await
+    .expect("Cannot update the document");
+
+    assert_eq!(
+        updated.created_at, inserted.created_at,
+        "Incorrect created_at"
+    );
+    assert_ne!(
+        updated.updated_at, inserted.updated_at,
+        "Incorrect updated_at"
+    );
+    assert!(
+        updated.updated_at.unwrap_as_ref().0 > inserted.updated_at.unwrap_as_ref().0,
+        "The updated_at must increase"
+    );
+}
@@ -0,0 +1,43 @@
+use arangodb_types::types::{DBExpiration, NullableOption};
+
+use crate::tests::models::model::SessionDBDocument;
+
+#[test]
+fn ttl_is_expired() {
+    let expired = SessionDBDocument {
+        expires_at: NullableOption::Value(DBExpiration::now_plus(0).before_seconds(10)),
+        ..Default::default()
+    };
+    let alive = SessionDBDocument {
+        expires_at: NullableOption::Value(DBExpiration::now_plus(100)),
+        ..Default::default()
+    };
+    let without_expiration = SessionDBDocument {
+        expires_at: NullableOption::Null,
+        ..Default::default()
+    };
+
+    assert!(expired.is_expired(), "The document must be expired");
+    assert!(!alive.is_expired(), "The document must not be expired");
+    assert!(
+        !without_expiration.is_expired(),
+        "A document without expiration must not be expired"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn ttl_index() {
+    assert_eq!(
+        SessionDBDocument::ttl_index(),
+        serde_json::json!({
+            "type": "ttl",
+            "fields": ["E"],
+            "expireAfter": 0,
+        }),
+        "Incorrect index"
+    );
+}
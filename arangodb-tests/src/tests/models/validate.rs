@@ -0,0 +1,56 @@
+use arangodb_types::traits::DBDocument;
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::db_mutex::TEST_RWLOCK;
+use crate::tests::init_db_connection;
+use crate::tests::models::model::{ValidateCollection, ValidateDBDocument};
+
+#[test]
+fn validate_ok() {
+    let document = ValidateDBDocument {
+        age: NullableOption::Value(20),
+        ..Default::default()
+    };
+
+    assert!(document.validate().is_ok(), "The document must be valid");
+    assert!(
+        ValidateDBDocument::validate_on_write(),
+        "Incorrect validate_on_write"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn validate_rejects_insert() {
+    let _test_lock = TEST_RWLOCK.read().await;
+    let (db_info, _collection) = init_db_connection().await;
+
+    // The collection is never created so any error other than the validation one means the
+    // document reached the DB.
+    let collection = ValidateCollection::new(&db_info);
+    let document = ValidateDBDocument {
+        db_key: Some(DBUuid::new()),
+        age: NullableOption::Value(200),
+        ..Default::default()
+    };
+
+    // Execute.
+    let error = document
+        .clone()
+        .insert(false, &collection)
+        .await
+        .expect_err("The insert must be rejected");
+
+    // Check.
+    assert_eq!(error.to_string(), "Invalid age: 200", "Incorrect error");
+
+    let error = document
+        .update(false, &collection)
+        .await
+        .expect_err("The update must be rejected");
+
+    assert_eq!(error.to_string(), "Invalid age: 200", "Incorrect error");
+}
@@ -0,0 +1,45 @@
+use arangodb_types::types::NullableOption;
+
+use crate::tests::models::model::{BoxedInfoList, RedactInfo};
+
+#[test]
+fn vec_box_serialization() {
+    let document = BoxedInfoList {
+        infos: NullableOption::Value(vec![Box::new(RedactInfo {
+            email: NullableOption::Value("email".to_string()),
+            phone: NullableOption::Missing,
+        })]),
+    };
+
+    let serialization = serde_json::to_string(&document).unwrap();
+    assert_eq!(serialization, r#"{"I":[{"E":"email"}]}"#);
+
+    let deserialization: BoxedInfoList = serde_json::from_str(&serialization).unwrap();
+    let infos = deserialization.infos.unwrap_as_ref();
+    assert_eq!(infos.len(), 1, "Incorrect info count");
+    assert_eq!(
+        infos[0].email,
+        NullableOption::Value("email".to_string()),
+        "Incorrect email"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[test]
+fn vec_box_map_values_to_null() {
+    let mut document = BoxedInfoList {
+        infos: NullableOption::Value(vec![Box::new(RedactInfo {
+            email: NullableOption::Value("email".to_string()),
+            phone: NullableOption::Missing,
+        })]),
+    };
+
+    document.map_values_to_null();
+
+    let infos = document.infos.unwrap_as_ref();
+    assert_eq!(infos[0].email, NullableOption::Null, "Incorrect email");
+    assert_eq!(infos[0].phone, NullableOption::Missing, "Incorrect phone");
+}
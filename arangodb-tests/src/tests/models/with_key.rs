@@ -0,0 +1,15 @@
+use arangodb_types::types::{DBUuid, NullableOption};
+
+use crate::tests::models::model::RedactDBDocument;
+
+#[test]
+fn with_key_defaults() {
+    let key = DBUuid::new();
+    let document = RedactDBDocument::with_key(key.clone());
+
+    assert_eq!(document.db_key, Some(key), "Incorrect key");
+    assert_eq!(document.db_rev, None, "Incorrect rev");
+    assert_eq!(document.name, NullableOption::Missing, "Incorrect name");
+    assert_eq!(document.age, NullableOption::Missing, "Incorrect age");
+    assert!(document.info.is_missing(), "Incorrect info");
+}
@@ -0,0 +1,13 @@
+use arangodb_types::models::type_model;
+#[allow(unused_imports)]
+use arangodb_types::types::NullableOption;
+
+type_model!(
+    #[db_attr(derive(PartialEq, Eq))]
+    pub struct FloatInfo {
+        #[db_name = "V"]
+        pub value: NullableOption<f64>,
+    }
+);
+
+fn main() {}
@@ -0,0 +1,17 @@
+use arangodb_types::models::model;
+#[allow(unused_imports)]
+use arangodb_types::types::{DBUuid, NullableOption};
+
+model!(
+    #![build_api]
+
+    pub struct Post {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "I"]
+        pub id: NullableOption<String>,
+    }
+);
+
+fn main() {}
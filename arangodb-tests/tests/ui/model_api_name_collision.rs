@@ -0,0 +1,18 @@
+use arangodb_types::models::model;
+#[allow(unused_imports)]
+use arangodb_types::types::{DBUuid, NullableOption};
+
+model!(
+    #![build_api]
+    #![build_Api]
+
+    pub struct Post {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "T"]
+        pub title: NullableOption<String>,
+    }
+);
+
+fn main() {}
@@ -0,0 +1,16 @@
+use arangodb_types::models::model;
+#[allow(unused_imports)]
+use arangodb_types::types::{DBReference, DBUuid, NullableOption};
+
+model!(
+    pub struct Post {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "A"]
+        #[inner_model = "struct"]
+        pub author: NullableOption<DBReference<AuthorDBDocument>>,
+    }
+);
+
+fn main() {}
@@ -0,0 +1,18 @@
+use arangodb_types::models::model;
+#[allow(unused_imports)]
+use arangodb_types::types::{DBUuid, NullableOption};
+
+model!(
+    #![build_api]
+
+    pub struct Account {
+        #[db_name = "_key"]
+        pub db_key: Option<DBUuid>,
+
+        #[db_name = "B"]
+        #[encrypted = "ReverseCipher"]
+        pub balance: NullableOption<u64>,
+    }
+);
+
+fn main() {}
@@ -0,0 +1,15 @@
+use arangodb_types::models::type_model;
+#[allow(unused_imports)]
+use arangodb_types::types::{DBReference, NullableOption};
+
+type_model!(
+    #![build_api]
+
+    pub struct PostInfo {
+        #[db_name = "A"]
+        #[inner_model = "struct"]
+        pub author: NullableOption<DBReference<AuthorDBDocument>>,
+    }
+);
+
+fn main() {}
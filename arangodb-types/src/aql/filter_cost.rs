@@ -0,0 +1,44 @@
+use std::collections::HashSet;
+
+/// The fields that are always indexed by ArangoDB: the primary and edge indexes.
+const ALWAYS_INDEXED_FIELDS: &[&str] = &["_key", "_from", "_to"];
+
+/// The estimated cost of a filter based on the indexes that can serve it.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct FilterCost {
+    /// The paths of the fields compared by the filter.
+    pub fields: Vec<&'static str>,
+    /// The paths of `fields` that are not covered by any index.
+    pub unindexed_fields: Vec<&'static str>,
+}
+
+impl FilterCost {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Whether every field of the filter is indexed.
+    pub fn is_fully_indexed(&self) -> bool {
+        self.unindexed_fields.is_empty()
+    }
+
+    /// Whether no index can serve the filter, so the whole collection must be scanned.
+    pub fn requires_full_scan(&self) -> bool {
+        self.fields.len() == self.unindexed_fields.len()
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Adds a field to the filter, checking whether `indexed_fields` contains it.
+    pub fn add_field(&mut self, path: &'static str, indexed_fields: &HashSet<String>) {
+        self.fields.push(path);
+
+        if !ALWAYS_INDEXED_FIELDS.contains(&path) && !indexed_fields.contains(path) {
+            self.unindexed_fields.push(path);
+        }
+    }
+
+    /// Adds the fields of `other` to the filter, e.g. the ones of a flattened field.
+    pub fn merge(&mut self, other: FilterCost) {
+        self.fields.extend(other.fields);
+        self.unindexed_fields.extend(other.unindexed_fields);
+    }
+}
@@ -1,14 +1,18 @@
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 use arangors::document::options::OverwriteMode;
 use serde::Serialize;
 
+pub use filter_cost::*;
 pub use result::*;
 
 use crate::traits::AQLMapping;
+use crate::types::DBDateTime;
 
 pub mod aql_functions;
+mod filter_cost;
 mod result;
 
 pub const AQL_COLLECTION_ID: &str = "@collection";
@@ -46,9 +50,11 @@ pub const AQL_INLINE_IDS: &[&str] = &[
 pub struct AqlBuilder<'a> {
     alias: &'a str,
     next_var: usize,
+    next_inline_var: usize,
     kind: AqlBuilderKind<'a>,
     batch_size: Option<u32>,
     full_count: bool,
+    query_cache: Option<bool>,
     handle_write_conflicts: bool,
     global_limit: u64,
     steps: Vec<AqlKind<'a>>,
@@ -69,9 +75,11 @@ impl<'a> AqlBuilder<'a> {
         AqlBuilder {
             alias: "",
             next_var: 0,
+            next_inline_var: 0,
             kind: AqlBuilderKind::Plain,
             batch_size: None,
             full_count: false,
+            query_cache: None,
             handle_write_conflicts: false,
             global_limit: 0,
             steps: Default::default(),
@@ -83,9 +91,11 @@ impl<'a> AqlBuilder<'a> {
         AqlBuilder {
             alias,
             next_var: 0,
+            next_inline_var: 0,
             kind: AqlBuilderKind::Collection(collection),
             batch_size: None,
             full_count: false,
+            query_cache: None,
             handle_write_conflicts: false,
             global_limit: 0,
             steps: Default::default(),
@@ -110,11 +120,13 @@ impl<'a> AqlBuilder<'a> {
         AqlBuilder {
             alias,
             next_var: 0,
+            next_inline_var: 0,
             kind: AqlBuilderKind::List(
                 iterator.map(|v| serde_json::to_value(v).unwrap()).collect(),
             ),
             batch_size: None,
             full_count: false,
+            query_cache: None,
             handle_write_conflicts: false,
             global_limit: 0,
             steps: Default::default(),
@@ -132,6 +144,12 @@ impl<'a> AqlBuilder<'a> {
         self.full_count
     }
 
+    /// Whether the results of the query must be read from and stored in the query results cache
+    /// of the server. `None` uses the cache mode of the server.
+    pub fn query_cache(&self) -> Option<bool> {
+        self.query_cache
+    }
+
     pub fn handle_write_conflicts(&self) -> bool {
         self.handle_write_conflicts
     }
@@ -150,6 +168,13 @@ impl<'a> AqlBuilder<'a> {
         self.full_count = full_count;
     }
 
+    /// Sets whether to use the query results cache of the server. With the `demand` mode only
+    /// the queries with `Some(true)` are cached whereas with the `on` mode `Some(false)` skips
+    /// the cache. See `DBInfo::set_query_cache_mode`.
+    pub fn set_query_cache(&mut self, query_cache: Option<bool>) {
+        self.query_cache = query_cache;
+    }
+
     pub fn set_handle_write_conflicts(&mut self, handle_write_conflicts: bool) {
         self.handle_write_conflicts = handle_write_conflicts;
     }
@@ -221,10 +246,32 @@ impl<'a> AqlBuilder<'a> {
         self.steps.push(AqlKind::Limit(step));
     }
 
+    /// Appends a let step.
+    ///
+    /// # Panics
+    /// Panics if it copies a variable that is not declared by a previous let step.
     pub fn let_step(&mut self, step: AqlLet<'a>) {
+        if let AqlLetKind::Variable(variable) = &step.expression {
+            assert!(
+                self.steps
+                    .iter()
+                    .any(|v| matches!(v, AqlKind::Let(v) if v.variable == *variable)),
+                "The variable '{}' must be declared by a previous let step",
+                variable
+            );
+        }
+
         self.steps.push(AqlKind::Let(step));
     }
 
+    /// Appends several let steps in order, so each one can use the variables declared by the
+    /// previous ones.
+    pub fn let_steps(&mut self, steps: Vec<AqlLet<'a>>) {
+        for step in steps {
+            self.let_step(step);
+        }
+    }
+
     pub fn remove_step(&mut self, step: AqlRemove<'a>) {
         self.steps.push(AqlKind::Remove(step));
     }
@@ -253,6 +300,78 @@ impl<'a> AqlBuilder<'a> {
         self.steps.push(AqlKind::Other(step));
     }
 
+    /// Appends the body of `inner` as a loop nested in the current scope, renumbering its bind
+    /// and inline variables so they do not collide with the ones of this builder.
+    pub fn nest(&mut self, inner: AqlBuilder<'a>) {
+        let inner_query = inner.build_query();
+
+        // Move bind variables.
+        let mut inner_vars = inner.vars.into_iter().collect::<Vec<_>>();
+        inner_vars.sort_by_key(|(id, _)| AQL_VARIABLE_IDS.iter().position(|v| &v[1..] == *id));
+
+        let mut bind_ids = HashMap::with_capacity(inner_vars.len());
+        for (id, value) in inner_vars {
+            let new_id = self
+                .add_variable_as_json(value)
+                .expect("Too many variables in nested builder");
+            bind_ids.insert(id, new_id);
+        }
+
+        // Skip the inline variables already declared in this scope.
+        let inline_offset = self
+            .steps
+            .iter()
+            .filter_map(|step| match step {
+                AqlKind::Let(v) => AQL_INLINE_IDS
+                    .iter()
+                    .position(|id| *id == v.variable)
+                    .map(|v| v + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+            .max(self.next_inline_var);
+
+        let mut inline_count = 0;
+        let query = Self::rename_nested_variables(
+            inner_query.trim_start(),
+            &bind_ids,
+            inline_offset,
+            &mut inline_count,
+        );
+
+        self.next_inline_var = inline_offset + inline_count;
+        self.steps
+            .push(AqlKind::Other(format!(" {}", query).into()));
+    }
+
+    /// Turns this query into one that only counts the matching documents, keeping its filters
+    /// but dropping the sorts, limits and returns. Useful to get the total before fetching any
+    /// page.
+    pub fn count_only(mut self) -> AqlBuilder<'a> {
+        self.steps.retain(|step| {
+            !matches!(
+                step,
+                AqlKind::Sort(_) | AqlKind::Limit(_) | AqlKind::Return(_)
+            )
+        });
+        self.full_count = false;
+        self.collect_step(AqlCollect::new_count("count".into()));
+        self.return_step(AqlReturn::new_expression("count".into()));
+
+        // Remove the variables of the dropped steps because unused bind variables are rejected.
+        let query = self.build_query();
+        self.vars.retain(|id, _| {
+            query.match_indices(id).any(|(index, _)| {
+                query[..index].ends_with('@')
+                    && !query[index + id.len()..]
+                        .starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+            })
+        });
+
+        self
+    }
+
     pub fn add_variable<T: Serialize>(
         &mut self,
         value: &T,
@@ -293,6 +412,100 @@ impl<'a> AqlBuilder<'a> {
 
         query
     }
+
+    /// Builds the body of the request to create a cursor in the `_api/cursor` endpoint.
+    pub fn build_cursor_body(&self) -> serde_json::Value {
+        let mut body = serde_json::json!({
+            "query": self.build_query(),
+            "bindVars": self.vars,
+            "options": {
+                "fullCount": self.full_count,
+            },
+        });
+
+        if let Some(batch_size) = self.batch_size {
+            body["batchSize"] = batch_size.into();
+        }
+
+        if let Some(query_cache) = self.query_cache {
+            body["cache"] = query_cache.into();
+        }
+
+        body
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Renames the bind and inline variables of a query, leaving string literals and quoted
+    /// names untouched.
+    fn rename_nested_variables(
+        query: &str,
+        bind_ids: &HashMap<&'static str, &'static str>,
+        inline_offset: usize,
+        inline_count: &mut usize,
+    ) -> String {
+        let is_identifier_byte = |byte: u8| byte.is_ascii_alphanumeric() || byte == b'_';
+        let bytes = query.as_bytes();
+        let mut result = String::with_capacity(query.len());
+        let mut index = 0;
+
+        while index < bytes.len() {
+            let start = index;
+            let byte = bytes[index];
+
+            match byte {
+                b'"' | b'\'' | b'`' => {
+                    index += 1;
+
+                    while index < bytes.len() && bytes[index] != byte {
+                        if bytes[index] == b'\\' {
+                            index += 1;
+                        }
+
+                        index += 1;
+                    }
+
+                    index = (index + 1).min(bytes.len());
+                    result.push_str(&query[start..index]);
+                }
+                b'@' | b'_' | b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' => {
+                    index += 1;
+
+                    while index < bytes.len() && is_identifier_byte(bytes[index]) {
+                        index += 1;
+                    }
+
+                    let word = &query[start..index];
+
+                    if start > 0 && bytes[start - 1] == b'.' {
+                        // Attribute accesses are not variables.
+                        result.push_str(word);
+                    } else if let Some(id) = word.strip_prefix('@').and_then(|v| bind_ids.get(v)) {
+                        result.push_str(id);
+                    } else if let Some(position) = AQL_INLINE_IDS.iter().position(|v| *v == word) {
+                        *inline_count = (*inline_count).max(position + 1);
+                        result.push_str(get_aql_inline_variable(inline_offset + position));
+                    } else {
+                        result.push_str(word);
+                    }
+                }
+                _ => {
+                    index += 1;
+
+                    while index < bytes.len()
+                        && !is_identifier_byte(bytes[index])
+                        && !matches!(bytes[index], b'@' | b'"' | b'\'' | b'`')
+                    {
+                        index += 1;
+                    }
+
+                    result.push_str(&query[start..index]);
+                }
+            }
+        }
+
+        result
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -399,6 +612,14 @@ impl<'a> AqlReturn<'a> {
         }
     }
 
+    /// Returns the `base` variable merged with the `extra` object expression.
+    pub fn new_merged(base: &str, extra: &str) -> AqlReturn<'a> {
+        AqlReturn {
+            distinct: false,
+            expression: format!("MERGE({}, {})", base, extra).into(),
+        }
+    }
+
     // METHODS ----------------------------------------------------------------
 
     pub(crate) fn build_query(&self, query: &mut String) {
@@ -482,6 +703,9 @@ impl<'a> AqlLet<'a> {
             AqlLetKind::Expression(v) => {
                 query.push_str(v.as_ref());
             }
+            AqlLetKind::Variable(v) => {
+                query.push_str(v);
+            }
             AqlLetKind::Aql(v) => {
                 let content = v.build_query();
 
@@ -499,6 +723,8 @@ impl<'a> AqlLet<'a> {
 pub enum AqlLetKind<'a> {
     Expression(Cow<'a, str>),
     Aql(AqlBuilder<'a>),
+    /// The value of the variable of a previous let step.
+    Variable(&'static str),
 }
 
 // ----------------------------------------------------------------------------
@@ -511,6 +737,7 @@ pub struct AqlRemove<'a> {
     pub collection: &'a str,
     pub ignore_revs: bool,
     pub ignore_errors: bool,
+    pub exclusive: bool,
 }
 
 impl<'a> AqlRemove<'a> {
@@ -522,6 +749,7 @@ impl<'a> AqlRemove<'a> {
             collection,
             ignore_revs: false,
             ignore_errors: true,
+            exclusive: false,
         }
     }
 
@@ -541,6 +769,12 @@ impl<'a> AqlRemove<'a> {
         self
     }
 
+    /// Locks the collection exclusively during the write to avoid write-write conflicts.
+    pub fn apply_exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
     pub(crate) fn build_query(&self, query: &mut String) {
         query.push_str(" REMOVE ");
         query.push_str(self.variable.as_ref());
@@ -548,11 +782,17 @@ impl<'a> AqlRemove<'a> {
         query.push_str(self.collection);
         query.push_str(
             format!(
-                " OPTIONS {{ ignoreRevs: {}, ignoreErrors: {} }}",
+                " OPTIONS {{ ignoreRevs: {}, ignoreErrors: {}",
                 self.ignore_revs, self.ignore_errors
             )
             .as_str(),
         );
+
+        if self.exclusive {
+            query.push_str(", exclusive: true");
+        }
+
+        query.push_str(" }");
     }
 }
 
@@ -569,6 +809,7 @@ pub struct AqlUpdate<'a> {
     pub keep_null: bool,
     pub merge_objects: bool,
     pub ignore_errors: bool,
+    pub exclusive: bool,
 }
 
 impl<'a> AqlUpdate<'a> {
@@ -583,6 +824,7 @@ impl<'a> AqlUpdate<'a> {
             keep_null: false,
             merge_objects: true,
             ignore_errors: false,
+            exclusive: false,
         }
     }
 
@@ -612,6 +854,12 @@ impl<'a> AqlUpdate<'a> {
         self
     }
 
+    /// Locks the collection exclusively during the write to avoid write-write conflicts.
+    pub fn apply_exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
     pub(crate) fn build_query(&self, query: &mut String) {
         query.push_str(" UPDATE ");
         query.push_str(self.variable.as_ref());
@@ -621,11 +869,17 @@ impl<'a> AqlUpdate<'a> {
         query.push_str(self.collection);
         query.push_str(
             format!(
-                " OPTIONS {{ ignoreRevs: {}, keepNull: {}, mergeObjects: {}, ignoreErrors: {} }}",
+                " OPTIONS {{ ignoreRevs: {}, keepNull: {}, mergeObjects: {}, ignoreErrors: {}",
                 self.ignore_revs, self.keep_null, self.merge_objects, self.ignore_errors
             )
             .as_str(),
         );
+
+        if self.exclusive {
+            query.push_str(", exclusive: true");
+        }
+
+        query.push_str(" }");
     }
 }
 
@@ -640,6 +894,7 @@ pub struct AqlReplace<'a> {
     pub expression: Cow<'a, str>,
     pub ignore_revs: bool,
     pub ignore_errors: bool,
+    pub exclusive: bool,
 }
 
 impl<'a> AqlReplace<'a> {
@@ -652,6 +907,7 @@ impl<'a> AqlReplace<'a> {
             expression,
             ignore_revs: false,
             ignore_errors: false,
+            exclusive: false,
         }
     }
 
@@ -671,6 +927,12 @@ impl<'a> AqlReplace<'a> {
         self
     }
 
+    /// Locks the collection exclusively during the write to avoid write-write conflicts.
+    pub fn apply_exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
     pub(crate) fn build_query(&self, query: &mut String) {
         query.push_str(" REPLACE ");
         query.push_str(self.variable.as_ref());
@@ -680,11 +942,17 @@ impl<'a> AqlReplace<'a> {
         query.push_str(self.collection);
         query.push_str(
             format!(
-                " OPTIONS {{ ignoreRevs: {}, ignoreErrors: {} }}",
+                " OPTIONS {{ ignoreRevs: {}, ignoreErrors: {}",
                 self.ignore_revs, self.ignore_errors
             )
             .as_str(),
         );
+
+        if self.exclusive {
+            query.push_str(", exclusive: true");
+        }
+
+        query.push_str(" }");
     }
 }
 
@@ -700,6 +968,7 @@ pub struct AqlInsert<'a> {
     pub keep_null: bool,
     pub merge_objects: bool,
     pub ignore_errors: bool,
+    pub exclusive: bool,
 }
 
 impl<'a> AqlInsert<'a> {
@@ -713,6 +982,7 @@ impl<'a> AqlInsert<'a> {
             keep_null: false,
             merge_objects: true,
             ignore_errors: false,
+            exclusive: false,
         }
     }
 
@@ -722,6 +992,11 @@ impl<'a> AqlInsert<'a> {
 
     // METHODS ----------------------------------------------------------------
 
+    pub fn apply_overwrite_mode(mut self, overwrite_mode: OverwriteMode) -> Self {
+        self.overwrite_mode = overwrite_mode;
+        self
+    }
+
     pub fn apply_keep_null(mut self, keep_null: bool) -> Self {
         self.keep_null = keep_null;
         self
@@ -737,6 +1012,12 @@ impl<'a> AqlInsert<'a> {
         self
     }
 
+    /// Locks the collection exclusively during the write to avoid write-write conflicts.
+    pub fn apply_exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
     pub(crate) fn build_query(&self, query: &mut String) {
         query.push_str(" INSERT ");
         query.push_str(self.expression.as_ref());
@@ -744,14 +1025,20 @@ impl<'a> AqlInsert<'a> {
         query.push_str(self.collection);
         query.push_str(
             format!(
-                " OPTIONS {{ overwriteMode: {}, keepNull: {}, mergeObjects: {}, ignoreErrors: {} }}",
+                " OPTIONS {{ overwriteMode: {}, keepNull: {}, mergeObjects: {}, ignoreErrors: {}",
                 serde_json::to_string(&self.overwrite_mode).unwrap(),
                 self.keep_null,
                 self.merge_objects,
                 self.ignore_errors,
             )
-                .as_str(),
+            .as_str(),
         );
+
+        if self.exclusive {
+            query.push_str(", exclusive: true");
+        }
+
+        query.push_str(" }");
     }
 }
 
@@ -769,6 +1056,7 @@ pub struct AqlUpsert<'a> {
     pub keep_null: bool,
     pub merge_objects: bool,
     pub ignore_errors: bool,
+    pub exclusive: bool,
 }
 
 impl<'a> AqlUpsert<'a> {
@@ -789,6 +1077,7 @@ impl<'a> AqlUpsert<'a> {
             keep_null: false,
             merge_objects: true,
             ignore_errors: false,
+            exclusive: false,
         }
     }
 
@@ -807,6 +1096,7 @@ impl<'a> AqlUpsert<'a> {
             keep_null: false,
             merge_objects: true,
             ignore_errors: false,
+            exclusive: false,
         }
     }
 
@@ -827,6 +1117,12 @@ impl<'a> AqlUpsert<'a> {
         self
     }
 
+    /// Locks the collection exclusively during the write to avoid write-write conflicts.
+    pub fn apply_exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
     pub(crate) fn build_query(&self, query: &mut String) {
         query.push_str(" UPSERT ");
         query.push_str(self.lookup_expression.as_ref());
@@ -844,11 +1140,17 @@ impl<'a> AqlUpsert<'a> {
         query.push_str(self.collection);
         query.push_str(
             format!(
-                " OPTIONS {{ keepNull: {}, mergeObjects: {}, ignoreErrors: {} }}",
+                " OPTIONS {{ keepNull: {}, mergeObjects: {}, ignoreErrors: {}",
                 self.keep_null, self.merge_objects, self.ignore_errors,
             )
             .as_str(),
         );
+
+        if self.exclusive {
+            query.push_str(", exclusive: true");
+        }
+
+        query.push_str(" }");
     }
 }
 
@@ -974,3 +1276,239 @@ impl<'a> AqlCollect<'a> {
 pub fn get_aql_inline_variable(index: usize) -> &'static str {
     AQL_INLINE_IDS[index]
 }
+
+/// Builds a filter expression restricting the date `field` of `variable` to the `[start, end)`
+/// range. Missing bounds are not checked.
+pub fn date_between<F: Display>(
+    variable: &str,
+    field: &F,
+    start: Option<&DBDateTime>,
+    end: Option<&DBDateTime>,
+) -> String {
+    let mut conditions = Vec::with_capacity(2);
+
+    if let Some(start) = start {
+        conditions.push(format!(
+            "{}.{} >= {}",
+            variable,
+            field,
+            serde_json::to_string(start).unwrap()
+        ));
+    }
+
+    if let Some(end) = end {
+        conditions.push(format!(
+            "{}.{} < {}",
+            variable,
+            field,
+            serde_json::to_string(end).unwrap()
+        ));
+    }
+
+    if conditions.is_empty() {
+        "true".to_string()
+    } else {
+        conditions.join(" && ")
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    #[test]
+    fn test_nest() {
+        let mut outer = AqlBuilder::new_for_in_collection("a", "Users");
+        let outer_id = outer.add_variable(&"x").unwrap().unwrap();
+        outer.filter_step(format!("a.name == {}", outer_id).into());
+        outer.let_step(AqlLet {
+            variable: get_aql_inline_variable(0),
+            expression: AqlLetKind::Expression("a.friends".into()),
+        });
+
+        let mut inner = AqlBuilder::new_for_in_list("b", &["_0", "@v0"]);
+        let inner_id = inner.add_variable(&5).unwrap().unwrap();
+        inner.let_step(AqlLet {
+            variable: get_aql_inline_variable(0),
+            expression: AqlLetKind::Expression("b.age".into()),
+        });
+        inner.filter_step(format!("_0 > {} AND b IN a._0", inner_id).into());
+        inner.return_step(AqlReturn::new_expression("{a, b, age: _0}".into()));
+
+        outer.nest(inner);
+
+        assert_eq!(
+            outer.build_query(),
+            "FOR a IN Users FILTER a.name == @v0 LET _0 = a.friends FOR b IN [\"_0\",\"@v0\"] LET _1 = b.age FILTER _1 > @v1 AND b IN a._0 RETURN {a, b, age: _1}"
+        );
+        assert_eq!(outer.vars.get("v0"), Some(&serde_json::json!("x")));
+        assert_eq!(outer.vars.get("v1"), Some(&serde_json::json!(5)));
+    }
+
+    #[test]
+    fn test_count_only() {
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Users");
+        let filter_id = aql.add_variable(&"x").unwrap().unwrap();
+        aql.filter_step(format!("i.name == {}", filter_id).into());
+        let sort_id = aql.add_variable(&"age").unwrap().unwrap();
+        aql.sort_step(vec![AqlSort {
+            is_descending: false,
+            expression: format!("i[{}]", sort_id).into(),
+        }]);
+        aql.limit_step(AqlLimit {
+            offset: Some(10),
+            count: 5,
+        });
+        aql.set_full_count(true);
+        aql.return_step(AqlReturn::new_document());
+
+        let aql = aql.count_only();
+        let query = aql.build_query();
+
+        assert_eq!(
+            query,
+            "FOR i IN Users FILTER i.name == @v0 COLLECT WITH COUNT INTO count RETURN count"
+        );
+        assert!(!query.contains("SORT"), "The query must not sort");
+        assert!(!query.contains("LIMIT"), "The query must not limit");
+        assert!(
+            !query.contains("RETURN i"),
+            "The query must not return documents"
+        );
+        assert!(!aql.full_count(), "The full count must be disabled");
+        assert_eq!(aql.vars.len(), 1, "Incorrect variable count");
+        assert_eq!(aql.vars.get("v0"), Some(&serde_json::json!("x")));
+    }
+
+    #[test]
+    fn test_query_cache() {
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Users");
+        aql.return_step(AqlReturn::new_document());
+
+        let body = aql.build_cursor_body();
+        assert!(body.get("cache").is_none(), "The cache must not be sent");
+
+        aql.set_query_cache(Some(true));
+
+        let body = aql.build_cursor_body();
+        assert_eq!(body["cache"], serde_json::json!(true), "Incorrect cache");
+        assert_eq!(body["query"], serde_json::json!("FOR i IN Users RETURN i"));
+    }
+
+    #[test]
+    fn test_date_between() {
+        struct CreatedAtField;
+
+        impl Display for CreatedAtField {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("C")
+            }
+        }
+
+        let start = DBDateTime(Utc.ymd(1970, 1, 1).and_hms(0, 0, 1));
+        let end = DBDateTime(Utc.ymd(1970, 1, 1).and_hms(0, 0, 2));
+
+        assert_eq!(
+            date_between("i", &CreatedAtField, Some(&start), Some(&end)),
+            "i.C >= 1000 && i.C < 2000"
+        );
+        assert_eq!(
+            date_between("i", &CreatedAtField, Some(&start), None),
+            "i.C >= 1000"
+        );
+        assert_eq!(
+            date_between("i", &CreatedAtField, None, Some(&end)),
+            "i.C < 2000"
+        );
+        assert_eq!(date_between("i", &CreatedAtField, None, None), "true");
+    }
+
+    #[test]
+    fn test_return_merged() {
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Users");
+        aql.return_step(AqlReturn::new_merged(
+            AQL_DOCUMENT_ID,
+            "{ fullName: CONCAT(i.name, \" \", i.surname) }",
+        ));
+
+        assert_eq!(
+            aql.build_query(),
+            "FOR i IN Users RETURN MERGE(i, { fullName: CONCAT(i.name, \" \", i.surname) })"
+        );
+    }
+
+    #[test]
+    fn test_let_steps() {
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Users");
+        aql.let_steps(vec![
+            AqlLet {
+                variable: get_aql_inline_variable(0),
+                expression: AqlLetKind::Expression("LENGTH(i.friends)".into()),
+            },
+            AqlLet {
+                variable: get_aql_inline_variable(1),
+                expression: AqlLetKind::Expression("_0 * 2".into()),
+            },
+            AqlLet {
+                variable: get_aql_inline_variable(2),
+                expression: AqlLetKind::Variable(get_aql_inline_variable(1)),
+            },
+        ]);
+        aql.return_step(AqlReturn::new_expression("_2".into()));
+
+        assert_eq!(
+            aql.build_query(),
+            "FOR i IN Users LET _0 = LENGTH(i.friends) LET _1 = _0 * 2 LET _2 = _1 RETURN _2"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The variable '_1' must be declared by a previous let step")]
+    fn test_let_steps_undeclared_variable() {
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Users");
+        aql.let_steps(vec![
+            AqlLet {
+                variable: get_aql_inline_variable(0),
+                expression: AqlLetKind::Variable(get_aql_inline_variable(1)),
+            },
+            AqlLet {
+                variable: get_aql_inline_variable(1),
+                expression: AqlLetKind::Expression("i.age".into()),
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_exclusive_write() {
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Users");
+        aql.update_step(AqlUpdate::new_document("Users", "{ a: 1 }".into()).apply_exclusive(true));
+
+        assert_eq!(
+            aql.build_query(),
+            "FOR i IN Users UPDATE i WITH { a: 1 } IN Users OPTIONS { ignoreRevs: false, keepNull: false, mergeObjects: true, ignoreErrors: false, exclusive: true }"
+        );
+
+        let mut aql = AqlBuilder::new_for_in_list(AQL_DOCUMENT_ID, &[1, 2]);
+        aql.insert_step(AqlInsert::new_document("Users").apply_exclusive(true));
+
+        assert_eq!(
+            aql.build_query(),
+            "FOR i IN [1,2] INSERT i INTO Users OPTIONS { overwriteMode: \"replace\", keepNull: false, mergeObjects: true, ignoreErrors: false, exclusive: true }"
+        );
+
+        // Not emitted by default.
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, "Users");
+        aql.remove_step(AqlRemove::new_document("Users"));
+
+        assert_eq!(
+            aql.build_query(),
+            "FOR i IN Users REMOVE i IN Users OPTIONS { ignoreRevs: false, ignoreErrors: true }"
+        );
+    }
+}
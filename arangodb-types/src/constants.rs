@@ -4,8 +4,25 @@
 pub const MUTEX_ALIVE_INTERVAL: u64 = 2 * 60;
 #[cfg(feature = "test")]
 pub const MUTEX_ALIVE_INTERVAL: u64 = 3;
+// 10% of the alive interval in milliseconds, applied in both directions
+pub const MUTEX_ALIVE_JITTER: u64 = MUTEX_ALIVE_INTERVAL * 100;
 // Alive interval + 10 seconds
 pub const MUTEX_EXPIRATION: u64 = MUTEX_ALIVE_INTERVAL + 10;
 // From 50ms to 150ms
 pub const MUTEX_ACQUIRE_MIN_INTERVAL: u64 = 100;
 pub const MUTEX_ACQUIRE_MAX_INTERVAL: u64 = 150;
+// Maximum number of keys locked per request
+pub const MUTEX_ACQUIRE_BATCH_SIZE: usize = 1000;
+
+// Collections ----------------------------------------------------------------
+// Default maximum number of documents returned by get_all_limited
+pub const GET_ALL_MAX_DOCUMENTS: u64 = 10_000;
+// Number of keys fetched per request by stream_keys
+pub const STREAM_KEYS_BATCH_SIZE: u32 = 1000;
+
+// Migrations -----------------------------------------------------------------
+pub const MIGRATIONS_COLLECTION: &str = "_migrations";
+
+// Tailing --------------------------------------------------------------------
+// Delay in milliseconds between requests when the WAL has no more changes
+pub const TAIL_POLL_INTERVAL: u64 = 500;
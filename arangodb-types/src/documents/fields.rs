@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::fmt;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -27,3 +28,9 @@ impl DBDocumentField {
         }
     }
 }
+
+impl fmt::Display for DBDocumentField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.path())
+    }
+}
@@ -9,8 +9,10 @@ pub mod types;
 pub mod utilities;
 
 // Re-export other libs.
+pub use anyhow;
 pub use arangors;
 pub use arcstr;
 pub use async_trait;
 pub use nanoid;
 pub use rand;
+pub use serde_json;
@@ -0,0 +1,7 @@
+/// Applies a partial API model to a DB model. Implemented by the models for every API model
+/// built with `#![build_<model>]`.
+pub trait ApplyPatch<P> {
+    /// Copies only the fields present in `patch`, i.e. those that are not `Missing` or `None`,
+    /// patching the sub-models recursively. Read-only fields are never copied.
+    fn apply_patch(&mut self, patch: P);
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Write;
 
 use serde::Serialize;
@@ -18,7 +18,28 @@ pub trait AQLMapping {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+impl<T: AQLMapping> AQLMapping for Box<T> {
+    fn include_let_steps(&self, aql: &mut AqlBuilder, path: &str, next_id: &mut usize) {
+        self.as_ref().include_let_steps(aql, path, next_id)
+    }
+
+    fn map_to_json(&self, buffer: &mut Vec<u8>, path: &str, next_id: &mut usize) {
+        self.as_ref().map_to_json(buffer, path, next_id)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 impl<T: AQLMapping> AQLMapping for Vec<T> {
+    fn include_let_steps(&self, aql: &mut AqlBuilder, path: &str, next_id: &mut usize) {
+        for (i, v) in self.iter().enumerate() {
+            let sub_path = format!("{}[{}]", path, i);
+            v.include_let_steps(aql, sub_path.as_str(), next_id);
+        }
+    }
+
     fn map_to_json(&self, buffer: &mut Vec<u8>, path: &str, next_id: &mut usize) {
         buffer.write_all(b"[").unwrap();
 
@@ -37,11 +58,45 @@ impl<T: AQLMapping> AQLMapping for Vec<T> {
 // ----------------------------------------------------------------------------
 
 impl<K: Serialize, T: AQLMapping> AQLMapping for HashMap<K, T> {
+    fn include_let_steps(&self, aql: &mut AqlBuilder, path: &str, next_id: &mut usize) {
+        for (k, v) in self.iter() {
+            let sub_path = format!("{}[{}]", path, map_key_literal(k));
+            v.include_let_steps(aql, sub_path.as_str(), next_id);
+        }
+    }
+
+    fn map_to_json(&self, buffer: &mut Vec<u8>, path: &str, next_id: &mut usize) {
+        buffer.write_all(b"{").unwrap();
+
+        for (k, v) in self.iter() {
+            let key = map_key_literal(k);
+            let sub_path = format!("{}[{}]", path, key);
+            buffer.write_all(key.as_bytes()).unwrap();
+            buffer.write_all(b":").unwrap();
+            v.map_to_json(buffer, sub_path.as_str(), next_id);
+            buffer.write_all(b",").unwrap();
+        }
+
+        buffer.write_all(b"}").unwrap();
+    }
+}
+
+impl<K: Serialize, T: AQLMapping> AQLMapping for BTreeMap<K, T> {
+    fn include_let_steps(&self, aql: &mut AqlBuilder, path: &str, next_id: &mut usize) {
+        for (k, v) in self.iter() {
+            let sub_path = format!("{}[{}]", path, map_key_literal(k));
+            v.include_let_steps(aql, sub_path.as_str(), next_id);
+        }
+    }
+
     fn map_to_json(&self, buffer: &mut Vec<u8>, path: &str, next_id: &mut usize) {
         buffer.write_all(b"{").unwrap();
 
         for (k, v) in self.iter() {
-            let sub_path = format!("{}.{}", path, serde_json::to_string(k).unwrap());
+            let key = map_key_literal(k);
+            let sub_path = format!("{}[{}]", path, key);
+            buffer.write_all(key.as_bytes()).unwrap();
+            buffer.write_all(b":").unwrap();
             v.map_to_json(buffer, sub_path.as_str(), next_id);
             buffer.write_all(b",").unwrap();
         }
@@ -49,3 +104,15 @@ impl<K: Serialize, T: AQLMapping> AQLMapping for HashMap<K, T> {
         buffer.write_all(b"}").unwrap();
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Gets the AQL string literal of a map key. Keys are always strings in JSON objects.
+fn map_key_literal<K: Serialize>(key: &K) -> String {
+    match serde_json::to_value(key).unwrap() {
+        serde_json::Value::String(v) => serde_json::to_string(&v).unwrap(),
+        v => serde_json::to_string(&v.to_string()).unwrap(),
+    }
+}
@@ -0,0 +1,9 @@
+/// Encrypts and decrypts the fields marked as `#[encrypted = "..."]` in the models when they
+/// are converted between their API and DB forms.
+pub trait Cipher {
+    /// Encrypts a plain value of the API model to store it in the DB model.
+    fn encrypt(plaintext: String) -> String;
+
+    /// Decrypts a value of the DB model to expose it in the API model.
+    fn decrypt(ciphertext: String) -> String;
+}
@@ -1,25 +1,38 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::pin::Pin;
 use std::sync::Arc;
 
 use arangors::{AqlOptions, AqlQuery};
+use arcstr::ArcStr;
 use async_trait::async_trait;
+use futures_util::stream::{self, Stream};
 use serde::{Deserialize, Serialize};
 
-use crate::aql::{AqlBuilder, AqlInsert};
-use crate::aql::AQL_DOCUMENT_ID;
-use crate::aql::AQL_NEW_ID;
 use crate::aql::AqlLet;
 use crate::aql::AqlLetKind;
 use crate::aql::AqlLimit;
 use crate::aql::AqlResult;
 use crate::aql::AqlReturn;
 use crate::aql::AqlUpdate;
+use crate::aql::AQL_COLLECTION_ID;
+use crate::aql::AQL_DOCUMENT_ID;
+use crate::aql::AQL_NEW_ID;
+use crate::aql::{AqlBuilder, AqlInsert};
+use crate::constants::{GET_ALL_MAX_DOCUMENTS, STREAM_KEYS_BATCH_SIZE};
 use crate::documents::DBDocumentField;
+use crate::traits::AQLMapping;
 use crate::traits::DBDocument;
-use crate::traits::utils::check_client_is_write_conflict;
+use crate::types::retry_on_write_conflict;
 use crate::types::Collection;
-use crate::types::Database;
 use crate::types::DBInfo;
+use crate::types::DBUuid;
+use crate::types::Database;
+
+/// A stream of document keys, as returned by `DBCollection::stream_keys`.
+pub type DBKeyStream<'a, K> = Pin<Box<dyn Stream<Item = Result<K, anyhow::Error>> + Send + 'a>>;
+
+/// A stream of query results, as returned by `DBCollection::stream_pages`.
+pub type DBResultStream<'a, R> = Pin<Box<dyn Stream<Item = Result<R, anyhow::Error>> + Send + 'a>>;
 
 #[async_trait]
 pub trait DBCollection: Send + Sync {
@@ -36,11 +49,17 @@ pub trait DBCollection: Send + Sync {
     /// Gets the arangodb instance of this collection.
     async fn db_collection(&self) -> Result<Collection, anyhow::Error> {
         let db_info = self.db_info();
-        Ok(db_info.database.collection(Self::name()).await?)
+        Ok(db_info
+            .with_reconnect(|database| async move { database.collection(Self::name()).await })
+            .await?)
     }
 
-    fn database(&self) -> &Database {
-        &self.db_info().database
+    /// Gets the current database of `db_info`.
+    ///
+    /// Breaking change: it returns an owned `Database` instead of `&Database` because the
+    /// database is replaced when the JWT session expires.
+    fn database(&self) -> Database {
+        self.db_info().database()
     }
 
     // METHODS ----------------------------------------------------------------
@@ -50,16 +69,42 @@ pub trait DBCollection: Send + Sync {
         &self,
         key: &<Self::Document as DBDocument>::Key,
     ) -> Result<bool, anyhow::Error> {
-        Ok(self.get_one_by_key(key, None).await?.is_some())
+        self.exists_by(&DBDocumentField::Key.path(), key).await
     }
 
     /// Checks whether a document exists in the DB by a single custom property.
+    /// Write conflicts are retried instead of being returned as errors.
     async fn exists_by<V: Serialize + Send + Sync>(
         &self,
         property_path: &str,
         value: &V,
     ) -> Result<bool, anyhow::Error> {
-        Ok(self.get_one_by(property_path, value, None).await?.is_some())
+        // Prepare AQL.
+        // FOR i IN <collection>
+        //      FILTER i.<property> == <value>
+        //      LIMIT 1
+        //      RETURN true
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, Self::name());
+
+        aql.filter_step(
+            format!(
+                "{}.{} == {}",
+                AQL_DOCUMENT_ID,
+                property_path,
+                serde_json::to_string(value).unwrap()
+            )
+            .into(),
+        );
+        aql.limit_step(AqlLimit {
+            offset: None,
+            count: 1,
+        });
+        aql.return_step(AqlReturn::new_expression("true".into()));
+        aql.set_handle_write_conflicts(true);
+
+        let result = self.send_generic_aql::<bool>(&aql).await?;
+
+        Ok(!result.results.is_empty())
     }
 
     /// Gets all documents in the collection. Useful for cache.
@@ -83,6 +128,44 @@ pub trait DBCollection: Send + Sync {
         Ok(aql_result.results)
     }
 
+    /// Like `get_all` but fails if the collection has more than `max` documents, or
+    /// `GET_ALL_MAX_DOCUMENTS` if not set, instead of loading all of them.
+    async fn get_all_limited(
+        &self,
+        max: Option<u64>,
+        return_fields: Option<&Self::Document>,
+    ) -> Result<Vec<Self::Document>, anyhow::Error> {
+        let max = max.unwrap_or(GET_ALL_MAX_DOCUMENTS);
+
+        // Prepare AQL.
+        // FOR i IN <collection>
+        //      LIMIT <max + 1>
+        //      RETURN i
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, Self::name());
+        aql.limit_step(AqlLimit {
+            offset: None,
+            count: max.saturating_add(1),
+        });
+
+        if let Some(fields) = return_fields {
+            aql.return_step_with_fields(AQL_DOCUMENT_ID, fields);
+        } else {
+            aql.return_step(AqlReturn::new_document());
+        }
+
+        let aql_result = self.send_aql(&aql).await?;
+
+        if aql_result.results.len() as u64 > max {
+            return Err(anyhow::anyhow!(
+                "The collection {} has more than {} documents",
+                Self::name(),
+                max
+            ));
+        }
+
+        Ok(aql_result.results)
+    }
+
     /// Gets a document from the DB by its key.
     async fn get_one_by_key(
         &self,
@@ -95,6 +178,63 @@ pub trait DBCollection: Send + Sync {
         Ok(result)
     }
 
+    /// Gets a document from the DB by its key along with its current revision.
+    /// Useful to apply optimistic concurrency without a second request.
+    async fn get_one_by_key_with_rev(
+        &self,
+        key: &<<Self as DBCollection>::Document as DBDocument>::Key,
+        return_fields: Option<&Self::Document>,
+    ) -> Result<Option<(Self::Document, ArcStr)>, anyhow::Error> {
+        // Prepare AQL.
+        // FOR i IN <collection>
+        //      FILTER i._key == <key>
+        //      LIMIT 1
+        //      RETURN { D: <return_fields>, R: i._rev }
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, Self::name());
+
+        aql.filter_step(
+            format!(
+                "{}.{} == {}",
+                AQL_DOCUMENT_ID,
+                DBDocumentField::Key.path(),
+                serde_json::to_string(key).unwrap()
+            )
+            .into(),
+        );
+        aql.limit_step(AqlLimit {
+            offset: None,
+            count: 1,
+        });
+
+        let document_expression = if let Some(fields) = return_fields {
+            let mut buffer = Vec::with_capacity(50);
+            let mut next_id = 0;
+            fields.include_let_steps(&mut aql, AQL_DOCUMENT_ID, &mut next_id);
+
+            next_id = 0;
+            fields.map_to_json(&mut buffer, AQL_DOCUMENT_ID, &mut next_id);
+            String::from_utf8(buffer).unwrap()
+        } else {
+            AQL_DOCUMENT_ID.to_string()
+        };
+
+        aql.return_step(AqlReturn::new_expression(
+            format!(
+                "{{ D: {}, R: {}.{} }}",
+                document_expression,
+                AQL_DOCUMENT_ID,
+                DBDocumentField::Rev.path()
+            )
+            .into(),
+        ));
+
+        let mut aql_result = self
+            .send_generic_aql::<DocumentWithRev<Self::Document>>(&aql)
+            .await?;
+
+        Ok(aql_result.results.pop().map(|v| (v.document, v.rev)))
+    }
+
     /// Gets a list of documents from the DB by their keys.
     async fn get_many_by_key<
         I: Iterator<Item = <<Self as DBCollection>::Document as DBDocument>::Key> + std::marker::Send,
@@ -223,6 +363,134 @@ pub trait DBCollection: Send + Sync {
         Ok(result.results)
     }
 
+    /// Streams the keys of all the documents of the collection without reading the documents,
+    /// fetching them in batches.
+    fn stream_keys(&self) -> DBKeyStream<'_, <Self::Document as DBDocument>::Key> {
+        // FOR i IN <collection>
+        //      RETURN i._key
+        let query = format!(
+            "FOR {} IN {} RETURN {}.{}",
+            AQL_DOCUMENT_ID,
+            Self::name(),
+            AQL_DOCUMENT_ID,
+            DBDocumentField::Key.path()
+        );
+
+        Box::pin(stream::unfold(
+            (KeyCursor::Start(query), VecDeque::new()),
+            move |(mut cursor, mut keys)| async move {
+                loop {
+                    if let Some(key) = keys.pop_front() {
+                        return Some((Ok(key), (cursor, keys)));
+                    }
+
+                    if let KeyCursor::Done = cursor {
+                        return None;
+                    }
+
+                    match read_key_batch(self.db_info(), &cursor).await {
+                        Ok((batch, next_cursor)) => {
+                            keys.extend(batch);
+                            cursor = next_cursor;
+                        }
+                        Err(e) => return Some((Err(e), (KeyCursor::Done, keys))),
+                    }
+                }
+            },
+        ))
+    }
+
+    /// Streams the results of the query built by `build_aql` fetching them in pages of
+    /// `page_size` results. `build_aql` receives the `AqlLimit` of each page, moving its offset
+    /// forward until a page returns less than `page_size` results, so the query must be sorted
+    /// to get consistent pages.
+    fn stream_pages<'a, R, F>(&'a self, page_size: u64, build_aql: F) -> DBResultStream<'a, R>
+    where
+        R: Send + Sync + for<'de> Deserialize<'de> + 'a,
+        F: Fn(AqlLimit) -> AqlBuilder<'a> + Send + Sync + 'a,
+    {
+        Box::pin(stream::unfold(
+            (build_aql, Some(0), VecDeque::new()),
+            move |(build_aql, mut offset, mut results)| async move {
+                loop {
+                    if let Some(result) = results.pop_front() {
+                        return Some((Ok(result), (build_aql, offset, results)));
+                    }
+
+                    let page_offset = offset?;
+                    let aql = build_aql(AqlLimit {
+                        offset: Some(page_offset),
+                        count: page_size,
+                    });
+
+                    match self.send_generic_aql::<R>(&aql).await {
+                        Ok(page) => {
+                            let length = page.results.len() as u64;
+                            offset = if length == 0 || length < page_size {
+                                None
+                            } else {
+                                Some(page_offset + length)
+                            };
+
+                            results.extend(page.results);
+                        }
+                        Err(e) => return Some((Err(e), (build_aql, None, results))),
+                    }
+                }
+            },
+        ))
+    }
+
+    /// Gets the documents whose key starts with `prefix` using a key range scan.
+    async fn get_by_key_prefix(
+        &self,
+        prefix: &str,
+        limit: Option<u64>,
+        return_fields: Option<&Self::Document>,
+    ) -> Result<Vec<Self::Document>, anyhow::Error> {
+        // Prepare AQL.
+        // FOR i IN <collection>
+        //      FILTER i._key >= <lo> && i._key < <hi>
+        //      LIMIT <limit>
+        //      RETURN <return_fields>
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, Self::name());
+        let key_path = DBDocumentField::Key.path();
+        let lo_var = aql.add_variable(&prefix)?.unwrap();
+
+        match DBUuid::prefix_upper_bound(prefix) {
+            Some(hi) => {
+                let hi_var = aql.add_variable(&hi)?.unwrap();
+                aql.filter_step(
+                    format!(
+                        "{}.{} >= {} && {}.{} < {}",
+                        AQL_DOCUMENT_ID, key_path, lo_var, AQL_DOCUMENT_ID, key_path, hi_var
+                    )
+                    .into(),
+                );
+            }
+            None => {
+                aql.filter_step(format!("{}.{} >= {}", AQL_DOCUMENT_ID, key_path, lo_var).into());
+            }
+        }
+
+        if let Some(limit) = limit {
+            aql.limit_step(AqlLimit {
+                offset: None,
+                count: limit,
+            });
+        }
+
+        if let Some(return_fields) = return_fields {
+            aql.return_step_with_fields(AQL_DOCUMENT_ID, return_fields);
+        } else {
+            aql.return_step(AqlReturn::new_document());
+        }
+
+        let result = self.send_aql(&aql).await?;
+
+        Ok(result.results)
+    }
+
     /// Update a list with retries.
     async fn update_list_with_retries(
         &self,
@@ -241,10 +509,7 @@ pub trait DBCollection: Send + Sync {
             format!("{}.{}", AQL_NEW_ID, DBDocumentField::Key.path()).into(),
         ));
 
-        let mut active_keys: HashSet<_> = documents
-            .iter()
-            .map(|v| v.db_key().clone().unwrap())
-            .collect();
+        let mut active_keys: HashSet<_> = documents.iter().map(|v| v.key()).collect();
 
         collection
             .send_generic_aql_with_manual_retries(
@@ -283,6 +548,118 @@ pub trait DBCollection: Send + Sync {
         Ok(())
     }
 
+    /// Updates many documents in a single request. Returns the updated document or the error
+    /// of every document in the same order.
+    ///
+    /// WARN: returns the whole documents.
+    async fn update_many(
+        &self,
+        mut documents: Vec<Self::Document>,
+        merge_objects: bool,
+    ) -> Result<Vec<Result<Self::Document, anyhow::Error>>, anyhow::Error> {
+        for document in &mut documents {
+            if document.db_key().is_none() {
+                panic!(
+                    "You forgot to include the key property in the {} document",
+                    Self::name()
+                );
+            }
+
+            document.touch_timestamps(false);
+
+            if Self::Document::validate_on_write() {
+                document.validate()?;
+            }
+        }
+
+        let ignore_revs = documents.iter().all(|v| v.db_rev().is_none());
+        let mut results: Vec<Option<Result<Self::Document, anyhow::Error>>> =
+            documents.iter().map(|_| None).collect();
+        let mut pending: Vec<usize> = (0..documents.len()).collect();
+
+        // Retry the documents that fail because of a write conflict.
+        while !pending.is_empty() {
+            let batch: Vec<_> = pending.iter().map(|i| &documents[*i]).collect();
+            let response = self
+                .db_info()
+                .update_documents(Self::name(), &batch, merge_objects, ignore_revs)
+                .await?;
+
+            let mut conflicts = Vec::new();
+            for (index, mut value) in pending.into_iter().zip(response) {
+                if value.get("error") == Some(&serde_json::Value::Bool(true)) {
+                    let error_num = value.get("errorNum").and_then(|v| v.as_u64()).unwrap_or(0);
+
+                    if error_num == 1200 {
+                        conflicts.push(index);
+                        continue;
+                    }
+
+                    let message = value
+                        .get("errorMessage")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("<undefined>");
+                    results[index] = Some(Err(anyhow::anyhow!("{} ({})", message, error_num)));
+                } else {
+                    let new = value
+                        .get_mut("new")
+                        .map(serde_json::Value::take)
+                        .unwrap_or_default();
+                    results[index] = Some(serde_json::from_value(new).map_err(|e| e.into()));
+                }
+            }
+
+            pending = conflicts;
+        }
+
+        Ok(results.into_iter().map(|v| v.unwrap()).collect())
+    }
+
+    /// Normalizes every document of `documents` and updates the modified ones with a single
+    /// `update_many`, replacing them by their updated values. Returns the number of updated
+    /// documents or the first error after processing all of them.
+    async fn normalize_page(
+        &self,
+        documents: &mut [Self::Document],
+    ) -> Result<usize, anyhow::Error> {
+        let mut indexes = Vec::new();
+        let mut modified = Vec::new();
+
+        for (index, document) in documents.iter_mut().enumerate() {
+            if document.normalize() {
+                indexes.push(index);
+                modified.push(document.clone());
+            }
+        }
+
+        if modified.is_empty() {
+            return Ok(0);
+        }
+
+        let results = self.update_many(modified, false).await?;
+
+        let mut updated = 0;
+        let mut error = None;
+        for (index, result) in indexes.into_iter().zip(results) {
+            match result {
+                Ok(document) => {
+                    documents[index] = document;
+                    updated += 1;
+                }
+                Err(e) => {
+                    if error.is_none() {
+                        error = Some(e);
+                    }
+                }
+            }
+        }
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(updated),
+        }
+    }
+
     /// Sends an AQL command returning current collection's documents.
     async fn send_aql<'a>(
         &self,
@@ -295,39 +672,46 @@ pub trait DBCollection: Send + Sync {
     async fn send_generic_aql<'a, R: Send + Sync + for<'de> Deserialize<'de>>(
         &self,
         aql: &AqlBuilder<'a>,
+    ) -> Result<AqlResult<R>, anyhow::Error> {
+        self.send_generic_aql_batched(aql, aql.batch_size()).await
+    }
+
+    /// Sends an AQL command fetching the results through the cursor in chunks of `batch_size`
+    /// documents instead of the batch size of the builder.
+    async fn send_generic_aql_batched<'a, R: Send + Sync + for<'de> Deserialize<'de>>(
+        &self,
+        aql: &AqlBuilder<'a>,
+        batch_size: Option<u32>,
     ) -> Result<AqlResult<R>, anyhow::Error> {
         let db_info = self.db_info();
 
-        let batch_size = aql.batch_size();
         let full_count = aql.full_count();
+        let query_cache = aql.query_cache();
         let global_limit = aql.global_limit();
         let handle_write_conflicts = aql.handle_write_conflicts();
 
         let query = aql.build_query();
 
-        'outer: loop {
-            let aql_query = AqlQuery::builder()
-                .query(&query)
-                .bind_vars(aql.vars.clone())
-                .options(AqlOptions::builder().full_count(full_count).build());
-
-            let aql_query = if let Some(batch_size) = batch_size {
-                aql_query.batch_size(batch_size).build()
-            } else {
-                aql_query.build()
-            };
-
-            let mut response_cursor = match db_info.database.aql_query_batch(aql_query).await {
-                Ok(v) => v,
-                Err(e) => {
-                    if handle_write_conflicts {
-                        check_client_is_write_conflict(e)?;
-                        continue 'outer;
-                    } else {
-                        return Err(e.into());
-                    }
-                }
-            };
+        let send_query = || async {
+            let mut response_cursor = db_info
+                .with_reconnect(|database| {
+                    let aql_query = AqlQuery::builder()
+                        .query(&query)
+                        .bind_vars(aql.vars.clone())
+                        .options(AqlOptions::builder().full_count(full_count).build());
+
+                    let aql_query = match (batch_size, query_cache) {
+                        (Some(batch_size), Some(query_cache)) => {
+                            aql_query.batch_size(batch_size).cache(query_cache).build()
+                        }
+                        (Some(batch_size), None) => aql_query.batch_size(batch_size).build(),
+                        (None, Some(query_cache)) => aql_query.cache(query_cache).build(),
+                        (None, None) => aql_query.build(),
+                    };
+
+                    async move { database.aql_query_batch(aql_query).await }
+                })
+                .await?;
 
             if response_cursor.more {
                 let mut results: Vec<R> = Vec::with_capacity(global_limit as usize);
@@ -336,17 +720,11 @@ pub trait DBCollection: Send + Sync {
 
                     if response_cursor.more {
                         let id = response_cursor.id.as_ref().unwrap();
-                        response_cursor = match db_info.database.aql_next_batch(id.as_str()).await {
-                            Ok(v) => v,
-                            Err(e) => {
-                                if handle_write_conflicts {
-                                    check_client_is_write_conflict(e)?;
-                                    continue 'outer;
-                                } else {
-                                    return Err(e.into());
-                                }
-                            }
-                        };
+                        response_cursor = db_info
+                            .with_reconnect(|database| async move {
+                                database.aql_next_batch(id.as_str()).await
+                            })
+                            .await?;
                     } else {
                         break;
                     }
@@ -355,10 +733,94 @@ pub trait DBCollection: Send + Sync {
                 response_cursor.result = results;
             }
 
-            return Ok(response_cursor.into());
+            Ok(response_cursor)
+        };
+
+        let response_cursor = if handle_write_conflicts {
+            retry_on_write_conflict(send_query).await?
+        } else {
+            send_query().await?
+        };
+
+        Ok(response_cursor.into())
+    }
+
+    /// Sends an AQL command returning the untyped cursor response, i.e. `result` with the
+    /// results of every batch along with `extra` including the `stats` of the query.
+    async fn send_raw_aql<'a>(
+        &self,
+        aql: &AqlBuilder<'a>,
+    ) -> Result<serde_json::Value, anyhow::Error> {
+        let db_info = self.db_info();
+        let client = db_info.connection().session();
+        let url = format!("{}_api/cursor", db_info.database().url().as_str());
+
+        let body = aql.build_cursor_body();
+        let mut request = client.client.post(&url).json(&body);
+        let mut response: Option<serde_json::Value> = None;
+
+        loop {
+            let batch = request
+                .basic_auth(&db_info.username, Some(&db_info.password))
+                .send()
+                .await?;
+
+            let mut batch: serde_json::Value = match batch.status().as_u16() {
+                200 | 201 => batch.json().await?,
+                _ => {
+                    let text = batch
+                        .text()
+                        .await
+                        .unwrap_or_else(|_| "<undefined>".to_string());
+                    return Err(anyhow::anyhow!(text));
+                }
+            };
+
+            let has_more = batch["hasMore"].as_bool().unwrap_or(false);
+            let id = batch["id"].as_str().map(|v| v.to_string());
+
+            // Append the results of the next batches to the first one.
+            match &mut response {
+                Some(response) => {
+                    if let (Some(results), Some(batch_results)) = (
+                        response["result"].as_array_mut(),
+                        batch["result"].as_array_mut(),
+                    ) {
+                        results.append(batch_results);
+                    }
+
+                    if !batch["extra"].is_null() {
+                        response["extra"] = batch["extra"].take();
+                    }
+
+                    response["hasMore"] = has_more.into();
+                }
+                None => response = Some(batch),
+            }
+
+            match id {
+                Some(id) if has_more => {
+                    request = client.client.put(format!("{}/{}", url, id));
+                }
+                _ => return Ok(response.unwrap()),
+            }
         }
     }
 
+    /// Sends a hand-written AQL query binding `@@collection` to the current collection.
+    async fn send_scoped_aql<R: Send + Sync + for<'de> Deserialize<'de>>(
+        &self,
+        query: &str,
+        mut bind_vars: HashMap<&str, serde_json::Value>,
+    ) -> Result<Vec<R>, anyhow::Error> {
+        bind_vars.insert(AQL_COLLECTION_ID, Self::name().into());
+
+        Ok(self
+            .db_info()
+            .send_aql_with_retries(query, bind_vars)
+            .await?)
+    }
+
     /// Sends an AQL command applying manual retries and returning current collection's documents.
     async fn send_aql_with_manual_retries<'a, F>(
         &self,
@@ -408,3 +870,63 @@ pub trait DBCollection: Send + Sync {
         Ok(())
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The position of `stream_keys` in the cursor.
+enum KeyCursor {
+    Start(String),
+    Next(String),
+    Done,
+}
+
+/// Reads the next batch of keys of a cursor returning them along with the following position.
+async fn read_key_batch<K: for<'de> Deserialize<'de>>(
+    db_info: &DBInfo,
+    cursor: &KeyCursor,
+) -> Result<(Vec<K>, KeyCursor), anyhow::Error> {
+    let client = db_info.connection().session();
+    let url = format!("{}_api/cursor", db_info.database().url().as_str());
+    let request = match cursor {
+        KeyCursor::Start(query) => client.client.post(&url).json(&serde_json::json!({
+            "query": query,
+            "batchSize": STREAM_KEYS_BATCH_SIZE,
+        })),
+        KeyCursor::Next(id) => client.client.put(format!("{}/{}", url, id)),
+        KeyCursor::Done => return Ok((Vec::new(), KeyCursor::Done)),
+    };
+
+    let response = request
+        .basic_auth(&db_info.username, Some(&db_info.password))
+        .send()
+        .await?;
+
+    let mut batch: serde_json::Value = match response.status().as_u16() {
+        200 | 201 => response.json().await?,
+        _ => {
+            let text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "<undefined>".to_string());
+            return Err(anyhow::anyhow!(text));
+        }
+    };
+
+    let keys = serde_json::from_value(batch["result"].take())?;
+    let next_cursor = match batch["id"].as_str() {
+        Some(id) if batch["hasMore"].as_bool().unwrap_or(false) => KeyCursor::Next(id.to_string()),
+        _ => KeyCursor::Done,
+    };
+
+    Ok((keys, next_cursor))
+}
+
+#[derive(Deserialize)]
+struct DocumentWithRev<T> {
+    #[serde(rename = "D")]
+    document: T,
+    #[serde(rename = "R")]
+    rev: ArcStr,
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use crate::traits::{DBCollection, DBDocument};
+use crate::types::DBInfo;
+
+/// A collection that can be built only with the database information.
+pub trait DBCollectionFromInfo: DBCollection {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    fn from_db_info(db_info: &Arc<DBInfo>) -> Arc<Self>;
+}
+
+/// Links a document type to its collection, so it can be reached from a `DBInfo`.
+pub trait DBDocumentCollection: DBDocument {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Gets the collection of the document.
+    fn collection(db_info: &Arc<DBInfo>) -> Arc<Self::Collection>;
+}
+
+impl<T: DBDocument> DBDocumentCollection for T
+where
+    T::Collection: DBCollectionFromInfo,
+{
+    fn collection(db_info: &Arc<DBInfo>) -> Arc<Self::Collection> {
+        T::Collection::from_db_info(db_info)
+    }
+}
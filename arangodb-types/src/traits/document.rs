@@ -35,6 +35,32 @@ pub trait DBDocument:
 
     fn db_key(&self) -> &Option<Self::Key>;
 
+    /// Gets the key of the document.
+    ///
+    /// # Panics
+    /// Panics if the key is missing.
+    fn key(&self) -> Self::Key {
+        match self.db_key() {
+            Some(v) => v.clone(),
+            None => panic!("Missing db_key in document"),
+        }
+    }
+
+    /// Gets the key of the document or fails if it is missing.
+    fn try_key(&self) -> Result<Self::Key, anyhow::Error> {
+        match self.db_key() {
+            Some(v) => Ok(v.clone()),
+            None => Err(anyhow::anyhow!("Missing db_key in document")),
+        }
+    }
+
+    /// Gets the document handle, i.e. `<collection>/<key>`, to use as `_id` in raw queries.
+    fn document_handle(&self) -> Option<String> {
+        self.db_key()
+            .as_ref()
+            .map(|key| format!("{}/{}", Self::Collection::name(), key.to_string()))
+    }
+
     fn db_rev(&self) -> &Option<ArcStr>;
 
     /// Whether all the fields are missing or not.
@@ -46,6 +72,16 @@ pub trait DBDocument:
     /// Whether all the fields are null or missing or not.
     fn is_all_null_or_missing(&self) -> bool;
 
+    /// Whether the document must be validated before being inserted or updated.
+    fn validate_on_write() -> bool {
+        false
+    }
+
+    /// Gets a copy of the document to roll back in-memory changes later with `restore`.
+    fn snapshot(&self) -> Self {
+        self.clone()
+    }
+
     // SETTERS ----------------------------------------------------------------
 
     fn set_db_key(&mut self, value: Option<Self::Key>);
@@ -55,6 +91,37 @@ pub trait DBDocument:
     /// Maps all fields that contain a value into a null.
     fn map_values_to_null(&mut self);
 
+    /// Reverts the document to a previous `snapshot`.
+    fn restore(&mut self, snapshot: Self) {
+        *self = snapshot;
+    }
+
+    /// Checks the invariants of the document.
+    fn validate(&self) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+
+    /// Rewrites the fields of the document into their canonical form.
+    /// Returns whether the document has changed.
+    fn normalize(&mut self) -> bool {
+        false
+    }
+
+    /// Computes the deterministic key of the document from its fields, if any.
+    /// It is set as the key of the document on insert, that fails if it cannot be computed.
+    fn compute_key(&self) -> Result<Option<Self::Key>, anyhow::Error> {
+        Ok(None)
+    }
+
+    /// Assigns a newly generated key to the document when it has none.
+    /// It is called on insert after `compute_key`.
+    fn assign_key(&mut self) {}
+
+    /// Sets the timestamps of the document on every write. `is_new` is true for inserts,
+    /// that also set the creation timestamp.
+    #[allow(unused_variables)]
+    fn touch_timestamps(&mut self, is_new: bool) {}
+
     /// Inserts a new document.
     ///
     /// WARN: returns the whole document.
@@ -63,6 +130,17 @@ pub trait DBDocument:
         overwrite: bool,
         collection: &Self::Collection,
     ) -> Result<Self, anyhow::Error> {
+        if let Some(key) = self.compute_key()? {
+            self.set_db_key(Some(key));
+        }
+
+        self.assign_key();
+        self.touch_timestamps(true);
+
+        if Self::validate_on_write() {
+            self.validate()?;
+        }
+
         let db_collection = collection.db_collection().await?;
 
         loop {
@@ -111,6 +189,17 @@ pub trait DBDocument:
         overwrite: bool,
         collection: &Self::Collection,
     ) -> Result<Self::Key, anyhow::Error> {
+        if let Some(key) = self.compute_key()? {
+            self.set_db_key(Some(key));
+        }
+
+        self.assign_key();
+        self.touch_timestamps(true);
+
+        if Self::validate_on_write() {
+            self.validate()?;
+        }
+
         let db_collection = collection.db_collection().await?;
 
         loop {
@@ -142,7 +231,7 @@ pub trait DBDocument:
             };
 
             match response {
-                Ok(_) => return Ok(self.db_key().clone().unwrap()),
+                Ok(_) => return Ok(self.key()),
                 Err(error) => {
                     check_client_is_write_conflict(error)?;
                 }
@@ -158,6 +247,13 @@ pub trait DBDocument:
         merge_objects: bool,
         collection: &Self::Collection,
     ) -> Result<Self, anyhow::Error> {
+        let mut document = self.clone();
+        document.touch_timestamps(false);
+
+        if Self::validate_on_write() {
+            document.validate()?;
+        }
+
         let db_collection = collection.db_collection().await?;
 
         let ignore_rev = self.db_rev().is_none();
@@ -178,7 +274,7 @@ pub trait DBDocument:
             let response = db_collection
                 .update_document(
                     &key,
-                    self.clone(),
+                    document.clone(),
                     UpdateOptions::builder()
                         .merge_objects(merge_objects)
                         .keep_null(false)
@@ -206,6 +302,13 @@ pub trait DBDocument:
         merge_objects: bool,
         collection: &Self::Collection,
     ) -> Result<(), anyhow::Error> {
+        let mut document = self.clone();
+        document.touch_timestamps(false);
+
+        if Self::validate_on_write() {
+            document.validate()?;
+        }
+
         let db_collection = collection.db_collection().await?;
 
         let ignore_rev = self.db_rev().is_none();
@@ -226,7 +329,7 @@ pub trait DBDocument:
             let response = db_collection
                 .update_document(
                     &key,
-                    self.clone(),
+                    document.clone(),
                     UpdateOptions::builder()
                         .merge_objects(merge_objects)
                         .keep_null(false)
@@ -253,6 +356,12 @@ pub trait DBDocument:
         merge_objects: bool,
         collection: &Self::Collection,
     ) -> Result<Self, anyhow::Error> {
+        self.touch_timestamps(false);
+
+        if Self::validate_on_write() {
+            self.validate()?;
+        }
+
         let db_collection = collection.db_collection().await?;
 
         loop {
@@ -288,6 +397,12 @@ pub trait DBDocument:
         merge_objects: bool,
         collection: &Self::Collection,
     ) -> Result<Self::Key, anyhow::Error> {
+        self.touch_timestamps(false);
+
+        if Self::validate_on_write() {
+            self.validate()?;
+        }
+
         let db_collection = collection.db_collection().await?;
 
         loop {
@@ -307,7 +422,7 @@ pub trait DBDocument:
                 .await;
 
             match response {
-                Ok(_) => return Ok(self.db_key().clone().unwrap()),
+                Ok(_) => return Ok(self.key()),
                 Err(error) => {
                     check_client_is_write_conflict(error)?;
                 }
@@ -3,6 +3,8 @@ use std::hash::Hash;
 
 use serde::{Deserialize, Serialize};
 
+use crate::traits::DBDocument;
+
 pub trait APIDocument {
     type Id: Debug
         + ToString
@@ -19,3 +21,25 @@ pub trait APIDocument {
 
     fn id(&self) -> &Option<Self::Id>;
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Converts a list of DB documents into API documents, e.g. the results of a query.
+pub fn to_api_vec<D, A>(documents: Vec<D>) -> Vec<A>
+where
+    D: DBDocument,
+    A: APIDocument + From<D>,
+{
+    documents.into_iter().map(A::from).collect()
+}
+
+/// Converts a list of API documents into DB documents.
+pub fn to_db_vec<A, D>(documents: Vec<A>) -> Vec<D>
+where
+    A: APIDocument,
+    D: DBDocument + From<A>,
+{
+    documents.into_iter().map(D::from).collect()
+}
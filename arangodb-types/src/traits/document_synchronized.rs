@@ -2,6 +2,10 @@ use crate::traits::DBDocument;
 use crate::types::{DBMutex, NullableOption};
 
 pub trait DBSynchronizedDocument<'a>: DBDocument {
+    /// Whether the mutex acquire queries lock the collection exclusively.
+    /// Set it with the `sync_exclusive` option of the model.
+    const EXCLUSIVE_MUTEX: bool = false;
+
     // GETTERS ----------------------------------------------------------------
 
     /// The key of the document that represents the collection in the config collection.
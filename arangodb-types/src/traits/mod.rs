@@ -1,16 +1,24 @@
+pub use apply_patch::*;
 pub use aql_mapping::*;
+pub use cipher::*;
 pub use collection::*;
 pub use collection_edge::*;
+pub use collection_from_info::*;
 pub use document::*;
 pub use document_api::*;
 pub use document_edge::*;
 pub use document_synchronized::*;
+pub use schema_migration::*;
 
+mod apply_patch;
 mod aql_mapping;
+mod cipher;
 mod collection;
 mod collection_edge;
+mod collection_from_info;
 mod document;
 mod document_api;
 mod document_edge;
 mod document_synchronized;
+mod schema_migration;
 pub mod utils;
@@ -0,0 +1,9 @@
+use crate::traits::DBDocument;
+
+/// Upgrades the documents of a model declared with the `schema_version` option.
+pub trait DBSchemaMigration: DBDocument {
+    // METHODS ----------------------------------------------------------------
+
+    /// Upgrades the document from `version` to the next one.
+    fn migrate_from(&mut self, version: u32) -> Result<(), anyhow::Error>;
+}
@@ -9,3 +9,20 @@ pub fn check_client_is_write_conflict(error: ClientError) -> Result<ClientError,
         _ => Err(error),
     }
 }
+
+pub fn check_client_is_connection_error(error: ClientError) -> Result<ClientError, ClientError> {
+    match &error {
+        ClientError::HttpClient(_) => Ok(error),
+        _ => Err(error),
+    }
+}
+
+pub fn check_client_is_auth_expired(error: ClientError) -> Result<ClientError, ClientError> {
+    match &error {
+        ClientError::Arango(e) => match e.code() {
+            401 => Ok(error),
+            _ => Err(error),
+        },
+        _ => Err(error),
+    }
+}
@@ -1,23 +1,64 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+#[cfg(feature = "tokio")]
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+#[cfg(feature = "tokio")]
+use std::time::Duration;
 
 use arangors::uclient::reqwest::ReqwestClient;
 use arangors::{ClientError, Connection, GenericConnection};
+use futures_util::lock::Mutex;
+#[cfg(feature = "tokio")]
+use futures_util::stream::{self, Stream};
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::traits::utils::check_client_is_write_conflict;
+use crate::constants::MIGRATIONS_COLLECTION;
+#[cfg(feature = "tokio")]
+use crate::constants::TAIL_POLL_INTERVAL;
+#[cfg(feature = "tokio")]
+use crate::traits::utils::check_client_is_connection_error;
+use crate::traits::utils::{check_client_is_auth_expired, check_client_is_write_conflict};
 
 pub type Database = arangors::Database<ReqwestClient>;
 pub type Collection = arangors::Collection<ReqwestClient>;
 
+const DOCUMENT_COLLECTION_TYPE: u8 = 2;
+const EDGE_COLLECTION_TYPE: u8 = 3;
+#[cfg(feature = "tokio")]
+const WAL_DOCUMENT_MARKER: u16 = 2300;
+#[cfg(feature = "tokio")]
+const WAL_REMOVE_MARKER: u16 = 2302;
+
 /// The database information.
+///
+/// Breaking change: `connection` and `database` are no longer public fields because `reconnect`
+/// replaces them when the JWT session expires. Use the `connection()` and `database()` getters,
+/// that return owned clones like `DBCollection::database` does now.
+///
+/// Only `with_reconnect`, `send_aql_with_retries`, `DBCollection::db_collection`, hence the
+/// `DBDocument` methods, and the AQL methods of `DBCollection` reconnect automatically. The other
+/// methods of `DBInfo` fail with a 401 error after the session expires until `reconnect` is
+/// called, and a `Collection` fetched before the expiration keeps failing until it is fetched
+/// again.
 #[derive(Debug)]
 pub struct DBInfo {
     pub username: Cow<'static, str>,
     pub password: Cow<'static, str>,
-    pub connection: GenericConnection<ReqwestClient>,
-    pub database: Database,
+    session: RwLock<DBSession>,
+    reconnect_lock: Mutex<()>,
+}
+
+/// The connection and database of a `DBInfo`, that are replaced together on `reconnect`.
+#[derive(Debug)]
+struct DBSession {
+    connection: GenericConnection<ReqwestClient>,
+    database: Database,
+    /// The number of times the session has been replaced.
+    generation: u64,
 }
 
 impl DBInfo {
@@ -31,17 +72,40 @@ impl DBInfo {
     ) -> Result<DBInfo, anyhow::Error> {
         let connection = Connection::establish_jwt(&url, &username, &password).await?;
 
+        Self::connect_to_database(connection, database, username, password).await
+    }
+
+    /// Like `connect` but retries up to `max_attempts` times while the server is not reachable,
+    /// doubling the `delay` after each failed attempt.
+    #[cfg(feature = "tokio")]
+    pub async fn connect_with_retries(
+        url: Cow<'static, str>,
+        database: Cow<'static, str>,
+        username: Cow<'static, str>,
+        password: Cow<'static, str>,
+        max_attempts: usize,
+        delay: Duration,
+    ) -> Result<DBInfo, anyhow::Error> {
+        let connection = retry_on_connection_error(max_attempts, delay, || {
+            Connection::establish_jwt(&url, &username, &password)
+        })
+        .await?;
+
+        Self::connect_to_database(connection, database, username, password).await
+    }
+
+    async fn connect_to_database(
+        connection: GenericConnection<ReqwestClient>,
+        database: Cow<'static, str>,
+        username: Cow<'static, str>,
+        password: Cow<'static, str>,
+    ) -> Result<DBInfo, anyhow::Error> {
         let database = match connection.create_database(&database).await {
             Ok(v) => v,
             Err(_) => connection.db(&database).await?,
         };
 
-        Ok(DBInfo {
-            username,
-            password,
-            connection,
-            database,
-        })
+        Ok(Self::new(username, password, connection, database))
     }
 
     pub fn new(
@@ -53,24 +117,116 @@ impl DBInfo {
         Self {
             username,
             password,
-            connection,
-            database,
+            session: RwLock::new(DBSession {
+                connection,
+                database,
+                generation: 0,
+            }),
+            reconnect_lock: Mutex::new(()),
         }
     }
 
+    // GETTERS ----------------------------------------------------------------
+
+    /// Gets the current connection, that changes after `reconnect`.
+    pub fn connection(&self) -> GenericConnection<ReqwestClient> {
+        self.session.read().unwrap().connection.clone()
+    }
+
+    /// Gets the current database, that changes after `reconnect`.
+    pub fn database(&self) -> Database {
+        self.session.read().unwrap().database.clone()
+    }
+
     // METHODS ----------------------------------------------------------------
 
+    /// Re-establishes the JWT session with the stored credentials, replacing the connection
+    /// and the database.
+    pub async fn reconnect(&self) -> Result<(), ClientError> {
+        let generation = self.session.read().unwrap().generation;
+        self.reconnect_generation(generation).await
+    }
+
+    /// Executes `action` with the current database. If it fails because the JWT session has
+    /// expired, reconnects and executes it once more with the new database.
+    pub async fn with_reconnect<T, F, Fut>(&self, mut action: F) -> Result<T, ClientError>
+    where
+        F: FnMut(Database) -> Fut,
+        Fut: Future<Output = Result<T, ClientError>>,
+    {
+        let generation = AtomicU64::new(0);
+
+        retry_on_auth_expired(
+            || {
+                let session = self.session.read().unwrap();
+                generation.store(session.generation, Ordering::Relaxed);
+                action(session.database.clone())
+            },
+            || self.reconnect_generation(generation.load(Ordering::Relaxed)),
+        )
+        .await
+    }
+
+    /// Replaces the session of `generation` unless another caller has already replaced it, so
+    /// the requests that fail at the same time with an expired session reconnect only once.
+    async fn reconnect_generation(&self, generation: u64) -> Result<(), ClientError> {
+        let _guard = self.reconnect_lock.lock().await;
+
+        let (url, name) = {
+            let session = self.session.read().unwrap();
+
+            if session.generation != generation {
+                return Ok(());
+            }
+
+            (
+                session.connection.url().to_string(),
+                session.database.name().to_string(),
+            )
+        };
+
+        let connection = Connection::establish_jwt(&url, &self.username, &self.password).await?;
+        let database = connection.db(&name).await?;
+
+        *self.session.write().unwrap() = DBSession {
+            connection,
+            database,
+            generation: generation + 1,
+        };
+
+        Ok(())
+    }
+
+    /// Creates a new database with the same credentials. Fails if it already exists.
+    pub async fn create_database(&self, name: &str) -> Result<Database, anyhow::Error> {
+        Ok(self.connection().create_database(name).await?)
+    }
+
+    /// Drops a database. The database of this `DBInfo` cannot be dropped.
+    pub async fn drop_database(&self, name: &str) -> Result<(), anyhow::Error> {
+        if name == self.database().name() {
+            return Err(anyhow::anyhow!(
+                "Cannot drop the database {} while it is in use",
+                name
+            ));
+        }
+
+        self.connection().drop_database(name).await?;
+        Ok(())
+    }
+
     pub async fn send_aql_with_retries<T: for<'de> Deserialize<'de>>(
         &self,
         query: &str,
         bind_vars: HashMap<&str, serde_json::Value>,
     ) -> Result<Vec<T>, ClientError> {
-        loop {
-            match self.database.aql_bind_vars(query, bind_vars.clone()).await {
-                Ok(v) => return Ok(v),
-                Err(e) => check_client_is_write_conflict(e)?,
-            };
-        }
+        retry_on_write_conflict(|| {
+            self.with_reconnect(|database| {
+                let bind_vars = bind_vars.clone();
+                async move { database.aql_bind_vars(query, bind_vars).await }
+            })
+        })
+        .await
     }
 
     pub async fn add_aql_function(
@@ -79,10 +235,13 @@ impl DBInfo {
         code: &str,
         is_deterministic: bool,
     ) -> Result<(), anyhow::Error> {
-        let client = self.connection.session();
+        let client = self.connection().session();
         let response = client
             .client
-            .post(format!("{}_api/aqlfunction", self.database.url().as_str()))
+            .post(format!(
+                "{}_api/aqlfunction",
+                self.database().url().as_str()
+            ))
             .basic_auth(&self.username, Some(&self.password))
             .json(&AddFunctionRequest {
                 name,
@@ -104,13 +263,492 @@ impl DBInfo {
         }
     }
 
+    /// Registers an AQL function replacing any previous one with the same name.
+    pub async fn register_aql_function(&self, function: &AqlFunction) -> Result<(), anyhow::Error> {
+        self.add_aql_function(
+            &function.full_name(),
+            &function.code,
+            function.deterministic,
+        )
+        .await
+    }
+
+    /// Registers an AQL function unless it is already registered with the same code, so it can
+    /// be called on every deploy. Returns whether the function has been registered.
+    pub async fn ensure_aql_function(&self, function: &AqlFunction) -> Result<bool, anyhow::Error> {
+        let full_name = function.full_name();
+        let functions = self.get_aql_functions(&function.namespace).await?;
+        let is_registered = functions.iter().any(|v| {
+            v.name.eq_ignore_ascii_case(&full_name)
+                && v.code == function.code
+                && v.is_deterministic == function.deterministic
+        });
+
+        if is_registered {
+            return Ok(false);
+        }
+
+        self.register_aql_function(function).await?;
+
+        Ok(true)
+    }
+
+    async fn get_aql_functions(
+        &self,
+        namespace: &str,
+    ) -> Result<Vec<AqlFunctionResponse>, anyhow::Error> {
+        let client = self.connection().session();
+        let response = client
+            .client
+            .get(format!(
+                "{}_api/aqlfunction?namespace={}",
+                self.database().url().as_str(),
+                urlencoding::encode(namespace),
+            ))
+            .basic_auth(&self.username, Some(&self.password))
+            .send()
+            .await?;
+
+        match response.status().as_u16() {
+            200 => Ok(response.json::<AqlFunctionListResponse>().await?.result),
+            _ => {
+                let text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "<undefined>".to_string());
+                Err(anyhow::anyhow!(text))
+            }
+        }
+    }
+
+    /// Creates a collection if it does not exist yet. If it already exists, its type and key
+    /// generator must be compatible with the given options.
+    pub async fn ensure_collection(
+        &self,
+        name: &str,
+        options: CollectionOptions,
+    ) -> Result<(), anyhow::Error> {
+        let collection_type = if options.is_edge {
+            EDGE_COLLECTION_TYPE
+        } else {
+            DOCUMENT_COLLECTION_TYPE
+        };
+
+        let client = self.connection().session();
+        let response = client
+            .client
+            .post(format!("{}_api/collection", self.database().url().as_str()))
+            .basic_auth(&self.username, Some(&self.password))
+            .json(&CreateCollectionRequest {
+                name,
+                collection_type,
+                is_system: options.is_system,
+                key_options: options.key_options.as_ref(),
+                number_of_shards: options.number_of_shards,
+                shard_keys: options.shard_keys.as_deref(),
+                replication_factor: options.replication_factor,
+            })
+            .send()
+            .await?;
+
+        match response.status().as_u16() {
+            200 => Ok(()),
+            409 => {
+                let properties = self.get_collection_properties(name).await?;
+
+                if properties.collection_type != collection_type {
+                    return Err(anyhow::anyhow!(
+                        "The collection {} already exists with a different type",
+                        name
+                    ));
+                }
+
+                if let Some(key_options) = &options.key_options {
+                    if properties.key_options.generator != key_options.generator {
+                        return Err(anyhow::anyhow!(
+                            "The collection {} already exists with a different key generator",
+                            name
+                        ));
+                    }
+                }
+
+                Ok(())
+            }
+            _ => {
+                let text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "<undefined>".to_string());
+                Err(anyhow::anyhow!(text))
+            }
+        }
+    }
+
+    async fn get_collection_properties(
+        &self,
+        name: &str,
+    ) -> Result<CollectionPropertiesResponse, anyhow::Error> {
+        let client = self.connection().session();
+        let response = client
+            .client
+            .get(format!(
+                "{}_api/collection/{}/properties",
+                self.database().url().as_str(),
+                urlencoding::encode(name),
+            ))
+            .basic_auth(&self.username, Some(&self.password))
+            .send()
+            .await?;
+
+        match response.status().as_u16() {
+            200 => Ok(response.json().await?),
+            _ => {
+                let text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "<undefined>".to_string());
+                Err(anyhow::anyhow!(text))
+            }
+        }
+    }
+
+    /// Creates an index in a collection if it does not exist yet. `index` is the definition
+    /// of the index as expected by the `_api/index` endpoint, e.g. `{"type": "persistent",
+    /// "fields": ["N"]}`.
+    pub async fn ensure_index(
+        &self,
+        collection: &str,
+        index: &serde_json::Value,
+    ) -> Result<(), anyhow::Error> {
+        let client = self.connection().session();
+        let response = client
+            .client
+            .post(format!(
+                "{}_api/index?collection={}",
+                self.database().url().as_str(),
+                urlencoding::encode(collection),
+            ))
+            .basic_auth(&self.username, Some(&self.password))
+            .json(index)
+            .send()
+            .await?;
+
+        match response.status().as_u16() {
+            200 | 201 => Ok(()),
+            _ => {
+                let text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "<undefined>".to_string());
+                Err(anyhow::anyhow!(text))
+            }
+        }
+    }
+
+    /// Sets the mode of the query results cache of the server, that is shared by every database.
+    /// Cached results are invalidated whenever any collection read by the query is modified, so
+    /// it only pays off for read-heavy collections. Queries that modify documents or that use
+    /// non-deterministic functions are never cached. Use `AqlBuilder::set_query_cache` to choose
+    /// the queries cached with the `demand` mode.
+    pub async fn set_query_cache_mode(&self, mode: AqlQueryCacheMode) -> Result<(), anyhow::Error> {
+        let client = self.connection().session();
+        let response = client
+            .client
+            .put(format!(
+                "{}_api/query-cache/properties",
+                self.database().url().as_str()
+            ))
+            .basic_auth(&self.username, Some(&self.password))
+            .json(&serde_json::json!({ "mode": mode }))
+            .send()
+            .await?;
+
+        match response.status().as_u16() {
+            200 => Ok(()),
+            _ => {
+                let text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "<undefined>".to_string());
+                Err(anyhow::anyhow!(text))
+            }
+        }
+    }
+
+    /// Updates many documents of a collection in a single request. Returns the raw result of
+    /// every document, either the updated document in `new` or an error, in the same order.
+    pub async fn update_documents<T: Serialize>(
+        &self,
+        collection: &str,
+        documents: &[T],
+        merge_objects: bool,
+        ignore_revs: bool,
+    ) -> Result<Vec<serde_json::Value>, anyhow::Error> {
+        let client = self.connection().session();
+        let response = client
+            .client
+            .patch(format!(
+                "{}_api/document/{}?mergeObjects={}&keepNull=false&returnNew=true&ignoreRevs={}",
+                self.database().url().as_str(),
+                urlencoding::encode(collection),
+                merge_objects,
+                ignore_revs,
+            ))
+            .basic_auth(&self.username, Some(&self.password))
+            .json(documents)
+            .send()
+            .await?;
+
+        match response.status().as_u16() {
+            200..=202 => Ok(response.json().await?),
+            _ => {
+                let text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "<undefined>".to_string());
+                Err(anyhow::anyhow!(text))
+            }
+        }
+    }
+
+    /// Runs the steps of a migration in order unless a migration with the same `name` has
+    /// already been applied. Applied migrations are recorded in a system collection so re-runs
+    /// are no-ops. Returns whether the migration has been applied in this call.
+    ///
+    /// ArangoDB cannot run collection or index operations inside a transaction, so if a step
+    /// fails the migration is not recorded and the whole migration runs again next time.
+    /// Therefore AQL steps must be idempotent too.
+    pub async fn run_migration(
+        &self,
+        name: &str,
+        steps: Vec<MigrationStep>,
+    ) -> Result<bool, anyhow::Error> {
+        self.ensure_collection(
+            MIGRATIONS_COLLECTION,
+            CollectionOptions {
+                is_system: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        // FOR i IN <collection>
+        //     FILTER i._key == <name>
+        //     RETURN i._key
+        let mut bind_vars = HashMap::new();
+        bind_vars.insert("@collection", MIGRATIONS_COLLECTION.into());
+        bind_vars.insert("name", name.into());
+
+        let applied: Vec<String> = self
+            .send_aql_with_retries(
+                "FOR i IN @@collection FILTER i._key == @name RETURN i._key",
+                bind_vars.clone(),
+            )
+            .await?;
+
+        if !applied.is_empty() {
+            return Ok(false);
+        }
+
+        for step in steps {
+            match step {
+                MigrationStep::EnsureCollection { name, options } => {
+                    self.ensure_collection(&name, options).await?;
+                }
+                MigrationStep::EnsureIndex { collection, index } => {
+                    self.ensure_index(&collection, &index).await?;
+                }
+                MigrationStep::RunAql { query, bind_vars } => {
+                    let bind_vars = bind_vars
+                        .iter()
+                        .map(|(k, v)| (k.as_str(), v.clone()))
+                        .collect();
+
+                    self.send_aql_with_retries::<serde_json::Value>(&query, bind_vars)
+                        .await?;
+                }
+            }
+        }
+
+        // UPSERT { _key: <name> }
+        //     INSERT { _key: <name>, appliedAt: DATE_NOW() }
+        //     UPDATE {}
+        //     IN <collection>
+        self.send_aql_with_retries::<serde_json::Value>(
+            "UPSERT { _key: @name } INSERT { _key: @name, appliedAt: DATE_NOW() } UPDATE {} IN @@collection",
+            bind_vars,
+        )
+        .await?;
+
+        Ok(true)
+    }
+
+    /// Gets the last tick of the write-ahead log, i.e. the tick to start tailing from to get
+    /// only the changes made from now on.
+    pub async fn last_tick(&self) -> Result<u64, anyhow::Error> {
+        let client = self.connection().session();
+        let response = client
+            .client
+            .get(format!(
+                "{}_api/wal/lastTick",
+                self.database().url().as_str()
+            ))
+            .basic_auth(&self.username, Some(&self.password))
+            .send()
+            .await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let response: LastTickResponse = response.json().await?;
+                Ok(response.tick.parse()?)
+            }
+            _ => {
+                let text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "<undefined>".to_string());
+                Err(anyhow::anyhow!(text))
+            }
+        }
+    }
+
+    /// Tails the write-ahead log returning the changes of the documents of `collection` made
+    /// after `from_tick`. The stream polls the server for new changes and never ends unless
+    /// there is an error, which is returned as its last item.
+    #[cfg(feature = "tokio")]
+    pub async fn tail(
+        &self,
+        collection: &str,
+        from_tick: u64,
+    ) -> Result<impl Stream<Item = Result<ChangeEvent, anyhow::Error>> + '_, anyhow::Error> {
+        let collection_id = self
+            .get_collection_properties(collection)
+            .await?
+            .globally_unique_id;
+
+        let state = (from_tick, VecDeque::new(), false);
+        Ok(stream::unfold(
+            state,
+            move |(mut from_tick, mut events, mut finished)| {
+                let collection_id = collection_id.clone();
+
+                async move {
+                    loop {
+                        if let Some(event) = events.pop_front() {
+                            return Some((Ok(event), (from_tick, events, finished)));
+                        }
+
+                        if finished {
+                            return None;
+                        }
+
+                        match self.tail_chunk(&collection_id, from_tick).await {
+                            Ok((chunk, next_tick, check_more)) => {
+                                events.extend(chunk);
+                                from_tick = next_tick;
+
+                                if !check_more && events.is_empty() {
+                                    tokio::time::sleep(Duration::from_millis(TAIL_POLL_INTERVAL))
+                                        .await;
+                                }
+                            }
+                            Err(e) => {
+                                finished = true;
+                                return Some((Err(e), (from_tick, events, finished)));
+                            }
+                        }
+                    }
+                }
+            },
+        ))
+    }
+
+    /// Reads a chunk of the write-ahead log after `from_tick` returning the changes of the
+    /// collection, the tick to continue from and whether there are more changes to read.
+    #[cfg(feature = "tokio")]
+    async fn tail_chunk(
+        &self,
+        collection_id: &str,
+        from_tick: u64,
+    ) -> Result<(Vec<ChangeEvent>, u64, bool), anyhow::Error> {
+        let client = self.connection().session();
+        let response = client
+            .client
+            .get(format!(
+                "{}_api/wal/tail?from={}",
+                self.database().url().as_str(),
+                from_tick
+            ))
+            .basic_auth(&self.username, Some(&self.password))
+            .send()
+            .await?;
+
+        let status = response.status().as_u16();
+        if status != 200 && status != 204 {
+            let text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "<undefined>".to_string());
+            return Err(anyhow::anyhow!(text));
+        }
+
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let check_more = header("x-arango-replication-checkmore").as_deref() == Some("true");
+        let last_included = header("x-arango-replication-lastincluded")
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(0);
+        let last_scanned = header("x-arango-replication-lastscanned")
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(0);
+        let next_tick = from_tick.max(last_included).max(last_scanned);
+
+        let text = response.text().await?;
+        let mut events = Vec::new();
+
+        for line in text.lines().filter(|v| !v.trim().is_empty()) {
+            let marker: WalMarker = serde_json::from_str(line)?;
+
+            if marker.cuid.as_deref() != Some(collection_id) {
+                continue;
+            }
+
+            let kind = match marker.marker_type {
+                WAL_DOCUMENT_MARKER => ChangeEventKind::Upsert,
+                WAL_REMOVE_MARKER => ChangeEventKind::Remove,
+                _ => continue,
+            };
+
+            let key = marker
+                .data
+                .get("_key")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+
+            events.push(ChangeEvent {
+                tick: marker.tick.parse()?,
+                kind,
+                key,
+                document: marker.data,
+            });
+        }
+
+        Ok((events, next_tick, check_more))
+    }
+
     pub async fn remove_all_aql_function(&self, namespace: &str) -> Result<(), anyhow::Error> {
-        let client = self.connection.session();
+        let client = self.connection().session();
         let response = client
             .client
             .delete(format!(
                 "{}_api/aqlfunction/{}?group=true",
-                self.database.url().as_str(),
+                self.database().url().as_str(),
                 namespace,
             ))
             .basic_auth(&self.username, Some(&self.password))
@@ -141,3 +779,439 @@ struct AddFunctionRequest<'a> {
     code: &'a str,
     is_deterministic: bool,
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A user-defined AQL function, called as `<namespace>::<name>` in the queries.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AqlFunction {
+    pub namespace: String,
+    pub name: String,
+    /// The JavaScript code of the function.
+    pub code: String,
+    pub deterministic: bool,
+}
+
+impl AqlFunction {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The name used to call the function in the queries.
+    pub fn full_name(&self) -> String {
+        format!("{}::{}", self.namespace, self.name)
+    }
+}
+
+/// The options to create a collection.
+#[derive(Debug, Clone, Default)]
+pub struct CollectionOptions {
+    pub is_edge: bool,
+    pub is_system: bool,
+    pub key_options: Option<CollectionKeyOptions>,
+    pub number_of_shards: Option<u32>,
+    pub shard_keys: Option<Vec<String>>,
+    pub replication_factor: Option<u32>,
+}
+
+/// The key generator settings of a collection.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionKeyOptions {
+    #[serde(rename = "type")]
+    pub generator: CollectionKeyGenerator,
+    pub allow_user_keys: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub increment: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+}
+
+/// The key generator of a collection.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CollectionKeyGenerator {
+    Traditional,
+    Autoincrement,
+    Padded,
+    Uuid,
+}
+
+/// The mode of the query results cache of the server.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AqlQueryCacheMode {
+    /// No query is cached.
+    Off,
+    /// Every query is cached unless it sets `cache` to false.
+    On,
+    /// Only the queries that set `cache` to true are cached.
+    Demand,
+}
+
+/// A step of a migration run by `DBInfo::run_migration`.
+#[derive(Debug, Clone)]
+pub enum MigrationStep {
+    EnsureCollection {
+        name: String,
+        options: CollectionOptions,
+    },
+    EnsureIndex {
+        collection: String,
+        index: serde_json::Value,
+    },
+    RunAql {
+        query: String,
+        bind_vars: HashMap<String, serde_json::Value>,
+    },
+}
+
+/// A change of a document read from the write-ahead log by `DBInfo::tail`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeEvent {
+    pub tick: u64,
+    pub kind: ChangeEventKind,
+    pub key: String,
+    /// The whole document for upserts and only its `_key` and `_rev` for removals.
+    pub document: serde_json::Value,
+}
+
+/// The kind of a `ChangeEvent`. The write-ahead log does not distinguish inserts from updates
+/// or replacements.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ChangeEventKind {
+    Upsert,
+    Remove,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateCollectionRequest<'a> {
+    name: &'a str,
+    #[serde(rename = "type")]
+    collection_type: u8,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    is_system: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key_options: Option<&'a CollectionKeyOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number_of_shards: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shard_keys: Option<&'a [String]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replication_factor: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CollectionPropertiesResponse {
+    #[serde(rename = "type")]
+    collection_type: u8,
+    #[cfg(feature = "tokio")]
+    globally_unique_id: String,
+    key_options: CollectionPropertiesKeyOptions,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CollectionPropertiesKeyOptions {
+    #[serde(rename = "type")]
+    generator: CollectionKeyGenerator,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AqlFunctionListResponse {
+    result: Vec<AqlFunctionResponse>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AqlFunctionResponse {
+    name: String,
+    code: String,
+    is_deterministic: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct LastTickResponse {
+    tick: String,
+}
+
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Deserialize)]
+struct WalMarker {
+    tick: String,
+    #[serde(rename = "type")]
+    marker_type: u16,
+    #[serde(default)]
+    cuid: Option<String>,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Executes `action` up to `max_attempts` times while it fails with a connection error,
+/// doubling the `delay` after each failed attempt.
+#[cfg(feature = "tokio")]
+async fn retry_on_connection_error<T, F, Fut>(
+    max_attempts: usize,
+    mut delay: Duration,
+    mut action: F,
+) -> Result<T, ClientError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ClientError>>,
+{
+    let mut attempt = 1;
+
+    loop {
+        match action().await {
+            Ok(v) => return Ok(v),
+            Err(e) => {
+                let e = check_client_is_connection_error(e)?;
+
+                if attempt >= max_attempts {
+                    return Err(e);
+                }
+            }
+        }
+
+        tokio::time::sleep(delay).await;
+        delay = delay.saturating_mul(2);
+        attempt += 1;
+    }
+}
+
+/// Executes `action` and, if it fails because the JWT session has expired, executes `reconnect`
+/// and retries `action` once.
+async fn retry_on_auth_expired<T, F, Fut, R, RFut>(
+    mut action: F,
+    reconnect: R,
+) -> Result<T, ClientError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ClientError>>,
+    R: FnOnce() -> RFut,
+    RFut: Future<Output = Result<(), ClientError>>,
+{
+    match action().await {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            check_client_is_auth_expired(e)?;
+            reconnect().await?;
+            action().await
+        }
+    }
+}
+
+/// Executes `action` until it does not fail because of a write conflict.
+pub(crate) async fn retry_on_write_conflict<T, F, Fut>(mut action: F) -> Result<T, ClientError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ClientError>>,
+{
+    loop {
+        match action().await {
+            Ok(v) => return Ok(v),
+            Err(e) => check_client_is_write_conflict(e)?,
+        };
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_retry_on_connection_error() {
+        let attempts = AtomicUsize::new(0);
+        let result = retry_on_connection_error(5, Duration::from_millis(1), || async {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err(ClientError::HttpClient("Connection refused".to_string())),
+                _ => Ok(()),
+            }
+        })
+        .await;
+
+        assert!(result.is_ok(), "The action must succeed");
+        assert_eq!(attempts.load(Ordering::SeqCst), 3, "Incorrect attempts");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_retry_on_connection_error_max_attempts() {
+        let attempts = AtomicUsize::new(0);
+        let result: Result<(), _> =
+            retry_on_connection_error(4, Duration::from_millis(1), || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(ClientError::HttpClient("Connection refused".to_string()))
+            })
+            .await;
+
+        assert!(
+            matches!(result, Err(ClientError::HttpClient(_))),
+            "The action must fail"
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 4, "Incorrect attempts");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_retry_on_connection_error_other_errors() {
+        let attempts = AtomicUsize::new(0);
+        let result: Result<(), _> =
+            retry_on_connection_error(4, Duration::from_millis(1), || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(ClientError::InvalidServer("Invalid".to_string()))
+            })
+            .await;
+
+        assert!(
+            matches!(result, Err(ClientError::InvalidServer(_))),
+            "The action must fail"
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 1, "Incorrect attempts");
+    }
+
+    fn auth_expired_error() -> ClientError {
+        ClientError::Arango(
+            serde_json::from_value(serde_json::json!({
+                "code": 401,
+                "error": true,
+                "errorNum": 11,
+                "errorMessage": "not authorized to execute this request",
+            }))
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_auth_expired() {
+        let attempts = AtomicUsize::new(0);
+        let reconnections = AtomicUsize::new(0);
+        let result = retry_on_auth_expired(
+            || async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(auth_expired_error()),
+                    _ => Ok(()),
+                }
+            },
+            || async {
+                reconnections.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            },
+        )
+        .await;
+
+        assert!(result.is_ok(), "The action must succeed");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2, "Incorrect attempts");
+        assert_eq!(
+            reconnections.load(Ordering::SeqCst),
+            1,
+            "Incorrect reconnections"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_auth_expired_only_once() {
+        let attempts = AtomicUsize::new(0);
+        let result: Result<(), _> = retry_on_auth_expired(
+            || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(auth_expired_error())
+            },
+            || async { Ok(()) },
+        )
+        .await;
+
+        assert!(
+            matches!(result, Err(ClientError::Arango(_))),
+            "The action must fail"
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 2, "Incorrect attempts");
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_auth_expired_other_errors() {
+        let attempts = AtomicUsize::new(0);
+        let reconnections = AtomicUsize::new(0);
+        let result: Result<(), _> = retry_on_auth_expired(
+            || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(ClientError::HttpClient("Connection refused".to_string()))
+            },
+            || async {
+                reconnections.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            },
+        )
+        .await;
+
+        assert!(
+            matches!(result, Err(ClientError::HttpClient(_))),
+            "The action must fail"
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 1, "Incorrect attempts");
+        assert_eq!(
+            reconnections.load(Ordering::SeqCst),
+            0,
+            "Incorrect reconnections"
+        );
+    }
+
+    fn write_conflict_error() -> ClientError {
+        ClientError::Arango(
+            serde_json::from_value(serde_json::json!({
+                "code": 409,
+                "error": true,
+                "errorNum": 1200,
+                "errorMessage": "write-write conflict",
+            }))
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_write_conflict() {
+        let attempts = AtomicUsize::new(0);
+        let result = retry_on_write_conflict(|| async {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(write_conflict_error()),
+                _ => Ok(()),
+            }
+        })
+        .await;
+
+        assert!(result.is_ok(), "The action must succeed");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2, "Incorrect attempts");
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_write_conflict_other_errors() {
+        let attempts = AtomicUsize::new(0);
+        let result: Result<(), _> = retry_on_write_conflict(|| async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(auth_expired_error())
+        })
+        .await;
+
+        assert!(
+            matches!(result, Err(ClientError::Arango(_))),
+            "The action must fail"
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 1, "Incorrect attempts");
+    }
+}
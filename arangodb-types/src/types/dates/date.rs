@@ -5,8 +5,9 @@ use chrono::{Datelike, TimeZone, Utc};
 use serde::de::Visitor;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::types::dates::DBDateTime;
+use crate::types::dates::{DBDateTime, DBDayTime};
 
+/// A date stored in DB as a number of days from CE.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct DBDate(pub chrono::Date<Utc>);
 
@@ -44,10 +45,41 @@ impl DBDate {
             - (zero_month.0.year() as u32 * 12 + zero_month.0.month0())
     }
 
+    /// Gets the number of days from this date to `other`, negative if `other` is before it.
+    pub fn days_between(&self, other: &DBDate) -> i64 {
+        (other.0 - self.0).num_days()
+    }
+
+    /// Gets the number of calendar months from this date to `other`, negative if `other`
+    /// is before it. The days of the month are ignored.
+    pub fn months_between(&self, other: &DBDate) -> i32 {
+        other.months_since_zero_month() as i32 - self.months_since_zero_month() as i32
+    }
+
     // METHODS ----------------------------------------------------------------
 
+    pub fn after_years(&self, years: u32) -> DBDate {
+        self.after_years_checked(years).unwrap()
+    }
+
+    /// Creates a new Date from the current one after `years` years.
+    /// Feb 29 is clamped to Feb 28 when the final year is not a leap year.
+    pub fn after_years_checked(&self, years: u32) -> Option<DBDate> {
+        let year = self.0.year().checked_add(i32::try_from(years).ok()?)?;
+
+        Self::ymd_clamping_leap_day(year, self.0.month(), self.0.day())
+    }
+
     pub fn before_years(&self, years: u32) -> DBDate {
-        DBDate(Utc.ymd(self.0.year() - years as i32, self.0.month(), self.0.day()))
+        self.before_years_checked(years).unwrap()
+    }
+
+    /// Creates a new Date from the current one before `years` years.
+    /// Feb 29 is clamped to Feb 28 when the final year is not a leap year.
+    pub fn before_years_checked(&self, years: u32) -> Option<DBDate> {
+        let year = self.0.year().checked_sub(i32::try_from(years).ok()?)?;
+
+        Self::ymd_clamping_leap_day(year, self.0.month(), self.0.day())
     }
 
     pub fn after_days(&self, duration: u64) -> DBDate {
@@ -79,6 +111,21 @@ impl DBDate {
     pub fn to_date_time(&self) -> DBDateTime {
         DBDateTime::new(self.0.and_hms(0, 0, 0))
     }
+
+    /// Combines this date with a time of the day in UTC.
+    pub fn at(&self, time: &DBDayTime) -> DBDateTime {
+        DBDateTime::new(self.0.and_time(time.0).unwrap())
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn ymd_clamping_leap_day(year: i32, month: u32, day: u32) -> Option<DBDate> {
+        match Utc.ymd_opt(year, month, day).single() {
+            Some(v) => Some(DBDate(v)),
+            None if month == 2 && day == 29 => Utc.ymd_opt(year, 2, 28).single().map(DBDate),
+            None => None,
+        }
+    }
 }
 
 impl Serialize for DBDate {
@@ -100,27 +147,30 @@ impl<'de> Deserialize<'de> for DBDate {
             type Value = DBDate;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("an integer between -2^63 and 2^63")
+                formatter.write_str("a number of days from CE in the representable date range")
             }
 
             fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(DBDate(chrono::Date::from_utc(
-                    chrono::NaiveDate::from_num_days_from_ce(value as i32),
-                    Utc,
-                )))
+                match i32::try_from(value)
+                    .ok()
+                    .and_then(chrono::NaiveDate::from_num_days_from_ce_opt)
+                {
+                    Some(v) => Ok(DBDate(chrono::Date::from_utc(v, Utc))),
+                    None => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+                }
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(DBDate(chrono::Date::from_utc(
-                    chrono::NaiveDate::from_num_days_from_ce(value as i32),
-                    Utc,
-                )))
+                match i64::try_from(value) {
+                    Ok(v) => self.visit_i64(v),
+                    Err(_) => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+                }
             }
         }
 
@@ -152,8 +202,66 @@ impl Default for DBDate {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Serializes a `DBDate` as an ISO 8601 string, i.e. `YYYY-MM-DD`, instead of a number of days.
+///
+/// Use it as `#[serde(with = "date_iso")]`.
+pub mod date_iso {
+    use std::fmt;
+
+    use chrono::Utc;
+    use serde::de::Visitor;
+    use serde::{de, Deserializer, Serializer};
+
+    use crate::types::dates::DBDate;
+
+    const FORMAT: &str = "%Y-%m-%d";
+
+    pub fn serialize<S>(
+        value: &DBDate,
+        serializer: S,
+    ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&value.0.naive_utc().format(FORMAT))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DBDate, <D as Deserializer<'de>>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DateVisitor;
+        impl<'de> Visitor<'de> for DateVisitor {
+            type Value = DBDate;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a date in the YYYY-MM-DD format")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match chrono::NaiveDate::parse_from_str(value, FORMAT) {
+                    Ok(v) => Ok(DBDate(chrono::Date::from_utc(v, Utc))),
+                    Err(_) => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(DateVisitor)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
     use super::*;
 
     #[test]
@@ -165,6 +273,40 @@ mod test {
         assert_eq!(date, serde_json::from_str(str_date.as_str()).unwrap());
     }
 
+    #[test]
+    fn test_date_round_trip() {
+        let min = chrono::NaiveDate::MIN.num_days_from_ce();
+        let max = chrono::NaiveDate::MAX.num_days_from_ce();
+        let mut rng = StdRng::seed_from_u64(0);
+        let days = [min, max, 0]
+            .into_iter()
+            .chain((0..1000).map(|_| rng.gen_range(min..=max)));
+
+        for days in days {
+            let date = DBDate(chrono::Date::from_utc(
+                chrono::NaiveDate::from_num_days_from_ce(days),
+                Utc,
+            ));
+            let str_date = serde_json::to_string(&date).unwrap();
+
+            assert_eq!(
+                date,
+                serde_json::from_str(str_date.as_str()).unwrap(),
+                "Incorrect round trip for {}",
+                days
+            );
+        }
+
+        let out_of_range = [i64::from(min) - 1, i64::from(max) + 1, i64::MAX];
+        for days in out_of_range {
+            assert!(
+                serde_json::from_str::<DBDate>(days.to_string().as_str()).is_err(),
+                "The date {} must be out of range",
+                days
+            );
+        }
+    }
+
     #[test]
     fn date_after_months() {
         let original_date = DBDate(Utc.ymd(2021, 12, 1));
@@ -194,4 +336,109 @@ mod test {
         assert_eq!(final_date.0.year(), 2019, "The year is incorrect");
         assert_eq!(final_date.0.month(), 9, "The month is incorrect");
     }
+
+    #[test]
+    fn date_days_between() {
+        let date = DBDate(Utc.ymd(2021, 3, 1));
+
+        let other = DBDate(Utc.ymd(2021, 3, 14));
+        assert_eq!(date.days_between(&date), 0, "Incorrect same day");
+        assert_eq!(date.days_between(&other), 13, "Incorrect same month");
+
+        let other = DBDate(Utc.ymd(2022, 1, 1));
+        assert_eq!(date.days_between(&other), 306, "Incorrect cross year");
+        assert_eq!(other.days_between(&date), -306, "Incorrect reversed order");
+    }
+
+    #[test]
+    fn date_months_between() {
+        let date = DBDate(Utc.ymd(2021, 3, 1));
+
+        let other = DBDate(Utc.ymd(2021, 3, 31));
+        assert_eq!(date.months_between(&other), 0, "Incorrect same month");
+
+        let other = DBDate(Utc.ymd(2022, 1, 1));
+        assert_eq!(date.months_between(&other), 10, "Incorrect cross year");
+        assert_eq!(other.months_between(&date), -10, "Incorrect reversed order");
+    }
+
+    #[test]
+    fn date_at() {
+        let date = DBDate(Utc.ymd(2021, 3, 14));
+        let time = DBDayTime(chrono::NaiveTime::from_hms(15, 9, 26));
+        let date_time = date.at(&time);
+
+        assert_eq!(
+            date_time,
+            DBDateTime::new(Utc.ymd(2021, 3, 14).and_hms(15, 9, 26)),
+            "Incorrect date time"
+        );
+    }
+
+    #[test]
+    fn date_after_years_leap_day() {
+        let original_date = DBDate(Utc.ymd(2020, 2, 29));
+
+        let final_date = original_date.after_years(1);
+        assert_eq!(final_date, DBDate(Utc.ymd(2021, 2, 28)), "Incorrect date");
+
+        let final_date = original_date.after_years(4);
+        assert_eq!(final_date, DBDate(Utc.ymd(2024, 2, 29)), "Incorrect date");
+
+        let final_date = original_date.after_years_checked(u32::MAX);
+        assert_eq!(final_date, None, "The date must overflow");
+    }
+
+    #[test]
+    fn date_before_years_leap_day() {
+        let original_date = DBDate(Utc.ymd(2020, 2, 29));
+
+        let final_date = original_date.before_years(1);
+        assert_eq!(final_date, DBDate(Utc.ymd(2019, 2, 28)), "Incorrect date");
+
+        let final_date = original_date.before_years(4);
+        assert_eq!(final_date, DBDate(Utc.ymd(2016, 2, 29)), "Incorrect date");
+
+        let final_date = original_date.before_years_checked(u32::MAX);
+        assert_eq!(final_date, None, "The date must overflow");
+    }
+
+    #[test]
+    fn test_date_iso() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "date_iso")] DBDate);
+
+        let date = Wrapper(DBDate(Utc.ymd(2020, 2, 29)));
+        let str_date = serde_json::to_string(&date).unwrap();
+
+        assert_eq!("\"2020-02-29\"", str_date);
+        assert_eq!(date, serde_json::from_str(str_date.as_str()).unwrap());
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let min = Utc.ymd(1, 1, 1).num_days_from_ce();
+        let max = Utc.ymd(9999, 12, 31).num_days_from_ce();
+
+        for days in (0..1000).map(|_| rng.gen_range(min..=max)) {
+            let date = Wrapper(DBDate(chrono::Date::from_utc(
+                chrono::NaiveDate::from_num_days_from_ce(days),
+                Utc,
+            )));
+            let str_date = serde_json::to_string(&date).unwrap();
+
+            assert_eq!(
+                date,
+                serde_json::from_str(str_date.as_str()).unwrap(),
+                "Incorrect round trip for {}",
+                str_date
+            );
+        }
+
+        for str_date in ["\"2021-02-29\"", "\"2021-13-01\"", "\"today\"", "737850"] {
+            assert!(
+                serde_json::from_str::<Wrapper>(str_date).is_err(),
+                "The date {} must be invalid",
+                str_date
+            );
+        }
+    }
 }
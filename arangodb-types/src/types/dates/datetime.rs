@@ -5,6 +5,8 @@ use chrono::{DateTime, Datelike, LocalResult, TimeZone, Timelike, Utc};
 use serde::de::Visitor;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
+use crate::types::dates::DBDuration;
+
 /// A datetime stored in DB as a UNIX milliseconds timestamp.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct DBDateTime(pub chrono::DateTime<Utc>);
@@ -12,6 +14,7 @@ pub struct DBDateTime(pub chrono::DateTime<Utc>);
 impl DBDateTime {
     // CONSTRUCTORS -----------------------------------------------------------
 
+    /// Creates a new datetime truncated to milliseconds, the precision it is stored with.
     pub fn new(date: chrono::DateTime<Utc>) -> Self {
         DBDateTime(date.date().and_hms_milli(
             date.hour(),
@@ -47,6 +50,14 @@ impl DBDateTime {
         self.0 <= now.0
     }
 
+    /// Checks whether more than `duration` seconds have passed since this datetime.
+    pub fn is_older_than(&self, duration: DBDuration) -> bool {
+        match self.after_duration_checked(std::time::Duration::from_secs(*duration)) {
+            Some(v) => v.is_expired(),
+            None => false,
+        }
+    }
+
     // METHODS ----------------------------------------------------------------
 
     /// Creates a new DateTime from the current one after `duration` seconds.
@@ -61,6 +72,17 @@ impl DBDateTime {
             .map(DBDateTime)
     }
 
+    /// Creates a new DateTime from the current one after `duration`, truncated to milliseconds.
+    pub fn after_duration(&self, duration: std::time::Duration) -> DBDateTime {
+        self.after_duration_checked(duration).unwrap()
+    }
+
+    /// Creates a new DateTime from the current one after `duration`, truncated to milliseconds.
+    pub fn after_duration_checked(&self, duration: std::time::Duration) -> Option<DBDateTime> {
+        let duration = chrono::Duration::from_std(duration).ok()?;
+        self.0.checked_add_signed(duration).map(DBDateTime::new)
+    }
+
     /// Creates a new DateTime from the current one before `duration` seconds.
     pub fn before_seconds(&self, duration: u64) -> DBDateTime {
         DBDateTime(self.0 - chrono::Duration::seconds(duration as i64))
@@ -179,21 +201,27 @@ impl<'de> Deserialize<'de> for DBDateTime {
             type Value = DBDateTime;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("an integer between -2^63 and 2^63")
+                formatter.write_str("a milliseconds timestamp in the representable datetime range")
             }
 
             fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(DBDateTime::new(Utc::timestamp_millis(&Utc, value)))
+                match Utc.timestamp_millis_opt(value) {
+                    LocalResult::Single(v) => Ok(DBDateTime::new(v)),
+                    _ => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+                }
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(DBDateTime::new(Utc::timestamp_millis(&Utc, value as i64)))
+                match i64::try_from(value) {
+                    Ok(v) => self.visit_i64(v),
+                    Err(_) => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+                }
             }
         }
 
@@ -225,8 +253,76 @@ impl Default for DBDateTime {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// Serializes a `DBDateTime` as a UNIX seconds timestamp instead of milliseconds.
+///
+/// Use it as `#[serde(with = "datetime_seconds")]`. The sub-second part is dropped.
+pub mod datetime_seconds {
+    use std::fmt;
+
+    use chrono::{LocalResult, TimeZone, Utc};
+    use serde::de::Visitor;
+    use serde::{de, Deserializer, Serializer};
+
+    use crate::types::dates::DBDateTime;
+
+    pub fn serialize<S>(
+        value: &DBDateTime,
+        serializer: S,
+    ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(value.0.timestamp())
+    }
+
+    pub fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<DBDateTime, <D as Deserializer<'de>>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DateTimeVisitor;
+        impl<'de> Visitor<'de> for DateTimeVisitor {
+            type Value = DBDateTime;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a seconds timestamp in the representable datetime range")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match Utc.timestamp_opt(value, 0) {
+                    LocalResult::Single(v) => Ok(DBDateTime::new(v)),
+                    _ => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+                }
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match i64::try_from(value) {
+                    Ok(v) => self.visit_i64(v),
+                    Err(_) => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_i64(DateTimeVisitor)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
     use super::*;
 
     #[test]
@@ -238,6 +334,100 @@ mod test {
         assert_eq!(date, serde_json::from_str(str_date.as_str()).unwrap());
     }
 
+    #[test]
+    fn test_datetime_round_trip() {
+        let min = chrono::DateTime::<Utc>::MIN_UTC.timestamp();
+        let max = chrono::DateTime::<Utc>::MAX_UTC.timestamp();
+        let mut rng = StdRng::seed_from_u64(0);
+        let timestamps = [(min, 0), (max, 999_999_999), (0, 0)]
+            .into_iter()
+            .chain((0..1000).map(|_| (rng.gen_range(min..=max), rng.gen_range(0..1_000_000_000))));
+
+        for (seconds, nanos) in timestamps {
+            let date = DBDateTime::new(Utc.timestamp(seconds, nanos));
+            let str_date = serde_json::to_string(&date).unwrap();
+
+            assert_eq!(
+                date.timestamp_subsec_nanos() % 1_000_000,
+                0,
+                "The datetime must be truncated to milliseconds"
+            );
+            assert_eq!(
+                date,
+                serde_json::from_str(str_date.as_str()).unwrap(),
+                "Incorrect round trip for {}.{:09}",
+                seconds,
+                nanos
+            );
+        }
+    }
+
+    #[test]
+    fn test_datetime_after_duration() {
+        let date = DBDateTime(Utc.timestamp(1000, 0));
+
+        assert_eq!(
+            date.after_duration(std::time::Duration::from_millis(1500)),
+            DBDateTime(Utc.timestamp_millis(1001500)),
+            "Incorrect after_duration"
+        );
+        assert_eq!(
+            date.after_duration(std::time::Duration::from_nanos(2_999_999)),
+            DBDateTime(Utc.timestamp_millis(1000002)),
+            "The result must be truncated to milliseconds"
+        );
+        assert_eq!(
+            DBDateTime::max_datetime().after_duration_checked(std::time::Duration::from_secs(1)),
+            None,
+            "Incorrect overflow"
+        );
+        assert_eq!(
+            date.after_duration_checked(std::time::Duration::MAX),
+            None,
+            "Incorrect out of range duration"
+        );
+    }
+
+    #[test]
+    fn test_datetime_out_of_range() {
+        let result = serde_json::from_str::<DBDateTime>(&i64::MAX.to_string());
+        assert!(result.is_err(), "The max i64 must be rejected");
+
+        let result = serde_json::from_str::<DBDateTime>(&i64::MIN.to_string());
+        assert!(result.is_err(), "The min i64 must be rejected");
+
+        let result = serde_json::from_str::<DBDateTime>(&u64::MAX.to_string());
+        assert!(result.is_err(), "The max u64 must be rejected");
+
+        let max_millis = DBDateTime::max_datetime().timestamp_millis();
+        let result = serde_json::from_str::<DBDateTime>(&max_millis.to_string());
+        assert_eq!(
+            result.unwrap(),
+            DBDateTime::max_datetime(),
+            "The max datetime must be accepted"
+        );
+    }
+
+    #[test]
+    fn test_datetime_seconds() {
+        #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "datetime_seconds")] DBDateTime);
+
+        let date = Wrapper(DBDateTime(
+            Utc.ymd(1970, 12, 7).and_hms_milli(5, 23, 30, 500),
+        ));
+        let str_date = serde_json::to_string(&date).unwrap();
+
+        assert_eq!("29395410", str_date);
+
+        let expected = Wrapper(DBDateTime(Utc.ymd(1970, 12, 7).and_hms(5, 23, 30)));
+        assert_eq!(
+            expected,
+            serde_json::from_str(str_date.as_str()).unwrap(),
+            "The milliseconds must be dropped"
+        );
+    }
+
     #[test]
     fn test_datetime_after_months() {
         let original_date = DBDateTime(Utc.ymd(2021, 12, 1).and_hms(0, 0, 0));
@@ -267,4 +457,25 @@ mod test {
         assert_eq!(final_date.0.year(), 2019, "The year is incorrect");
         assert_eq!(final_date.0.month(), 9, "The month is incorrect");
     }
+
+    #[test]
+    fn test_datetime_is_older_than() {
+        let hour = DBDuration::from(3600u32);
+
+        assert!(DBDateTime::now()
+            .before_seconds(7200)
+            .is_older_than(hour.clone()));
+        assert!(!DBDateTime::now()
+            .before_seconds(60)
+            .is_older_than(hour.clone()));
+        assert!(!DBDateTime::now().after_seconds(7200).is_older_than(hour));
+        assert!(
+            !DBDateTime::now().is_older_than(DBDuration::from(u64::MAX)),
+            "The max u64 must not be older"
+        );
+        assert!(
+            !DBDateTime::now().is_older_than(DBDuration::from(i64::MAX as u64)),
+            "The max i64 must not be older"
+        );
+    }
 }
@@ -0,0 +1,179 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::dates::DBDateTime;
+
+/// A half-open `[from, to)` interval of datetimes stored in DB as `{ from, to }`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "DBDateTimeRangeFields")]
+pub struct DBDateTimeRange {
+    from: DBDateTime,
+    to: DBDateTime,
+}
+
+impl DBDateTimeRange {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new range unless `to` is before `from`.
+    pub fn new(from: DBDateTime, to: DBDateTime) -> Option<Self> {
+        if to.0 < from.0 {
+            return None;
+        }
+
+        Some(DBDateTimeRange { from, to })
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn from(&self) -> &DBDateTime {
+        &self.from
+    }
+
+    pub fn to(&self) -> &DBDateTime {
+        &self.to
+    }
+
+    /// Whether the range does not contain any datetime, i.e. `from == to`.
+    pub fn is_empty(&self) -> bool {
+        self.from == self.to
+    }
+
+    pub fn duration(&self) -> chrono::Duration {
+        self.to.0 - self.from.0
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Whether the datetime is inside the range. The end is excluded.
+    pub fn contains(&self, datetime: &DBDateTime) -> bool {
+        self.from.0 <= datetime.0 && datetime.0 < self.to.0
+    }
+
+    /// Whether both ranges share any datetime. Adjacent ranges do not overlap.
+    pub fn overlaps(&self, other: &DBDateTimeRange) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.from.0 < other.to.0
+            && other.from.0 < self.to.0
+    }
+}
+
+impl TryFrom<DBDateTimeRangeFields> for DBDateTimeRange {
+    type Error = InvalidDBDateTimeRange;
+
+    fn try_from(value: DBDateTimeRangeFields) -> Result<Self, Self::Error> {
+        DBDateTimeRange::new(value.from, value.to).ok_or(InvalidDBDateTimeRange)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[derive(Deserialize)]
+struct DBDateTimeRangeFields {
+    from: DBDateTime,
+    to: DBDateTime,
+}
+
+/// The error of deserializing a range whose end is before its start.
+#[derive(Debug)]
+pub struct InvalidDBDateTimeRange;
+
+impl fmt::Display for InvalidDBDateTimeRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the end of the range cannot be before its start")
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn range(from: i64, to: i64) -> DBDateTimeRange {
+        DBDateTimeRange::new(
+            DBDateTime::new(Utc.timestamp(from, 0)),
+            DBDateTime::new(Utc.timestamp(to, 0)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_range_new() {
+        assert!(
+            DBDateTimeRange::new(
+                DBDateTime::new(Utc.timestamp(10, 0)),
+                DBDateTime::new(Utc.timestamp(5, 0)),
+            )
+            .is_none(),
+            "The end cannot be before the start"
+        );
+        assert_eq!(
+            range(10, 25).duration(),
+            chrono::Duration::seconds(15),
+            "Incorrect duration"
+        );
+    }
+
+    #[test]
+    fn test_range_contains() {
+        let range = range(10, 20);
+
+        assert!(
+            range.contains(&DBDateTime::new(Utc.timestamp(10, 0))),
+            "The start must be included"
+        );
+        assert!(
+            range.contains(&DBDateTime::new(Utc.timestamp(15, 0))),
+            "The middle must be included"
+        );
+        assert!(
+            !range.contains(&DBDateTime::new(Utc.timestamp(20, 0))),
+            "The end must be excluded"
+        );
+        assert!(
+            !range.contains(&DBDateTime::new(Utc.timestamp(5, 0))),
+            "Previous datetimes must be excluded"
+        );
+    }
+
+    #[test]
+    fn test_range_overlaps() {
+        assert!(range(10, 20).overlaps(&range(15, 25)), "Partial overlap");
+        assert!(range(10, 20).overlaps(&range(12, 18)), "Inner overlap");
+        assert!(
+            !range(10, 20).overlaps(&range(20, 30)),
+            "Adjacent ranges must not overlap"
+        );
+        assert!(
+            !range(20, 30).overlaps(&range(10, 20)),
+            "Adjacent ranges must not overlap"
+        );
+        assert!(
+            !range(10, 20).overlaps(&range(15, 15)),
+            "Empty ranges must not overlap"
+        );
+    }
+
+    #[test]
+    fn test_range_serde() {
+        let range = range(10, 20);
+        let json = serde_json::to_string(&range).unwrap();
+        assert_eq!(json, "{\"from\":10000,\"to\":20000}", "Incorrect JSON");
+
+        let value: DBDateTimeRange = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, range, "Incorrect range");
+
+        assert!(
+            serde_json::from_str::<DBDateTimeRange>("{\"from\":20000,\"to\":10000}").is_err(),
+            "Inverted ranges must be rejected"
+        );
+    }
+}
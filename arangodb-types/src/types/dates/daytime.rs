@@ -5,6 +5,9 @@ use chrono::Timelike;
 use serde::de::Visitor;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
+/// A time of the day stored in DB as a number of seconds from midnight.
+///
+/// The sub-second part is dropped when serialized.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct DBDayTime(pub chrono::NaiveTime);
 
@@ -27,25 +30,30 @@ impl<'de> Deserialize<'de> for DBDayTime {
             type Value = DBDayTime;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("an integer between -2^63 and 2^63")
+                formatter.write_str("a number of seconds from midnight lower than 86400")
             }
 
             fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(DBDayTime(
-                    chrono::NaiveTime::from_num_seconds_from_midnight(value as u32, 0),
-                ))
+                match u64::try_from(value) {
+                    Ok(v) => self.visit_u64(v),
+                    Err(_) => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+                }
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(DBDayTime(
-                    chrono::NaiveTime::from_num_seconds_from_midnight(value as u32, 0),
-                ))
+                match u32::try_from(value)
+                    .ok()
+                    .and_then(|v| chrono::NaiveTime::from_num_seconds_from_midnight_opt(v, 0))
+                {
+                    Some(v) => Ok(DBDayTime(v)),
+                    None => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+                }
             }
         }
 
@@ -79,6 +87,9 @@ impl Default for DBDayTime {
 
 #[cfg(test)]
 mod test {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
     use super::*;
 
     #[test]
@@ -92,4 +103,34 @@ mod test {
             serde_json::from_str(str_day_time.as_str()).unwrap()
         );
     }
+
+    #[test]
+    fn test_day_time_round_trip() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let seconds = [0, 86_399]
+            .into_iter()
+            .chain((0..1000).map(|_| rng.gen_range(0..86_400)));
+
+        for seconds in seconds {
+            let day_time = DBDayTime(chrono::NaiveTime::from_num_seconds_from_midnight(
+                seconds, 0,
+            ));
+            let str_day_time = serde_json::to_string(&day_time).unwrap();
+
+            assert_eq!(
+                day_time,
+                serde_json::from_str(str_day_time.as_str()).unwrap(),
+                "Incorrect round trip for {}",
+                seconds
+            );
+        }
+
+        for seconds in ["-1", "86400", "4294967296"] {
+            assert!(
+                serde_json::from_str::<DBDayTime>(seconds).is_err(),
+                "The day time {} must be out of range",
+                seconds
+            );
+        }
+    }
 }
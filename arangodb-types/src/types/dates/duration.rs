@@ -4,7 +4,8 @@ use std::ops::Deref;
 use serde::de::Visitor;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
+/// A duration stored in DB as a number of seconds.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct DBDuration(u64);
 
 impl Serialize for DBDuration {
@@ -99,4 +100,18 @@ mod test {
             serde_json::from_str(str_time_duration.as_str()).unwrap()
         );
     }
+
+    #[test]
+    fn test_duration_ord() {
+        let short = DBDuration::from(60u32);
+        let long = DBDuration::from(3600u32);
+
+        assert!(short < long);
+        assert!(long > short);
+        assert_eq!(short.clone().max(long.clone()), long);
+        assert_eq!(
+            short.cmp(&DBDuration::from(60u8)),
+            std::cmp::Ordering::Equal
+        );
+    }
 }
@@ -1,17 +1,24 @@
 use std::fmt;
 use std::ops::Deref;
 
-use chrono::{TimeZone, Timelike, Utc};
+use chrono::{LocalResult, TimeZone, Timelike, Utc};
 use serde::de::Visitor;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::types::dates::DBDateTime;
 
 /// A datetime stored in DB as a UNIX seconds timestamp.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct DBExpiration(pub chrono::DateTime<Utc>);
 
 impl DBExpiration {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new expiration `duration` seconds after now.
+    pub fn now_plus(duration: u64) -> Self {
+        DBDateTime::now().after_seconds(duration).into()
+    }
+
     // GETTERS ----------------------------------------------------------------
 
     /// Checks this datetime against now as if it is an expiration.
@@ -19,6 +26,25 @@ impl DBExpiration {
         let now = DBDateTime::now();
         self.0 <= now.0
     }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Creates a new expiration from the current one after `duration` seconds.
+    pub fn after_seconds(&self, duration: u64) -> DBExpiration {
+        self.after_seconds_checked(duration as i64).unwrap()
+    }
+
+    /// Creates a new expiration from the current one after `duration` seconds.
+    pub fn after_seconds_checked(&self, duration: i64) -> Option<DBExpiration> {
+        self.0
+            .checked_add_signed(chrono::Duration::seconds(duration))
+            .map(DBExpiration)
+    }
+
+    /// Creates a new expiration from the current one before `duration` seconds.
+    pub fn before_seconds(&self, duration: u64) -> DBExpiration {
+        DBExpiration(self.0 - chrono::Duration::seconds(duration as i64))
+    }
 }
 
 impl Serialize for DBExpiration {
@@ -40,21 +66,27 @@ impl<'de> Deserialize<'de> for DBExpiration {
             type Value = DBExpiration;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("an integer between -2^63 and 2^63")
+                formatter.write_str("a seconds timestamp in the representable datetime range")
             }
 
             fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(DBDateTime::new(Utc::timestamp(&Utc, value, 0)).into())
+                match Utc.timestamp_opt(value, 0) {
+                    LocalResult::Single(v) => Ok(DBExpiration(v)),
+                    _ => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+                }
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(DBDateTime::new(Utc::timestamp(&Utc, value as i64, 0)).into())
+                match i64::try_from(value) {
+                    Ok(v) => self.visit_i64(v),
+                    Err(_) => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+                }
             }
         }
 
@@ -87,3 +119,105 @@ impl Default for DBExpiration {
         DBDateTime::now().into()
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_expiration_arithmetic() {
+        let expiration = DBExpiration(Utc.timestamp(1000, 0));
+
+        assert_eq!(
+            expiration.after_seconds(50),
+            DBExpiration(Utc.timestamp(1050, 0)),
+            "Incorrect after_seconds"
+        );
+        assert_eq!(
+            expiration.before_seconds(50),
+            DBExpiration(Utc.timestamp(950, 0)),
+            "Incorrect before_seconds"
+        );
+        assert_eq!(
+            expiration.after_seconds_checked(-50),
+            Some(DBExpiration(Utc.timestamp(950, 0))),
+            "Incorrect after_seconds_checked"
+        );
+        assert_eq!(
+            DBExpiration(chrono::DateTime::<Utc>::MAX_UTC).after_seconds_checked(1),
+            None,
+            "Incorrect overflow"
+        );
+    }
+
+    #[test]
+    fn test_expiration_now_plus() {
+        let before = DBExpiration::from(DBDateTime::now()).after_seconds(100);
+        let expiration = DBExpiration::now_plus(100);
+        let after = DBExpiration::from(DBDateTime::now()).after_seconds(100);
+
+        assert!(before.0 <= expiration.0, "The expiration is too early");
+        assert!(expiration.0 <= after.0, "The expiration is too late");
+        assert!(!expiration.is_expired(), "The expiration cannot be expired");
+    }
+
+    #[test]
+    fn test_expiration_ordering() {
+        let first = DBExpiration(Utc.timestamp(950, 0));
+        let second = DBExpiration(Utc.timestamp(1000, 0));
+        let third = DBExpiration(Utc.timestamp(1050, 0));
+
+        let mut expirations = vec![third.clone(), first.clone(), second.clone()];
+        expirations.sort();
+
+        assert_eq!(
+            expirations,
+            vec![first.clone(), second.clone(), third.clone()],
+            "Incorrect order"
+        );
+        assert_eq!(
+            expirations.iter().min(),
+            Some(&first),
+            "Incorrect soonest expiration"
+        );
+        assert_eq!(first.clone().max(third.clone()), third, "Incorrect max");
+        assert_eq!(first.clone().min(second), first, "Incorrect min");
+    }
+
+    #[test]
+    fn test_expiration_round_trip() {
+        let min = chrono::DateTime::<Utc>::MIN_UTC.timestamp();
+        let max = chrono::DateTime::<Utc>::MAX_UTC.timestamp();
+        let mut rng = StdRng::seed_from_u64(0);
+        let seconds = [min, max, 0]
+            .into_iter()
+            .chain((0..1000).map(|_| rng.gen_range(min..=max)));
+
+        for seconds in seconds {
+            let expiration = DBExpiration(Utc.timestamp(seconds, 0));
+            let str_expiration = serde_json::to_string(&expiration).unwrap();
+
+            assert_eq!(
+                expiration,
+                serde_json::from_str(str_expiration.as_str()).unwrap(),
+                "Incorrect round trip for {}",
+                seconds
+            );
+        }
+
+        for seconds in [min - 1, max + 1] {
+            assert!(
+                serde_json::from_str::<DBExpiration>(seconds.to_string().as_str()).is_err(),
+                "The expiration {} must be out of range",
+                seconds
+            );
+        }
+    }
+}
@@ -1,11 +1,13 @@
 pub use date::*;
 pub use datetime::*;
+pub use datetime_range::*;
 pub use daytime::*;
 pub use duration::*;
 pub use expiration::*;
 
 mod date;
 mod datetime;
+mod datetime_range;
 mod daytime;
 mod duration;
 pub mod expiration;
@@ -2,18 +2,22 @@ pub use database_information::*;
 pub use dates::*;
 pub use id::*;
 pub use mutex::*;
-pub use nullable_option::*;
+pub use nullable_option::{deserialize_nullable_hashmap, deserialize_nullable_vec, NullableOption};
 pub use number::*;
 pub use reference::*;
 pub use reference_api::*;
+pub use reference_cache::*;
+pub use schema_version::*;
 pub use uuid::*;
 
 mod database_information;
 pub mod dates;
 mod id;
 mod mutex;
-mod nullable_option;
+pub mod nullable_option;
 mod number;
 mod reference;
 mod reference_api;
+mod reference_cache;
+mod schema_version;
 mod uuid;
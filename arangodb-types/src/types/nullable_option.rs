@@ -1,4 +1,7 @@
-use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Debug, Clone, Serialize, Eq, PartialEq)]
 #[serde(untagged)]
@@ -109,6 +112,16 @@ impl<T> NullableOption<T> {
             NullableOption::Value(v) => Some(v),
         }
     }
+
+    /// Iterates over the value, yielding nothing for `Missing` and `Null`.
+    pub fn iter(&self) -> std::option::IntoIter<&T> {
+        self.as_ref_option().into_iter()
+    }
+
+    /// Iterates mutably over the value, yielding nothing for `Missing` and `Null`.
+    pub fn iter_mut(&mut self) -> std::option::IntoIter<&mut T> {
+        self.as_mut_ref_option().into_iter()
+    }
 }
 
 impl<T: Default> NullableOption<T> {
@@ -137,6 +150,33 @@ impl<T> From<Option<T>> for NullableOption<T> {
     }
 }
 
+impl<T> IntoIterator for NullableOption<T> {
+    type Item = T;
+    type IntoIter = std::option::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_option().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NullableOption<T> {
+    type Item = &'a T;
+    type IntoIter = std::option::IntoIter<&'a T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NullableOption<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::option::IntoIter<&'a mut T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 impl<'de, T> Deserialize<'de> for NullableOption<T>
 where
     T: Deserialize<'de>,
@@ -149,6 +189,57 @@ where
     }
 }
 
+/// Deserializes a list keeping an empty one as a value.
+/// Use it along with `#[serde(default)]` to map missing fields to `Missing`.
+pub fn deserialize_nullable_vec<'de, D, T>(
+    deserializer: D,
+) -> Result<NullableOption<Vec<T>>, <D as Deserializer<'de>>::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    NullableOption::<Vec<T>>::deserialize(deserializer)
+}
+
+/// Deserializes a map keeping an empty one as a value.
+/// Use it along with `#[serde(default)]` to map missing fields to `Missing`.
+pub fn deserialize_nullable_hashmap<'de, D, K, V>(
+    deserializer: D,
+) -> Result<NullableOption<HashMap<K, V>>, <D as Deserializer<'de>>::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+{
+    NullableOption::<HashMap<K, V>>::deserialize(deserializer)
+}
+
+/// Serializes a `NullableOption` as `null` when it is `Missing` or `Null`.
+/// Use it as `#[serde(with = "nullable_option")]` along with `#[serde(default)]` to map missing
+/// fields to `Missing` and `#[serde(skip_serializing_if = "NullableOption::is_missing")]` to
+/// omit them.
+pub fn serialize<S, T>(value: &NullableOption<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    match value {
+        NullableOption::Missing | NullableOption::Null => serializer.serialize_none(),
+        NullableOption::Value(v) => serializer.serialize_some(v),
+    }
+}
+
+/// Deserializes a `NullableOption` mapping `null` to `Null`.
+/// Use it as `#[serde(with = "nullable_option")]` along with `#[serde(default)]` to map missing
+/// fields to `Missing`.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<NullableOption<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    NullableOption::<T>::deserialize(deserializer)
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -222,4 +313,107 @@ mod tests {
             .field;
         assert_eq!(expected, actual);
     }
+
+    #[derive(Deserialize, Debug)]
+    struct TestCollections {
+        #[serde(default)]
+        #[serde(deserialize_with = "deserialize_nullable_vec")]
+        list: NullableOption<Vec<i32>>,
+        #[serde(default)]
+        #[serde(deserialize_with = "deserialize_nullable_hashmap")]
+        map: NullableOption<HashMap<String, i32>>,
+    }
+
+    #[test]
+    fn collections_undefined() {
+        let actual = serde_json::from_str::<TestCollections>("{}").unwrap();
+        assert_eq!(NullableOption::Missing, actual.list);
+        assert_eq!(NullableOption::Missing, actual.map);
+    }
+
+    #[test]
+    fn collections_empty() {
+        let actual =
+            serde_json::from_str::<TestCollections>("{\"list\":null,\"map\":null}").unwrap();
+        assert_eq!(NullableOption::Null, actual.list);
+        assert_eq!(NullableOption::Null, actual.map);
+    }
+
+    #[test]
+    fn collections_empty_value() {
+        let actual = serde_json::from_str::<TestCollections>("{\"list\":[],\"map\":{}}").unwrap();
+        assert_eq!(NullableOption::Value(Vec::new()), actual.list);
+        assert_eq!(NullableOption::Value(HashMap::new()), actual.map);
+    }
+
+    #[test]
+    fn collections_value() {
+        let actual =
+            serde_json::from_str::<TestCollections>("{\"list\":[1],\"map\":{\"a\":2}}").unwrap();
+        assert_eq!(NullableOption::Value(vec![1]), actual.list);
+
+        let mut map = HashMap::new();
+        map.insert("a".to_string(), 2);
+        assert_eq!(NullableOption::Value(map), actual.map);
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct TestWith {
+        #[serde(
+            default,
+            with = "super",
+            skip_serializing_if = "NullableOption::is_missing"
+        )]
+        field: NullableOption<Vec<String>>,
+    }
+
+    #[test]
+    fn with_undefined() {
+        let actual = serde_json::from_str::<TestWith>("{}").unwrap();
+        assert_eq!(NullableOption::Missing, actual.field);
+        assert_eq!("{}", serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn with_empty() {
+        let actual = serde_json::from_str::<TestWith>("{\"field\":null}").unwrap();
+        assert_eq!(NullableOption::Null, actual.field);
+        assert_eq!("{\"field\":null}", serde_json::to_string(&actual).unwrap());
+    }
+
+    #[test]
+    fn with_value() {
+        let actual = serde_json::from_str::<TestWith>("{\"field\":[\"a\"]}").unwrap();
+        assert_eq!(NullableOption::Value(vec!["a".to_string()]), actual.field);
+        assert_eq!(
+            "{\"field\":[\"a\"]}",
+            serde_json::to_string(&actual).unwrap()
+        );
+    }
+
+    #[test]
+    fn iterators() {
+        let mut list = vec![
+            NullableOption::Value(1),
+            NullableOption::Null,
+            NullableOption::Missing,
+            NullableOption::Value(2),
+        ];
+
+        let values: Vec<_> = list.iter().flat_map(|v| v.iter().copied()).collect();
+        assert_eq!(values, vec![1, 2]);
+
+        for value in list.iter_mut().flatten() {
+            *value *= 10;
+        }
+
+        let values: Vec<_> = list.iter().flatten().copied().collect();
+        assert_eq!(values, vec![10, 20]);
+
+        let values: Vec<i32> = list.into_iter().flatten().collect();
+        assert_eq!(values, vec![10, 20]);
+
+        assert_eq!(NullableOption::<i32>::Null.iter().count(), 0);
+        assert_eq!(NullableOption::<i32>::Missing.into_iter().count(), 0);
+    }
 }
@@ -1,10 +1,17 @@
+use std::fmt;
 use std::io::Write;
+use std::marker::PhantomData;
+use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
+use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::aql::{get_aql_inline_variable, AqlBuilder, AqlLet, AqlLetKind};
-use crate::traits::{APIDocument, AQLMapping, DBCollection, DBDocument};
-use crate::types::APIReference;
+use crate::traits::{
+    APIDocument, AQLMapping, DBCollection, DBCollectionFromInfo, DBDocument, DBDocumentCollection,
+};
+use crate::types::{APIReference, DBInfo, ReferenceCache};
 
 #[derive(Debug, Clone, Eq, Serialize, Deserialize)]
 #[serde(bound = "T: DBDocument")]
@@ -12,21 +19,87 @@ use crate::types::APIReference;
 pub enum DBReference<T: DBDocument> {
     // Keep this order because otherwise Key will always be dereferenced in favour of Document
     // ignoring the rest of the fields.
+    //
+    // Note that documents whose fields can all be missing are deserialized as Document even
+    // when only some of them, or only the key, are present, e.g. the result of a projection.
+    // In that case the document is partial and the missing fields must not be trusted.
     Document(Box<T>),
     Key(DBReferenceKey<T::Key>),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
-pub struct DBReferenceKey<K> {
-    #[serde(rename = "_key")]
+/// A reference serialized as an object with only the key, e.g. `{"_key": "..."}`.
+/// The name of the field is given by `F`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DBReferenceKey<K, F: DBReferenceKeyField = KeyField> {
     key: K,
+    field: PhantomData<F>,
+}
+
+/// The name of the field that contains the key of a `DBReferenceKey`.
+pub trait DBReferenceKeyField {
+    const NAME: &'static str;
+}
+
+/// References by the `_key` field.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct KeyField;
+
+/// References by the `_from` field of edges.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FromField;
+
+/// References by the `_to` field of edges.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ToField;
+
+/// References by the `id` field of embedded documents.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct IdField;
+
+impl DBReferenceKeyField for KeyField {
+    const NAME: &'static str = "_key";
+}
+
+impl DBReferenceKeyField for FromField {
+    const NAME: &'static str = "_from";
+}
+
+impl DBReferenceKeyField for ToField {
+    const NAME: &'static str = "_to";
+}
+
+impl DBReferenceKeyField for IdField {
+    const NAME: &'static str = "id";
 }
 
 impl<T: DBDocument> DBReference<T> {
     // CONSTRUCTORS -----------------------------------------------------------
 
     pub fn new_key(key: T::Key) -> Self {
-        Self::Key(DBReferenceKey { key })
+        Self::Key(DBReferenceKey::new(key))
+    }
+
+    /// Parses a document handle, i.e. `<collection>/<key>`, into a key reference.
+    /// The collection must be the one of `T`.
+    pub fn from_handle(handle: &str) -> Result<Self, anyhow::Error> {
+        let (collection, key) = match handle.split_once('/') {
+            Some(v) => v,
+            None => return Err(anyhow::anyhow!("Incorrect document handle: {}", handle)),
+        };
+
+        let expected_collection = T::Collection::name();
+        if collection != expected_collection {
+            return Err(anyhow::anyhow!(
+                "Incorrect collection in document handle: expected {} but got {}",
+                expected_collection,
+                collection
+            ));
+        }
+
+        let key = serde_json::from_value(serde_json::Value::String(key.to_string()))
+            .map_err(|e| anyhow::anyhow!("Incorrect key in document handle: {}", e))?;
+
+        Ok(Self::new_key(key))
     }
 
     // GETTERS ----------------------------------------------------------------
@@ -46,6 +119,19 @@ impl<T: DBDocument> DBReference<T> {
         matches!(self, DBReference::Document(_))
     }
 
+    /// Gets a copy of the reference that only contains the key.
+    pub fn to_key(&self) -> Self {
+        Self::new_key(self.key())
+    }
+
+    /// Gets the document, that can be partial, if it is present.
+    pub fn as_partial(&self) -> Option<&T> {
+        match self {
+            DBReference::Document(v) => Some(v),
+            DBReference::Key(_) => None,
+        }
+    }
+
     pub fn unwrap_document_as_ref(&self) -> &T {
         match self {
             DBReference::Document(v) => v,
@@ -69,6 +155,101 @@ impl<T: DBDocument> DBReference<T> {
         }
     }
 
+    /// Drops the document, if any, keeping only its key.
+    pub fn into_key(self) -> Self {
+        match self {
+            DBReference::Document(v) => {
+                Self::new_key(v.db_key().clone().expect("Missing db_key in reference"))
+            }
+            DBReference::Key(_) => self,
+        }
+    }
+
+    /// Gets the referenced document from its own collection.
+    pub async fn resolve(
+        &self,
+        db_info: &Arc<DBInfo>,
+        return_fields: Option<&T>,
+    ) -> Result<Option<T>, anyhow::Error>
+    where
+        T::Collection: DBCollectionFromInfo,
+    {
+        let collection = T::collection(db_info);
+        collection.get_one_by_key(&self.key(), return_fields).await
+    }
+
+    /// Gets the referenced document like `resolve` but looking for it in `cache` first.
+    /// The documents read from the DB are stored in `cache`.
+    pub async fn resolve_cached(
+        &self,
+        db_info: &Arc<DBInfo>,
+        cache: &ReferenceCache<T>,
+    ) -> Result<Option<T>, anyhow::Error>
+    where
+        T::Collection: DBCollectionFromInfo,
+    {
+        let key = self.key();
+        if let Some(document) = cache.get(&key) {
+            return Ok(Some(document));
+        }
+
+        let collection = T::collection(db_info);
+        let document = collection.get_one_by_key(&key, None).await?;
+
+        if let Some(document) = &document {
+            cache.insert(document.clone());
+        }
+
+        Ok(document)
+    }
+
+    /// Gets the referenced documents of `references`, in the same order, looking for
+    /// them in `cache` first. The missing ones are read from the DB in a single request
+    /// and stored in `cache`.
+    pub async fn resolve_many_cached(
+        references: &[Self],
+        db_info: &Arc<DBInfo>,
+        cache: &ReferenceCache<T>,
+    ) -> Result<Vec<Option<T>>, anyhow::Error>
+    where
+        T::Collection: DBCollectionFromInfo,
+    {
+        let mut result = Vec::with_capacity(references.len());
+        let mut missing_indexes = Vec::new();
+        let mut missing_keys = Vec::new();
+
+        for (index, reference) in references.iter().enumerate() {
+            let key = reference.key();
+            let document = cache.get(&key);
+
+            if document.is_none() {
+                missing_indexes.push(index);
+                missing_keys.push(key);
+            }
+
+            result.push(document);
+        }
+
+        if missing_keys.is_empty() {
+            return Ok(result);
+        }
+
+        let collection = T::collection(db_info);
+        let documents = collection
+            .get_many_by_key(missing_keys.into_iter(), None)
+            .await?;
+
+        for (index, document) in missing_indexes.into_iter().zip(documents) {
+            if let Some(document) = &document {
+                cache.insert(document.clone());
+            }
+
+            result[index] = document;
+        }
+
+        Ok(result)
+    }
+
     pub fn map_to_api<F, R>(self, mapper: F) -> APIReference<R>
     where
         F: FnOnce(Box<T>) -> Box<R>,
@@ -133,3 +314,83 @@ impl<T: DBDocument> AQLMapping for DBReference<T> {
         }
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+impl<K, F: DBReferenceKeyField> DBReferenceKey<K, F> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(key: K) -> Self {
+        DBReferenceKey {
+            key,
+            field: PhantomData,
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+impl<K: Serialize, F: DBReferenceKeyField> Serialize for DBReferenceKey<K, F> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(F::NAME, &self.key)?;
+        map.end()
+    }
+}
+
+impl<'de, K: Deserialize<'de>, F: DBReferenceKeyField> Deserialize<'de> for DBReferenceKey<K, F> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct KeyVisitor<K, F>(PhantomData<(K, F)>);
+        impl<'de, K: Deserialize<'de>, F: DBReferenceKeyField> Visitor<'de> for KeyVisitor<K, F> {
+            type Value = DBReferenceKey<K, F>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "an object with a '{}' field", F::NAME)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut key = None;
+
+                while let Some(name) = map.next_key::<String>()? {
+                    if name == F::NAME {
+                        if key.is_some() {
+                            return Err(de::Error::duplicate_field(F::NAME));
+                        }
+
+                        key = Some(map.next_value()?);
+                    } else {
+                        map.next_value::<de::IgnoredAny>()?;
+                    }
+                }
+
+                match key {
+                    Some(key) => Ok(DBReferenceKey::new(key)),
+                    None => Err(de::Error::missing_field(F::NAME)),
+                }
+            }
+        }
+
+        deserializer.deserialize_map(KeyVisitor(PhantomData))
+    }
+}
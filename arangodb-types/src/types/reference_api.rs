@@ -41,6 +41,11 @@ impl<T: APIDocument> APIReference<T> {
         matches!(self, APIReference::Document(_))
     }
 
+    /// Gets a copy of the reference that only contains the key.
+    pub fn to_key(&self) -> Self {
+        Self::new_key(self.key())
+    }
+
     pub fn unwrap_document_as_ref(&self) -> &T {
         match self {
             APIReference::Document(v) => v,
@@ -64,6 +69,16 @@ impl<T: APIDocument> APIReference<T> {
         }
     }
 
+    /// Drops the document, if any, keeping only its key.
+    pub fn into_key(self) -> Self {
+        match self {
+            APIReference::Document(v) => {
+                Self::new_key(v.id().clone().expect("Missing id in reference"))
+            }
+            APIReference::Key(_) => self,
+        }
+    }
+
     pub fn and<F>(&mut self, mapper: F)
     where
         F: FnOnce(&mut Box<T>),
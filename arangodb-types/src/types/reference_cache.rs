@@ -0,0 +1,124 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use crate::traits::{DBCollection, DBDocument};
+use crate::types::DBId;
+
+type CacheId<T> = DBId<<T as DBDocument>::Key, &'static str>;
+
+/// A bounded in-memory cache of resolved references that evicts the least recently
+/// used document when it is full. It is not global, so it must be passed explicitly
+/// to the methods that use it, e.g. `DBReference::resolve_cached`.
+pub struct ReferenceCache<T: DBDocument> {
+    capacity: usize,
+    state: Mutex<ReferenceCacheState<T>>,
+}
+
+struct ReferenceCacheState<T: DBDocument> {
+    next_tick: u64,
+    entries: HashMap<CacheId<T>, (T, u64)>,
+    usages: BTreeMap<u64, CacheId<T>>,
+}
+
+impl<T: DBDocument> ReferenceCache<T> {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Creates a new cache that holds at most `capacity` documents.
+    pub fn new(capacity: usize) -> Self {
+        ReferenceCache {
+            capacity,
+            state: Mutex::new(ReferenceCacheState {
+                next_tick: 0,
+                entries: HashMap::with_capacity(capacity),
+                usages: BTreeMap::new(),
+            }),
+        }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains_key(&self, key: &T::Key) -> bool {
+        self.state
+            .lock()
+            .unwrap()
+            .entries
+            .contains_key(&Self::cache_id(key))
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Gets a copy of the cached document marking it as the most recently used.
+    pub fn get(&self, key: &T::Key) -> Option<T> {
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        let tick = state.next_tick;
+        let (document, last_tick) = state.entries.get_mut(&Self::cache_id(key))?;
+
+        let id = state.usages.remove(last_tick).unwrap();
+        state.usages.insert(tick, id);
+        state.next_tick += 1;
+        *last_tick = tick;
+
+        Some(document.clone())
+    }
+
+    /// Caches a document by its key, evicting the least recently used one if the cache
+    /// is full. Documents without a key are ignored.
+    pub fn insert(&self, document: T) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let id = match document.db_key() {
+            Some(v) => Self::cache_id(v),
+            None => return,
+        };
+
+        let mut state = self.state.lock().unwrap();
+        let tick = state.next_tick;
+        state.next_tick += 1;
+
+        if let Some((_, last_tick)) = state.entries.remove(&id) {
+            state.usages.remove(&last_tick);
+        } else if state.entries.len() >= self.capacity {
+            let oldest_tick = *state.usages.keys().next().unwrap();
+            let oldest_id = state.usages.remove(&oldest_tick).unwrap();
+            state.entries.remove(&oldest_id);
+        }
+
+        state.usages.insert(tick, id.clone());
+        state.entries.insert(id, (document, tick));
+    }
+
+    pub fn remove(&self, key: &T::Key) -> Option<T> {
+        let mut state = self.state.lock().unwrap();
+        let (document, tick) = state.entries.remove(&Self::cache_id(key))?;
+        state.usages.remove(&tick);
+
+        Some(document)
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.usages.clear();
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    fn cache_id(key: &T::Key) -> CacheId<T> {
+        DBId::new(key.clone(), T::Collection::name())
+    }
+}
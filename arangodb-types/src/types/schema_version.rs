@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+/// The version of the schema a document was written with, `V` being the current one.
+/// New documents default to the current version.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DBSchemaVersion<const V: u32>(u32);
+
+impl<const V: u32> DBSchemaVersion<V> {
+    pub const CURRENT: u32 = V;
+
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    pub fn new(version: u32) -> Self {
+        Self(version)
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn version(&self) -> u32 {
+        self.0
+    }
+
+    /// Whether the document was written with a previous version of the schema.
+    pub fn is_outdated(&self) -> bool {
+        self.0 < V
+    }
+}
+
+impl<const V: u32> Default for DBSchemaVersion<V> {
+    fn default() -> Self {
+        Self(V)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_serialization() {
+        let version = DBSchemaVersion::<3>::new(2);
+        let serialization = serde_json::to_string(&version).unwrap();
+
+        assert_eq!(serialization, "2");
+
+        let deserialization: DBSchemaVersion<3> = serde_json::from_str(&serialization).unwrap();
+        assert_eq!(deserialization, version);
+        assert!(deserialization.is_outdated());
+        assert!(!DBSchemaVersion::<3>::default().is_outdated());
+    }
+}
@@ -5,8 +5,10 @@ use std::hash::Hash;
 use std::str::FromStr;
 
 use arcstr::ArcStr;
+use chrono::Utc;
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 // Char set used to create the codes.
 // We do not use the default because it is not correctly sorted in DB.
@@ -55,6 +57,52 @@ impl DBUuid {
         DBUuid(nanoid!(length, &ALPHABET).into())
     }
 
+    /// Creates an id prefixed by the current timestamp so that ids sort, in DB too, by their
+    /// creation time. Ids created in the same millisecond are sorted randomly.
+    pub fn new_sortable() -> DBUuid {
+        Self::new_sortable_with_length(22)
+    }
+
+    /// Same as `new_sortable` but with a custom total `length`, timestamp prefix included.
+    pub fn new_sortable_with_length(length: usize) -> DBUuid {
+        let millis = Utc::now().timestamp_millis().max(0) as u64;
+        let mut id = encode_sortable_millis(millis);
+        let random_length = length.saturating_sub(id.len());
+        id.push_str(&nanoid!(random_length, &ALPHABET));
+
+        DBUuid(id.into())
+    }
+
+    /// Creates an id from the hash of `content`, so the same content always gets the same id.
+    /// Useful as the key of documents that must be deduplicated on insert.
+    pub fn from_content(content: &[u8]) -> DBUuid {
+        let hash = Sha256::digest(content);
+        let mut id = String::with_capacity(CONTENT_ID_LENGTH);
+        let mut buffer = 0_u32;
+        let mut bits = 0;
+
+        // Encode the hash 6 bits at a time.
+        for byte in hash {
+            buffer = (buffer << 8) | byte as u32;
+            bits += 8;
+
+            while bits >= 6 {
+                bits -= 6;
+                id.push(ALPHABET[((buffer >> bits) & 0x3F) as usize]);
+            }
+
+            if id.len() >= CONTENT_ID_LENGTH {
+                break;
+            }
+
+            buffer &= (1 << bits) - 1;
+        }
+
+        id.truncate(CONTENT_ID_LENGTH);
+
+        DBUuid(id.into())
+    }
+
     pub fn new_simple() -> DBUuid {
         Self::new_simple_with_length(23)
     }
@@ -84,6 +132,29 @@ impl DBUuid {
     pub fn as_string(&self) -> &ArcStr {
         &self.0
     }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Gets the exclusive upper bound of the keys starting with `prefix`, i.e. the prefix with
+    /// its last incrementable char replaced by the next one in the alphabet.
+    /// Returns `None` when every key greater than `prefix` starts with it.
+    pub fn prefix_upper_bound(prefix: &str) -> Option<String> {
+        let mut chars: Vec<char> = prefix.chars().collect();
+
+        while let Some(last) = chars.pop() {
+            let next = match ALPHABET.binary_search(&last) {
+                Ok(index) => ALPHABET.get(index + 1).copied(),
+                Err(_) => char::from_u32(last as u32 + 1),
+            };
+
+            if let Some(next) = next {
+                chars.push(next);
+                return Some(chars.into_iter().collect());
+            }
+        }
+
+        None
+    }
 }
 
 impl FromStr for DBUuid {
@@ -118,6 +189,20 @@ impl Default for DBUuid {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+// Number of chars of the ids created from a content hash.
+const CONTENT_ID_LENGTH: usize = 22;
+
+// Number of chars used to encode the timestamp of the sortable ids: 8 chars of 6 bits each.
+const SORTABLE_TIMESTAMP_LENGTH: usize = 8;
+
+/// Encodes `millis` with ALPHABET in a fixed length so that the encodings sort like the numbers.
+fn encode_sortable_millis(millis: u64) -> String {
+    (0..SORTABLE_TIMESTAMP_LENGTH)
+        .rev()
+        .map(|i| ALPHABET[((millis >> (i * 6)) & 0x3F) as usize])
+        .collect()
+}
+
 fn check_nanoid(s: &str) -> Result<(), &'static str> {
     for c in s.chars() {
         if ALPHABET.binary_search(&c).is_err() {
@@ -155,4 +240,50 @@ mod test {
 
         DBUuid::from_str("gidMh8J1aB00000000000002ñ").expect_err("The id must fail by character");
     }
+
+    #[test]
+    fn test_prefix_upper_bound() {
+        assert_eq!(DBUuid::prefix_upper_bound("ab"), Some("ac".to_string()));
+        assert_eq!(DBUuid::prefix_upper_bound("a9"), Some("aA".to_string()));
+        assert_eq!(DBUuid::prefix_upper_bound("aZ"), Some("a_".to_string()));
+        assert_eq!(DBUuid::prefix_upper_bound("az"), Some("b".to_string()));
+        assert_eq!(DBUuid::prefix_upper_bound("zz"), None);
+        assert_eq!(DBUuid::prefix_upper_bound(""), None);
+    }
+
+    #[test]
+    fn test_encode_sortable_millis() {
+        assert_eq!(encode_sortable_millis(0), "--------");
+        assert_eq!(encode_sortable_millis(1), "-------0");
+        assert_eq!(encode_sortable_millis(64), "------0-");
+
+        let mut previous = encode_sortable_millis(0);
+        for millis in [1, 63, 64, 65, 4095, 4096, 1_700_000_000_000, (1 << 48) - 1] {
+            let current = encode_sortable_millis(millis);
+            assert!(previous < current, "Incorrect order for {}", millis);
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn test_new_sortable() {
+        let first = DBUuid::new_sortable();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let second = DBUuid::new_sortable();
+
+        assert_eq!(first.as_string().len(), 22);
+        assert!(check_nanoid(first.as_string()).is_ok());
+        assert!(first < second, "The ids must be sorted by creation time");
+    }
+
+    #[test]
+    fn test_from_content() {
+        let id = DBUuid::from_content(b"content");
+
+        assert_eq!(id.as_string().len(), CONTENT_ID_LENGTH);
+        assert!(check_nanoid(id.as_string()).is_ok());
+        assert_eq!(id, DBUuid::from_content(b"content"));
+        assert_ne!(id, DBUuid::from_content(b"content2"));
+        assert_ne!(DBUuid::from_content(b""), DBUuid::from_content(b" "));
+    }
 }
@@ -9,6 +9,19 @@ pub enum DBMutexError {
     Other(anyhow::Error),
 }
 
+impl DBMutexError {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The HTTP status code that best describes the error.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            DBMutexError::NotFound => 404,
+            DBMutexError::Timeout => 409,
+            DBMutexError::Other(_) => 500,
+        }
+    }
+}
+
 impl Error for DBMutexError {}
 
 impl Display for DBMutexError {
@@ -26,3 +39,22 @@ impl From<anyhow::Error> for DBMutexError {
         DBMutexError::Other(e)
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_status_code() {
+        assert_eq!(DBMutexError::NotFound.status_code(), 404);
+        assert_eq!(DBMutexError::Timeout.status_code(), 409);
+        assert_eq!(
+            DBMutexError::Other(anyhow::anyhow!("error")).status_code(),
+            500
+        );
+    }
+}
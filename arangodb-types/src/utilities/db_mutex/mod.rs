@@ -1,7 +1,9 @@
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use arangors::document::options::OverwriteMode;
 use arcstr::ArcStr;
 use rand::Rng;
 use tokio::sync::Mutex;
@@ -11,20 +13,21 @@ use tokio::time::sleep;
 pub use errors::*;
 
 use crate::aql::{
-    AqlBuilder, AqlLet, AqlLetKind, AqlLimit, AqlReturn, AqlSort, AqlUpdate, AQL_DOCUMENT_ID,
-    AQL_NEW_ID,
+    AqlBuilder, AqlInsert, AqlLet, AqlLetKind, AqlLimit, AqlReturn, AqlSort, AqlUpdate,
+    AQL_DOCUMENT_ID, AQL_NEW_ID,
 };
 use crate::constants::{
-    MUTEX_ACQUIRE_MAX_INTERVAL, MUTEX_ACQUIRE_MIN_INTERVAL, MUTEX_ALIVE_INTERVAL, MUTEX_EXPIRATION,
+    MUTEX_ACQUIRE_BATCH_SIZE, MUTEX_ACQUIRE_MAX_INTERVAL, MUTEX_ACQUIRE_MIN_INTERVAL,
+    MUTEX_ALIVE_INTERVAL, MUTEX_ALIVE_JITTER, MUTEX_EXPIRATION,
 };
 use crate::documents::DBDocumentField;
 use crate::traits::{DBCollection, DBSynchronizedDocument};
-use crate::types::{DBDateTime, DBMutex, DBMutexField, DBUuid, NullableOption};
+use crate::types::{DBDateTime, DBDuration, DBMutex, DBMutexField, DBUuid, NullableOption};
 
 mod errors;
 
-#[derive(Clone)]
 pub struct DBMutexGuard<T: 'static + DBSynchronizedDocument<'static>> {
+    handles: Arc<AtomicUsize>,
     inner: Arc<Mutex<BDMutexGuardInner<T>>>,
 }
 
@@ -48,6 +51,7 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
         collection: &Arc<T::Collection>,
     ) -> DBMutexGuard<T> {
         let guard = Self {
+            handles: Arc::new(AtomicUsize::new(1)),
             inner: Arc::new(Mutex::new(BDMutexGuardInner {
                 node_id: node_id.clone(),
                 elements: {
@@ -70,7 +74,7 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
         guard
     }
 
-    /// Acquires a single document optionally with a timeout.
+    /// Acquires a single document optionally with a timeout in seconds.
     pub async fn acquire_document(
         key: &T::Key,
         node_id: &ArcStr,
@@ -78,7 +82,26 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
         timeout: Option<u64>,
         collection: &Arc<T::Collection>,
     ) -> Result<(T, DBMutexGuard<T>), DBMutexError> {
-        let time_out = timeout.map(|v| DBDateTime::now().after_seconds(v));
+        Self::acquire_document_with_timeout(
+            key,
+            node_id,
+            fields,
+            timeout.map(Duration::from_secs),
+            collection,
+        )
+        .await
+    }
+
+    /// Acquires a single document optionally with a timeout.
+    pub async fn acquire_document_with_timeout(
+        key: &T::Key,
+        node_id: &ArcStr,
+        fields: Option<&T>,
+        timeout: Option<Duration>,
+        collection: &Arc<T::Collection>,
+    ) -> Result<(T, DBMutexGuard<T>), DBMutexError> {
+        // A deadline beyond the representable range is the same as no timeout.
+        let time_out = timeout.and_then(|v| DBDateTime::now().after_duration_checked(v));
         let mut checked_doc_exists = false;
 
         loop {
@@ -120,7 +143,7 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
         }
     }
 
-    /// Acquires a single document optionally with a timeout.
+    /// Acquires a single document optionally with a timeout in seconds, creating it if missing.
     pub async fn acquire_or_create_document<F: FnOnce() -> T>(
         key: &T::Key,
         node_id: &ArcStr,
@@ -129,52 +152,89 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
         collection: &Arc<T::Collection>,
         default: F,
     ) -> Result<(T, DBMutexGuard<T>), DBMutexError> {
-        match Self::acquire_document(key, node_id, fields, timeout, collection).await {
-            Ok(v) => Ok(v),
-            Err(e) => {
-                match e {
-                    DBMutexError::NotFound => {
-                        // Persist document with mutex.
-                        let mut document = default();
-                        let now = DBDateTime::now();
-                        let expiration = now.after_seconds(MUTEX_EXPIRATION);
-                        let change_flag = DBUuid::new();
-
-                        document.set_mutex(NullableOption::Value(DBMutex {
-                            node: node_id.clone(),
-                            expiration,
-                            change_flag: change_flag.clone(),
-                        }));
-
-                        let final_document = document.insert(false, collection).await?;
-
-                        let guard = Self {
-                            inner: Arc::new(Mutex::new(BDMutexGuardInner {
-                                node_id: node_id.clone(),
-                                elements: {
-                                    let mut set = HashSet::new();
-                                    set.insert(final_document.db_key().clone().unwrap());
-                                    set
-                                },
-                                change_flag,
-                                alive_job: None,
-                                collection: collection.clone(),
-                            })),
-                        };
-
-                        // Launch alive action.
-                        {
-                            let mut lock = guard.inner.lock().await;
-                            lock.alive_job =
-                                Some(tokio::spawn(Self::alive_action(guard.inner.clone())));
-                        }
+        Self::acquire_or_create_document_with_timeout(
+            key,
+            node_id,
+            fields,
+            timeout.map(Duration::from_secs),
+            collection,
+            default,
+        )
+        .await
+    }
 
-                        Ok((final_document, guard))
-                    }
-                    DBMutexError::Timeout => Err(DBMutexError::Timeout),
-                    DBMutexError::Other(e) => Err(DBMutexError::Other(e)),
+    /// Acquires a single document optionally with a timeout, creating it if missing.
+    /// The document is created already locked and only if it does not exist, so concurrent
+    /// calls create it only once. `default` is only called when the document is missing.
+    pub async fn acquire_or_create_document_with_timeout<F: FnOnce() -> T>(
+        key: &T::Key,
+        node_id: &ArcStr,
+        fields: Option<&T>,
+        timeout: Option<Duration>,
+        collection: &Arc<T::Collection>,
+        default: F,
+    ) -> Result<(T, DBMutexGuard<T>), DBMutexError> {
+        let started_at = Instant::now();
+        let mut default = Some(default);
+        let mut document: Option<T> = None;
+
+        loop {
+            let remaining = timeout.map(|v| v.saturating_sub(started_at.elapsed()));
+            match Self::acquire_document_with_timeout(key, node_id, fields, remaining, collection)
+                .await
+            {
+                Err(DBMutexError::NotFound) => {}
+                result => return result,
+            }
+
+            // Prepare the document to insert only once.
+            if document.is_none() {
+                let mut new_document = (default.take().unwrap())();
+                new_document.set_db_key(Some(key.clone()));
+                new_document.touch_timestamps(true);
+
+                if T::validate_on_write() {
+                    new_document.validate()?;
+                }
+
+                document = Some(new_document);
+            }
+
+            let change_flag = DBUuid::new();
+            if let Some(final_document) = Self::insert_locked_document(
+                document.as_ref().unwrap(),
+                node_id,
+                fields,
+                &change_flag,
+                collection,
+            )
+            .await?
+            {
+                let guard = Self {
+                    handles: Arc::new(AtomicUsize::new(1)),
+                    inner: Arc::new(Mutex::new(BDMutexGuardInner {
+                        node_id: node_id.clone(),
+                        elements: {
+                            let mut set = HashSet::new();
+                            set.insert(key.clone());
+                            set
+                        },
+                        change_flag,
+                        alive_job: None,
+                        collection: collection.clone(),
+                    })),
+                };
+
+                // Launch alive action.
+                {
+                    let mut lock = guard.inner.lock().await;
+                    lock.alive_job = Some(tokio::spawn(Self::alive_action(guard.inner.clone())));
                 }
+
+                return Ok((final_document, guard));
             }
+
+            // Created by another caller in the meantime, so acquire it as any other document.
         }
     }
 
@@ -191,6 +251,7 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
             return Ok((
                 Vec::new(),
                 Self {
+                    handles: Arc::new(AtomicUsize::new(1)),
                     inner: Arc::new(Mutex::new(BDMutexGuardInner {
                         node_id: node_id.clone(),
                         elements: HashSet::new(),
@@ -202,102 +263,35 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
             ));
         }
 
-        let collection_name = T::Collection::name();
-        let mutex_path = DBDocumentField::Mutex.path();
-
-        let now = DBDateTime::now();
-        let expiration = now.after_seconds(MUTEX_EXPIRATION);
-
-        // FOR i IN <keys>
-        //     LET o = Document(<collection>, i)
-        //     FILTER o != null && o.<mutex.expiration> <= <now>
-        //     UPDATE i WITH { <mutex>: { <node>: <node_id>, <expiration>: <expiration>, <change_flag>: <change_flag> } } IN <collection> OPTIONS { mergeObjects: true, ignoreErrors: true }
-        //     RETURN NEW
-        let document_key = "o";
         let change_flag = DBUuid::new();
-        let mut aql = AqlBuilder::new_for_in_list(AQL_DOCUMENT_ID, keys);
-        aql.let_step(AqlLet {
-            variable: document_key,
-            expression: AqlLetKind::Expression(
-                format!("DOCUMENT({}, {})", collection_name, AQL_DOCUMENT_ID).into(),
-            ),
-        });
-        aql.filter_step(
-            format!(
-                "{} != null && {}.{}.{} <= {}",
-                document_key,
-                document_key,
-                mutex_path,
-                DBMutexField::Expiration(None).path(),
-                serde_json::to_string(&now).unwrap()
-            )
-            .into(),
-        );
-        aql.update_step(
-            AqlUpdate::new(
-                AQL_DOCUMENT_ID.into(),
-                collection_name,
-                format!(
-                    "{{ {}: {{ {}: {}, {}: {}, {}: {} }} }}",
-                    mutex_path,
-                    DBMutexField::Node(None).path(),
-                    serde_json::to_string(node_id).unwrap(),
-                    DBMutexField::Expiration(None).path(),
-                    serde_json::to_string(&expiration).unwrap(),
-                    DBMutexField::ChangeFlag(None).path(),
-                    serde_json::to_string(&change_flag).unwrap()
-                )
-                .into(),
-            )
-            .apply_ignore_errors(true),
-        );
-
-        if let Some(fields) = fields {
-            aql.return_step_with_fields(AQL_NEW_ID, fields);
-        } else {
-            aql.return_step(AqlReturn::new_updated());
-        }
-
-        let result = collection.send_generic_aql::<Option<T>>(&aql).await?;
-        let result_ids = result
-            .results
-            .iter()
-            .filter_map(|v| match v {
-                Some(v) => v.db_key().clone(),
-                None => None,
-            })
-            .collect();
-
         let guard = Self {
+            handles: Arc::new(AtomicUsize::new(1)),
             inner: Arc::new(Mutex::new(BDMutexGuardInner {
                 node_id: node_id.clone(),
-                elements: result_ids,
-                change_flag,
-                alive_job: None,
+                elements: HashSet::new(),
+                change_flag: change_flag.clone(),
+                alive_job: Some(tokio::spawn(async {})),
                 collection: collection.clone(),
             })),
         };
 
-        // Adjust the result list to contain every element in its position.
-        let mut index = 0;
-        let mut results: Vec<Option<T>> = result.results;
-        for key in keys {
-            let result = match results.get(index) {
-                Some(Some(v)) => v,
-                Some(None) => {
-                    continue;
-                }
-                None => {
-                    results.push(None);
-                    continue;
-                }
-            };
+        // Lock the keys in batches to avoid huge queries. If any batch fails, the
+        // guard is dropped releasing the already locked keys.
+        let mut results = Vec::with_capacity(keys.len());
+        for batch in keys.chunks(MUTEX_ACQUIRE_BATCH_SIZE) {
+            let batch_results =
+                Self::acquire_batch(batch, node_id, fields, &change_flag, collection).await?;
 
-            if result.db_key().as_ref() != Some(key) {
-                results.insert(index, None);
+            {
+                let mut lock = guard.inner.lock().await;
+                lock.elements.extend(
+                    batch_results
+                        .iter()
+                        .filter_map(|v| v.as_ref().and_then(|v| v.db_key().clone())),
+                );
             }
 
-            index += 1;
+            results.extend(batch_results);
         }
 
         // Launch alive action.
@@ -309,6 +303,19 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
         Ok((results, guard))
     }
 
+    /// Like `acquire_list` but returns only the locked documents, skipping those that are
+    /// missing or already locked.
+    pub async fn acquire_list_available(
+        keys: &[T::Key],
+        node_id: &ArcStr,
+        fields: Option<&T>,
+        collection: &Arc<T::Collection>,
+    ) -> Result<(Vec<T>, DBMutexGuard<T>), anyhow::Error> {
+        let (results, guard) = Self::acquire_list(keys, node_id, fields, collection).await?;
+
+        Ok((results.into_iter().flatten().collect(), guard))
+    }
+
     /// Acquires a list of documents filtering them using a limited AQL.
     pub async fn acquire_aql(
         filter: Option<&str>,
@@ -329,7 +336,7 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
         //     FILTER i.<mutex.expiration> <= <now>
         //     <custom_sort>
         //     <custom_limit>
-        //     UPDATE i WITH { <mutex>: { <node>: <node_id>, <expiration>: <expiration>, <change_flag>: <change_flag> } } IN <collection> OPTIONS { mergeObjects: true, ignoreErrors: true }
+        //     UPDATE i WITH { <mutex>: { <node>: <node_id>, <expiration>: <expiration>, <change_flag>: <change_flag> } } IN <collection> OPTIONS { mergeObjects: true, ignoreErrors: true, exclusive: <exclusive> }
         //     FILTER NEW != null
         //     RETURN NEW
         let change_flag = DBUuid::new();
@@ -372,7 +379,8 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
                 )
                 .into(),
             )
-            .apply_ignore_errors(true),
+            .apply_ignore_errors(true)
+            .apply_exclusive(T::EXCLUSIVE_MUTEX),
         );
         aql.filter_step(format!("{} != null", AQL_NEW_ID).into());
 
@@ -383,13 +391,10 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
         }
 
         let result = collection.send_generic_aql::<T>(&aql).await?;
-        let result_ids = result
-            .results
-            .iter()
-            .map(|v| v.db_key().as_ref().unwrap().clone())
-            .collect();
+        let result_ids = result.results.iter().map(|v| v.key()).collect();
 
         let guard = Self {
+            handles: Arc::new(AtomicUsize::new(1)),
             inner: Arc::new(Mutex::new(BDMutexGuardInner {
                 node_id: node_id.clone(),
                 elements: result_ids,
@@ -416,6 +421,15 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
         lock.elements.is_empty()
     }
 
+    /// Whether the alive job is still keeping the mutex alive or not.
+    pub async fn is_alive(&self) -> bool {
+        let lock = self.inner.lock().await;
+        lock.alive_job
+            .as_ref()
+            .map(|v| !v.is_finished())
+            .unwrap_or(false)
+    }
+
     // METHODS ----------------------------------------------------------------
 
     /// Checks whether a document is locked or not.
@@ -483,6 +497,7 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
 
         let guard = if new_elements.is_empty() {
             Self {
+                handles: Arc::new(AtomicUsize::new(1)),
                 inner: Arc::new(Mutex::new(BDMutexGuardInner {
                     node_id: lock.node_id.clone(),
                     elements: new_elements,
@@ -493,6 +508,7 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
             }
         } else {
             let guard = Self {
+                handles: Arc::new(AtomicUsize::new(1)),
                 inner: Arc::new(Mutex::new(BDMutexGuardInner {
                     node_id: lock.node_id.clone(),
                     elements: new_elements,
@@ -514,135 +530,59 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
         Some(guard)
     }
 
-    /// Manually releases the mutex.
-    pub fn release(self) {
-        tokio::spawn(Self::release_action(self.inner.clone()));
-    }
-
-    // STATIC METHODS ---------------------------------------------------------
-
-    async fn alive_action(mutex: Arc<Mutex<BDMutexGuardInner<T>>>) {
-        loop {
-            // Sleep for interval.
-            sleep(Duration::from_secs(MUTEX_ALIVE_INTERVAL)).await;
-
-            let mut lock = mutex.lock().await;
-            if lock.alive_job.is_none() {
-                // The mutex has been already released.
-                return;
-            }
-
-            // Avoid doing unnecessary DB requests.
-            if lock.elements.is_empty() {
-                return;
-            }
-
-            let collection = &lock.collection;
-            let node_id = &lock.node_id;
-            let now = DBDateTime::now();
-            let expiration = now.after_seconds(MUTEX_EXPIRATION);
-            let keys = &lock.elements;
-
-            // FOR i IN <keys>
-            //     LET o = Document(<collection>, i)
-            //     FILTER o != null && o.<mutex.node> == <node> && o.<mutex.change_flag> == <change_flag>
-            //     UPDATE i WITH { <mutex>: { <expiration>: <expiration> } } IN <collection> OPTIONS { mergeObjects: true, ignoreErrors: true }
-            //     FILTER NEW != null
-            //     RETURN i
-            let document_key = "o";
-            let collection_name = T::Collection::name();
-            let mutex_path = DBDocumentField::Mutex.path();
-            let mut aql = AqlBuilder::new_for_in_set(AQL_DOCUMENT_ID, keys);
-            aql.let_step(AqlLet {
-                variable: document_key,
-                expression: AqlLetKind::Expression(
-                    format!("DOCUMENT({}, {})", collection_name, AQL_DOCUMENT_ID).into(),
-                ),
-            });
-            aql.filter_step(
-                format!(
-                    "{} != null && {}.{}.{} == {} && {}.{}.{} == {}",
-                    document_key,
-                    document_key,
-                    mutex_path,
-                    DBMutexField::Node(None).path(),
-                    serde_json::to_string(&node_id).unwrap(),
-                    document_key,
-                    mutex_path,
-                    DBMutexField::ChangeFlag(None).path(),
-                    serde_json::to_string(&lock.change_flag).unwrap(),
-                )
-                .into(),
-            );
-            aql.update_step(
-                AqlUpdate::new_document(
-                    collection_name,
-                    format!(
-                        "{{ {}: {{ {}: {} }} }}",
-                        mutex_path,
-                        DBMutexField::Expiration(None).path(),
-                        serde_json::to_string(&expiration).unwrap(),
-                    )
-                    .into(),
-                )
-                .apply_ignore_errors(true),
-            );
-            aql.filter_step(format!("{} != null", AQL_NEW_ID).into());
-            aql.return_step(AqlReturn::new_document());
-
-            let result = match collection.send_generic_aql::<T::Key>(&aql).await {
-                Ok(v) => v.results,
-                Err(e) => {
-                    let keys = keys.iter().map(|v| v.to_string()).collect::<Vec<_>>();
-                    lock.alive_job.take().unwrap().abort();
-                    log::error!(
-                        "Error while keeping alive document mutexes in DB. Keys: {:?}, Error: {}",
-                        keys,
-                        e
-                    );
-                    return;
-                }
-            };
-            let result: HashSet<_> = result.into_iter().collect();
+    /// Moves the keys of `other` into the current mutex, so a single alive job keeps all of
+    /// them alive. Both mutexes must belong to the same node. The keys of `other` take the change
+    /// flag of the current mutex in DB and those no longer locked by `other` are discarded.
+    /// `other` and the handles sharing it end up empty, except if the merge fails, in which case
+    /// `other` keeps locking its keys.
+    pub async fn merge(&mut self, other: &DBMutexGuard<T>) -> Result<(), DBMutexError> {
+        // Both guards share the same lease.
+        if Arc::ptr_eq(&self.inner, &other.inner) {
+            return Ok(());
+        }
 
-            if result.is_empty() {
-                lock.alive_job.take().unwrap().abort();
-                return;
-            }
+        // Lock both mutexes in a stable order so a concurrent merge in the opposite direction
+        // cannot deadlock.
+        let (mut lock, mut other_lock) = if Arc::as_ptr(&self.inner) < Arc::as_ptr(&other.inner) {
+            let lock = self.inner.lock().await;
+            (lock, other.inner.lock().await)
+        } else {
+            let other_lock = other.inner.lock().await;
+            (self.inner.lock().await, other_lock)
+        };
 
-            lock.elements = result;
+        if lock.node_id != other_lock.node_id {
+            return Err(DBMutexError::Other(anyhow::anyhow!(
+                "Cannot merge mutexes of different nodes: {} and {}",
+                lock.node_id,
+                other_lock.node_id
+            )));
         }
-    }
 
-    async fn release_action(mutex: Arc<Mutex<BDMutexGuardInner<T>>>) {
-        let mut lock = mutex.lock().await;
-        if lock.alive_job.is_none() {
-            // The mutex has been already released.
-            return;
+        // Stop keeping alive the keys of the other mutex. Without alive job, its release action
+        // does nothing.
+        if let Some(alive_job) = other_lock.alive_job.take() {
+            alive_job.abort();
         }
 
-        // Abort the alive job.
-        lock.alive_job.take().unwrap().abort();
-
-        // Avoid doing unnecessary DB requests.
-        if lock.elements.is_empty() {
-            return;
+        let keys = std::mem::take(&mut other_lock.elements);
+        if keys.is_empty() {
+            return Ok(());
         }
 
-        let collection = &lock.collection;
-        let node_id = &lock.node_id;
-        let keys = &lock.elements;
+        let now = DBDateTime::now();
+        let expiration = now.after_seconds(MUTEX_EXPIRATION);
 
         // FOR i IN <keys>
         //     LET o = Document(<collection>, i)
-        //     FILTER o != null && o.<mutex.node> == <node> && o.<mutex.change_flag> == <change_flag>
-        //     UPDATE i WITH { <mutex>: null } IN <collection> OPTIONS { mergeObjects: true, keepNulls: false, ignoreErrors: true }
+        //     FILTER o != null && o.<mutex.node> == <node> && o.<mutex.change_flag> == <other_change_flag>
+        //     UPDATE i WITH { <mutex>: { <change_flag>: <change_flag>, <expiration>: <expiration> } } IN <collection> OPTIONS { mergeObjects: true, ignoreErrors: true }
         //     FILTER NEW != null
         //     RETURN i
         let document_key = "o";
         let collection_name = T::Collection::name();
         let mutex_path = DBDocumentField::Mutex.path();
-        let mut aql = AqlBuilder::new_for_in_set(AQL_DOCUMENT_ID, keys);
+        let mut aql = AqlBuilder::new_for_in_set(AQL_DOCUMENT_ID, &keys);
         aql.let_step(AqlLet {
             variable: document_key,
             expression: AqlLetKind::Expression(
@@ -656,39 +596,654 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
                 document_key,
                 mutex_path,
                 DBMutexField::Node(None).path(),
-                serde_json::to_string(node_id).unwrap(),
+                serde_json::to_string(&lock.node_id).unwrap(),
                 document_key,
                 mutex_path,
                 DBMutexField::ChangeFlag(None).path(),
-                serde_json::to_string(&lock.change_flag).unwrap(),
+                serde_json::to_string(&other_lock.change_flag).unwrap(),
             )
             .into(),
         );
         aql.update_step(
             AqlUpdate::new_document(
                 collection_name,
-                format!("{{ {}: null }}", mutex_path).into(),
-            )
-            .apply_ignore_errors(true),
-        );
-        aql.filter_step(format!("{} != null", AQL_NEW_ID).into());
-        aql.return_step(AqlReturn::new_document());
-
+                format!(
+                    "{{ {}: {{ {}: {}, {}: {} }} }}",
+                    mutex_path,
+                    DBMutexField::ChangeFlag(None).path(),
+                    serde_json::to_string(&lock.change_flag).unwrap(),
+                    DBMutexField::Expiration(None).path(),
+                    serde_json::to_string(&expiration).unwrap(),
+                )
+                .into(),
+            )
+            .apply_ignore_errors(true),
+        );
+        aql.filter_step(format!("{} != null", AQL_NEW_ID).into());
+        aql.return_step(AqlReturn::new_document());
+
+        let result = match lock.collection.send_generic_aql::<T::Key>(&aql).await {
+            Ok(v) => v.results,
+            Err(e) => {
+                // Give the keys back so the other mutex keeps locking them.
+                other_lock.elements = keys;
+                other_lock.alive_job = Some(tokio::spawn(Self::alive_action(other.inner.clone())));
+                return Err(e.into());
+            }
+        };
+
+        lock.elements.extend(result);
+
+        // Relaunch the alive job if the current mutex was empty.
+        let is_alive = lock
+            .alive_job
+            .as_ref()
+            .map(|v| !v.is_finished())
+            .unwrap_or(false);
+
+        if !is_alive && !lock.elements.is_empty() {
+            lock.alive_job = Some(tokio::spawn(Self::alive_action(self.inner.clone())));
+        }
+
+        Ok(())
+    }
+
+    /// Updates a locked document refreshing its mutex expiration in the same request.
+    /// Fails with `NotFound` if the document is not locked by this mutex anymore.
+    pub async fn update_document(
+        &self,
+        document: &T,
+        merge_objects: bool,
+    ) -> Result<T, DBMutexError> {
+        let lock = self.inner.lock().await;
+
+        let key = document.db_key().as_ref().unwrap_or_else(|| {
+            panic!(
+                "You forgot to include the key property in the {} document",
+                T::Collection::name()
+            )
+        });
+
+        if !lock.elements.contains(key) {
+            return Err(DBMutexError::NotFound);
+        }
+
+        let collection = &lock.collection;
+        let node_id = &lock.node_id;
+        let now = DBDateTime::now();
+        let expiration = now.after_seconds(MUTEX_EXPIRATION);
+
+        let mut document = document.clone();
+        document.touch_timestamps(false);
+
+        if T::validate_on_write() {
+            document.validate()?;
+        }
+
+        document.set_mutex(NullableOption::Value(DBMutex {
+            node: node_id.clone(),
+            expiration,
+            change_flag: lock.change_flag.clone(),
+        }));
+
+        // FOR i IN [<key>]
+        //     LET o = Document(<collection>, i)
+        //     FILTER o != null && o.<mutex.node> == <node> && o.<mutex.change_flag> == <change_flag>
+        //     UPDATE i WITH <document> IN <collection> OPTIONS { mergeObjects: <merge_objects>, keepNulls: false, ignoreErrors: true }
+        //     FILTER NEW != null
+        //     RETURN NEW
+        let document_key = "o";
+        let collection_name = T::Collection::name();
+        let mutex_path = DBDocumentField::Mutex.path();
+        let keys = [key.clone()];
+        let mut aql = AqlBuilder::new_for_in_list(AQL_DOCUMENT_ID, &keys);
+        let document_variable = aql
+            .add_variable(&document)
+            .map_err(anyhow::Error::from)?
+            .unwrap();
+        aql.let_step(AqlLet {
+            variable: document_key,
+            expression: AqlLetKind::Expression(
+                format!("DOCUMENT({}, {})", collection_name, AQL_DOCUMENT_ID).into(),
+            ),
+        });
+        aql.filter_step(
+            format!(
+                "{} != null && {}.{}.{} == {} && {}.{}.{} == {}",
+                document_key,
+                document_key,
+                mutex_path,
+                DBMutexField::Node(None).path(),
+                serde_json::to_string(node_id).unwrap(),
+                document_key,
+                mutex_path,
+                DBMutexField::ChangeFlag(None).path(),
+                serde_json::to_string(&lock.change_flag).unwrap(),
+            )
+            .into(),
+        );
+        aql.update_step(
+            AqlUpdate::new_document(collection_name, document_variable.into())
+                .apply_ignore_revs(document.db_rev().is_none())
+                .apply_merge_objects(merge_objects)
+                .apply_ignore_errors(true),
+        );
+        aql.filter_step(format!("{} != null", AQL_NEW_ID).into());
+        aql.return_step(AqlReturn::new_updated());
+
+        let mut result = collection.send_generic_aql::<T>(&aql).await?;
+
+        match result.results.pop() {
+            Some(v) => Ok(v),
+            None => Err(DBMutexError::NotFound),
+        }
+    }
+
+    /// Gets the current version of a locked document from DB, checking the mutex still locks it.
+    /// Fails with `NotFound` if the document is not locked by this mutex anymore and returns
+    /// `None` if the document has been removed.
+    pub async fn reload(&self, key: &T::Key) -> Result<Option<T>, DBMutexError> {
+        let lock = self.inner.lock().await;
+
+        if !lock.elements.contains(key) {
+            return Err(DBMutexError::NotFound);
+        }
+
+        let collection = &lock.collection;
+        let node_id = &lock.node_id;
+
+        // FOR i IN [<key>]
+        //     LET o = Document(<collection>, i)
+        //     FILTER o == null || (o.<mutex.node> == <node> && o.<mutex.change_flag> == <change_flag>)
+        //     RETURN o
+        let document_key = "o";
+        let collection_name = T::Collection::name();
+        let mutex_path = DBDocumentField::Mutex.path();
+        let keys = [key.clone()];
+        let mut aql = AqlBuilder::new_for_in_list(AQL_DOCUMENT_ID, &keys);
+        aql.let_step(AqlLet {
+            variable: document_key,
+            expression: AqlLetKind::Expression(
+                format!("DOCUMENT({}, {})", collection_name, AQL_DOCUMENT_ID).into(),
+            ),
+        });
+        aql.filter_step(
+            format!(
+                "{} == null || ({}.{}.{} == {} && {}.{}.{} == {})",
+                document_key,
+                document_key,
+                mutex_path,
+                DBMutexField::Node(None).path(),
+                serde_json::to_string(node_id).unwrap(),
+                document_key,
+                mutex_path,
+                DBMutexField::ChangeFlag(None).path(),
+                serde_json::to_string(&lock.change_flag).unwrap(),
+            )
+            .into(),
+        );
+        aql.return_step(AqlReturn::new_expression(document_key.into()));
+
+        let mut result = collection.send_generic_aql::<Option<T>>(&aql).await?;
+
+        match result.results.pop() {
+            Some(v) => Ok(v),
+            None => Err(DBMutexError::NotFound),
+        }
+    }
+
+    /// Gets the minimum remaining time, in seconds, until any of the locked documents expires.
+    /// Useful to renew the mutex before it is lost. None is returned when no document is locked.
+    pub async fn min_remaining(&self) -> Result<Option<DBDuration>, anyhow::Error> {
+        let lock = self.inner.lock().await;
+
+        if lock.elements.is_empty() {
+            return Ok(None);
+        }
+
+        let collection = &lock.collection;
+        let node_id = &lock.node_id;
+
+        // FOR i IN <keys>
+        //     LET o = Document(<collection>, i)
+        //     FILTER o != null && o.<mutex.node> == <node> && o.<mutex.change_flag> == <change_flag>
+        //     SORT o.<mutex.expiration>
+        //     LIMIT 1
+        //     RETURN o.<mutex.expiration>
+        let document_key = "o";
+        let collection_name = T::Collection::name();
+        let mutex_path = DBDocumentField::Mutex.path();
+        let keys: Vec<_> = lock.elements.iter().collect();
+        let mut aql = AqlBuilder::new_for_in_list(AQL_DOCUMENT_ID, &keys);
+        aql.let_step(AqlLet {
+            variable: document_key,
+            expression: AqlLetKind::Expression(
+                format!("DOCUMENT({}, {})", collection_name, AQL_DOCUMENT_ID).into(),
+            ),
+        });
+        aql.filter_step(
+            format!(
+                "{} != null && {}.{}.{} == {} && {}.{}.{} == {}",
+                document_key,
+                document_key,
+                mutex_path,
+                DBMutexField::Node(None).path(),
+                serde_json::to_string(node_id).unwrap(),
+                document_key,
+                mutex_path,
+                DBMutexField::ChangeFlag(None).path(),
+                serde_json::to_string(&lock.change_flag).unwrap(),
+            )
+            .into(),
+        );
+        let expiration_path = format!(
+            "{}.{}.{}",
+            document_key,
+            mutex_path,
+            DBMutexField::Expiration(None).path()
+        );
+        aql.sort_step(vec![AqlSort {
+            is_descending: false,
+            expression: expiration_path.clone().into(),
+        }]);
+        aql.limit_step(AqlLimit {
+            offset: None,
+            count: 1,
+        });
+        aql.return_step(AqlReturn::new_expression(expiration_path.into()));
+
+        let mut result = collection.send_generic_aql::<DBDateTime>(&aql).await?;
+
+        Ok(result.results.pop().map(|expiration| {
+            let remaining = expiration.0 - DBDateTime::now().0;
+            DBDuration::from(remaining.num_seconds().max(0) as u64)
+        }))
+    }
+
+    /// Creates another handle sharing the same lease. The mutex is only released automatically
+    /// when the last handle is dropped.
+    pub fn clone_handle(&self) -> DBMutexGuard<T> {
+        self.handles.fetch_add(1, Ordering::AcqRel);
+
+        Self {
+            handles: self.handles.clone(),
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Re-spawns the alive job if it has died while the mutex still locks any document.
+    /// Returns whether the job has been re-spawned.
+    pub async fn heal(&self) -> bool {
+        let mut lock = self.inner.lock().await;
+        let is_alive = lock
+            .alive_job
+            .as_ref()
+            .map(|v| !v.is_finished())
+            .unwrap_or(false);
+
+        if is_alive || lock.elements.is_empty() {
+            return false;
+        }
+
+        lock.alive_job = Some(tokio::spawn(Self::alive_action(self.inner.clone())));
+        true
+    }
+
+    /// Runs `action` over a locked document reverting the in-memory changes if it fails.
+    /// The DB is not touched.
+    pub fn with_rollback<R, E, F>(&self, document: &mut T, action: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut T) -> Result<R, E>,
+    {
+        let snapshot = document.snapshot();
+
+        match action(document) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                document.restore(snapshot);
+                Err(e)
+            }
+        }
+    }
+
+    /// Consumes the guard without releasing the mutex, that is kept in DB until it expires.
+    /// Other handles sharing it are detached too, so none of them releases it on drop.
+    pub async fn into_detached(self) {
+        let mut lock = self.inner.lock().await;
+
+        // Without alive job the release action does nothing.
+        if let Some(alive_job) = lock.alive_job.take() {
+            alive_job.abort();
+        }
+
+        // Forget the keys so the mutex cannot be healed after being detached.
+        lock.elements.clear();
+    }
+
+    /// Manually releases the mutex, even if other handles share it.
+    pub fn release(self) {
+        tokio::spawn(Self::release_action(self.inner.clone()));
+    }
+
+    /// Manually releases the mutex, even if other handles share it, waiting until it is released
+    /// in DB. Returns the keys that were locked so they can be logged or retried later.
+    pub async fn release_returning(self) -> Vec<T::Key> {
+        Self::release_action(self.inner.clone()).await
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Inserts `document` locked by a new mutex if it does not exist. Returns None without
+    /// writing anything if the document already exists.
+    async fn insert_locked_document(
+        document: &T,
+        node_id: &ArcStr,
+        fields: Option<&T>,
+        change_flag: &DBUuid,
+        collection: &Arc<T::Collection>,
+    ) -> Result<Option<T>, anyhow::Error> {
+        let collection_name = T::Collection::name();
+
+        let now = DBDateTime::now();
+        let expiration = now.after_seconds(MUTEX_EXPIRATION);
+
+        let mut document = document.clone();
+        document.set_mutex(NullableOption::Value(DBMutex {
+            node: node_id.clone(),
+            expiration,
+            change_flag: change_flag.clone(),
+        }));
+
+        // INSERT <document> INTO <collection> OPTIONS { overwriteMode: "conflict", ignoreErrors: true }
+        //     FILTER NEW != null
+        //     RETURN NEW
+        let mut aql = AqlBuilder::new_simple();
+
+        // The insert fails silently with a unique constraint violation if the document exists.
+        aql.insert_step(
+            AqlInsert::new(
+                collection_name,
+                serde_json::to_string(&document).unwrap().into(),
+            )
+            .apply_overwrite_mode(OverwriteMode::Conflict)
+            .apply_ignore_errors(true),
+        );
+        aql.filter_step(format!("{} != null", AQL_NEW_ID).into());
+
+        if let Some(fields) = fields {
+            aql.return_step_with_fields(AQL_NEW_ID, fields);
+        } else {
+            aql.return_step(AqlReturn::new_updated());
+        }
+
+        aql.set_handle_write_conflicts(true);
+
+        let mut result = collection.send_generic_aql::<T>(&aql).await?;
+
+        Ok(result.results.pop())
+    }
+
+    /// Locks a batch of keys returning the documents in the same order as the keys.
+    async fn acquire_batch(
+        keys: &[T::Key],
+        node_id: &ArcStr,
+        fields: Option<&T>,
+        change_flag: &DBUuid,
+        collection: &Arc<T::Collection>,
+    ) -> Result<Vec<Option<T>>, anyhow::Error> {
+        let collection_name = T::Collection::name();
+        let mutex_path = DBDocumentField::Mutex.path();
+
+        let now = DBDateTime::now();
+        let expiration = now.after_seconds(MUTEX_EXPIRATION);
+
+        // FOR i IN <keys>
+        //     LET o = Document(<collection>, i)
+        //     FILTER o != null && o.<mutex.expiration> <= <now>
+        //     UPDATE i WITH { <mutex>: { <node>: <node_id>, <expiration>: <expiration>, <change_flag>: <change_flag> } } IN <collection> OPTIONS { mergeObjects: true, ignoreErrors: true, exclusive: <exclusive> }
+        //     RETURN NEW
+        let document_key = "o";
+        let mut aql = AqlBuilder::new_for_in_list(AQL_DOCUMENT_ID, keys);
+        aql.let_step(AqlLet {
+            variable: document_key,
+            expression: AqlLetKind::Expression(
+                format!("DOCUMENT({}, {})", collection_name, AQL_DOCUMENT_ID).into(),
+            ),
+        });
+        aql.filter_step(
+            format!(
+                "{} != null && {}.{}.{} <= {}",
+                document_key,
+                document_key,
+                mutex_path,
+                DBMutexField::Expiration(None).path(),
+                serde_json::to_string(&now).unwrap()
+            )
+            .into(),
+        );
+        aql.update_step(
+            AqlUpdate::new(
+                AQL_DOCUMENT_ID.into(),
+                collection_name,
+                format!(
+                    "{{ {}: {{ {}: {}, {}: {}, {}: {} }} }}",
+                    mutex_path,
+                    DBMutexField::Node(None).path(),
+                    serde_json::to_string(node_id).unwrap(),
+                    DBMutexField::Expiration(None).path(),
+                    serde_json::to_string(&expiration).unwrap(),
+                    DBMutexField::ChangeFlag(None).path(),
+                    serde_json::to_string(change_flag).unwrap()
+                )
+                .into(),
+            )
+            .apply_ignore_errors(true)
+            .apply_exclusive(T::EXCLUSIVE_MUTEX),
+        );
+
+        if let Some(fields) = fields {
+            aql.return_step_with_fields(AQL_NEW_ID, fields);
+        } else {
+            aql.return_step(AqlReturn::new_updated());
+        }
+
+        let result = collection.send_generic_aql::<Option<T>>(&aql).await?;
+
+        // Adjust the result list to contain every element in its position.
+        let mut index = 0;
+        let mut results: Vec<Option<T>> = result.results;
+        for key in keys {
+            let result = match results.get(index) {
+                Some(Some(v)) => v,
+                Some(None) => {
+                    continue;
+                }
+                None => {
+                    results.push(None);
+                    continue;
+                }
+            };
+
+            if result.db_key().as_ref() != Some(key) {
+                results.insert(index, None);
+            }
+
+            index += 1;
+        }
+
+        Ok(results)
+    }
+
+    async fn alive_action(mutex: Arc<Mutex<BDMutexGuardInner<T>>>) {
+        loop {
+            // Sleep for interval.
+            sleep(alive_interval()).await;
+
+            let mut lock = mutex.lock().await;
+            if lock.alive_job.is_none() {
+                // The mutex has been already released.
+                return;
+            }
+
+            // Avoid doing unnecessary DB requests.
+            if lock.elements.is_empty() {
+                return;
+            }
+
+            let collection = &lock.collection;
+            let node_id = &lock.node_id;
+            let now = DBDateTime::now();
+            let expiration = now.after_seconds(MUTEX_EXPIRATION);
+            let keys = &lock.elements;
+
+            // FOR i IN <keys>
+            //     LET o = Document(<collection>, i)
+            //     FILTER o != null && o.<mutex.node> == <node> && o.<mutex.change_flag> == <change_flag>
+            //     UPDATE i WITH { <mutex>: { <expiration>: <expiration> } } IN <collection> OPTIONS { mergeObjects: true, ignoreErrors: true }
+            //     FILTER NEW != null
+            //     RETURN i
+            let document_key = "o";
+            let collection_name = T::Collection::name();
+            let mutex_path = DBDocumentField::Mutex.path();
+            let mut aql = AqlBuilder::new_for_in_set(AQL_DOCUMENT_ID, keys);
+            aql.let_step(AqlLet {
+                variable: document_key,
+                expression: AqlLetKind::Expression(
+                    format!("DOCUMENT({}, {})", collection_name, AQL_DOCUMENT_ID).into(),
+                ),
+            });
+            aql.filter_step(
+                format!(
+                    "{} != null && {}.{}.{} == {} && {}.{}.{} == {}",
+                    document_key,
+                    document_key,
+                    mutex_path,
+                    DBMutexField::Node(None).path(),
+                    serde_json::to_string(&node_id).unwrap(),
+                    document_key,
+                    mutex_path,
+                    DBMutexField::ChangeFlag(None).path(),
+                    serde_json::to_string(&lock.change_flag).unwrap(),
+                )
+                .into(),
+            );
+            aql.update_step(
+                AqlUpdate::new_document(
+                    collection_name,
+                    format!(
+                        "{{ {}: {{ {}: {} }} }}",
+                        mutex_path,
+                        DBMutexField::Expiration(None).path(),
+                        serde_json::to_string(&expiration).unwrap(),
+                    )
+                    .into(),
+                )
+                .apply_ignore_errors(true),
+            );
+            aql.filter_step(format!("{} != null", AQL_NEW_ID).into());
+            aql.return_step(AqlReturn::new_document());
+
+            let result = match collection.send_generic_aql::<T::Key>(&aql).await {
+                Ok(v) => v.results,
+                Err(e) => {
+                    let keys = keys.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                    lock.alive_job.take().unwrap().abort();
+                    log::error!(
+                        "Error while keeping alive document mutexes in DB. Keys: {:?}, Error: {}",
+                        keys,
+                        e
+                    );
+                    return;
+                }
+            };
+            let result: HashSet<_> = result.into_iter().collect();
+
+            if result.is_empty() {
+                lock.alive_job.take().unwrap().abort();
+                return;
+            }
+
+            lock.elements = result;
+        }
+    }
+
+    /// Releases the mutex returning the keys it was holding.
+    async fn release_action(mutex: Arc<Mutex<BDMutexGuardInner<T>>>) -> Vec<T::Key> {
+        let mut lock = mutex.lock().await;
+        if lock.alive_job.is_none() {
+            // The mutex has been already released.
+            return Vec::new();
+        }
+
+        // Abort the alive job.
+        lock.alive_job.take().unwrap().abort();
+
+        // Avoid doing unnecessary DB requests.
+        if lock.elements.is_empty() {
+            return Vec::new();
+        }
+
+        // Forget the keys so the mutex cannot be healed after being released.
+        let keys = std::mem::take(&mut lock.elements);
+        let collection = &lock.collection;
+        let node_id = &lock.node_id;
+
+        // FOR i IN <keys>
+        //     LET o = Document(<collection>, i)
+        //     FILTER o != null && o.<mutex.node> == <node> && o.<mutex.change_flag> == <change_flag>
+        //     UPDATE i WITH { <mutex>: null } IN <collection> OPTIONS { mergeObjects: true, keepNulls: false, ignoreErrors: true }
+        //     FILTER NEW != null
+        //     RETURN i
+        let document_key = "o";
+        let collection_name = T::Collection::name();
+        let mutex_path = DBDocumentField::Mutex.path();
+        let mut aql = AqlBuilder::new_for_in_set(AQL_DOCUMENT_ID, &keys);
+        aql.let_step(AqlLet {
+            variable: document_key,
+            expression: AqlLetKind::Expression(
+                format!("DOCUMENT({}, {})", collection_name, AQL_DOCUMENT_ID).into(),
+            ),
+        });
+        aql.filter_step(
+            format!(
+                "{} != null && {}.{}.{} == {} && {}.{}.{} == {}",
+                document_key,
+                document_key,
+                mutex_path,
+                DBMutexField::Node(None).path(),
+                serde_json::to_string(node_id).unwrap(),
+                document_key,
+                mutex_path,
+                DBMutexField::ChangeFlag(None).path(),
+                serde_json::to_string(&lock.change_flag).unwrap(),
+            )
+            .into(),
+        );
+        aql.update_step(
+            AqlUpdate::new_document(
+                collection_name,
+                format!("{{ {}: null }}", mutex_path).into(),
+            )
+            .apply_ignore_errors(true),
+        );
+        aql.filter_step(format!("{} != null", AQL_NEW_ID).into());
+        aql.return_step(AqlReturn::new_document());
+
         let result = match collection.send_generic_aql::<T::Key>(&aql).await {
             Ok(v) => v.results,
             Err(e) => {
-                let keys = keys.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                let key_list = keys.iter().map(|v| v.to_string()).collect::<Vec<_>>();
                 log::error!(
                     "Error while releasing document mutexes in DB. Keys: {:?}, Error: {}",
-                    keys,
+                    key_list,
                     e
                 );
-                return;
+                return keys.into_iter().collect();
             }
         };
         let result: HashSet<_> = result.iter().collect();
 
-        for element_id in keys {
+        for element_id in &keys {
             if !result.contains(element_id) {
                 log::error!(
                     "The mutex (Collection: {}, Id: {}, ChangeFlag: {}) couldn't be released",
@@ -698,6 +1253,8 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
                 );
             }
         }
+
+        keys.into_iter().collect()
     }
 
     pub async fn release_all_mutexes(node_id: &str, collection: &Arc<T::Collection>) {
@@ -733,10 +1290,100 @@ impl<T: 'static + DBSynchronizedDocument<'static>> DBMutexGuard<T> {
             );
         }
     }
+
+    /// Releases all the expired mutexes of the collection regardless of their node.
+    pub async fn release_stale(collection: &Arc<T::Collection>) {
+        let now = DBDateTime::now();
+
+        // FOR i IN <collection>
+        //     FILTER i.<mutex> != null && i.<mutex.expiration> <= <now>
+        //     UPDATE i WITH { <mutex>: null } IN <collection> OPTIONS { mergeObjects: true, keepNulls: false, ignoreErrors: true }
+        let mutex_path = DBDocumentField::Mutex.path();
+        let collection_name = T::Collection::name();
+        let mut aql = AqlBuilder::new_for_in_collection(AQL_DOCUMENT_ID, collection_name);
+        aql.filter_step(
+            format!(
+                "{}.{} != null && {}.{}.{} <= {}",
+                AQL_DOCUMENT_ID,
+                mutex_path,
+                AQL_DOCUMENT_ID,
+                mutex_path,
+                DBMutexField::Expiration(None).path(),
+                serde_json::to_string(&now).unwrap(),
+            )
+            .into(),
+        );
+        aql.update_step(
+            AqlUpdate::new(
+                AQL_DOCUMENT_ID.into(),
+                collection_name,
+                format!("{{ {}: null }}", mutex_path).into(),
+            )
+            .apply_ignore_errors(true),
+        );
+
+        if let Err(e) = collection.send_generic_aql::<DBUuid>(&aql).await {
+            log::error!(
+                "Error while releasing stale collection mutexes in DB. Error: {}",
+                e
+            );
+        }
+    }
+}
+
+impl<T: 'static + DBSynchronizedDocument<'static>> Clone for DBMutexGuard<T> {
+    fn clone(&self) -> Self {
+        self.clone_handle()
+    }
 }
 
 impl<T: 'static + DBSynchronizedDocument<'static>> Drop for DBMutexGuard<T> {
     fn drop(&mut self) {
-        tokio::spawn(Self::release_action(self.inner.clone()));
+        // Only the last handle releases the mutex.
+        if self.handles.fetch_sub(1, Ordering::AcqRel) == 1 {
+            tokio::spawn(Self::release_action(self.inner.clone()));
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Gets the alive interval with a random jitter to avoid every node refreshing its mutexes
+/// at the same time.
+fn alive_interval() -> Duration {
+    let interval = MUTEX_ALIVE_INTERVAL * 1000;
+    let time = {
+        let mut rng = rand::thread_rng();
+        rng.gen_range(interval - MUTEX_ALIVE_JITTER..=interval + MUTEX_ALIVE_JITTER)
+    };
+
+    Duration::from_millis(time)
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_alive_interval() {
+        let min = Duration::from_millis(MUTEX_ALIVE_INTERVAL * 1000 - MUTEX_ALIVE_JITTER);
+        let max = Duration::from_millis(MUTEX_ALIVE_INTERVAL * 1000 + MUTEX_ALIVE_JITTER);
+        let intervals: HashSet<_> = (0..100).map(|_| alive_interval()).collect();
+
+        for interval in &intervals {
+            assert!(
+                *interval >= min && *interval <= max,
+                "Interval out of band: {:?}",
+                interval
+            );
+        }
+
+        assert!(intervals.len() > 1, "The intervals must vary");
     }
 }